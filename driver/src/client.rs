@@ -1,4 +1,4 @@
-use std::{io::Read, net::IpAddr};
+use std::{io::Read, net::IpAddr, thread, time::Duration};
 
 use r2d2::{ManageConnection, Pool};
 use websocket::{
@@ -6,33 +6,220 @@ use websocket::{
         stream::{NetworkStream, TcpStream},
         Client, Stream,
     },
-    ClientBuilder, Message,
+    ClientBuilder, Message, OwnedMessage, WebSocketError,
 };
 
+use tinkerpop_io::binary::Decode;
+
 use crate::error::GremlinError;
+use crate::message::{Request, Response};
+
+const MIME_TYPE: &str = "application/vnd.graphbinary-v1.0";
 
 pub struct Connection<S: NetworkStream>(S);
 
+/// Byte level round trip to a Gremlin Server. When reconnecting is enabled, `connect` is called
+/// again after `send` failed with a connection error. A failed `recv` is never retried, the
+/// server may already have executed the request.
+pub trait Transport {
+    fn connect(&mut self) -> std::io::Result<()>;
+
+    fn send(&mut self, request: &[u8]) -> std::io::Result<()>;
+
+    fn recv(&mut self) -> std::io::Result<Vec<u8>>;
+}
+
+/// Errors after which the request did not reach the server and can be resent on a new connection.
+fn is_connection_error(err: &std::io::Error) -> bool {
+    use std::io::ErrorKind;
+
+    matches!(
+        err.kind(),
+        ErrorKind::NotConnected
+            | ErrorKind::ConnectionRefused
+            | ErrorKind::ConnectionReset
+            | ErrorKind::ConnectionAborted
+            | ErrorKind::BrokenPipe
+    )
+}
+
+/// Keeps the kind of I/O errors, so only failures of the connection are retried. A closed stream
+/// counts as an aborted connection, protocol errors are invalid data.
+fn ws_error_to_io(err: WebSocketError) -> std::io::Error {
+    match err {
+        WebSocketError::IoError(err) => err,
+        WebSocketError::NoDataAvailable => std::io::ErrorKind::ConnectionAborted.into(),
+        err => std::io::Error::new(std::io::ErrorKind::InvalidData, err),
+    }
+}
+
+pub struct WsTransport {
+    url: String,
+    client: Option<Client<TcpStream>>,
+}
+
+impl WsTransport {
+    pub fn new(url: &str) -> Self {
+        WsTransport {
+            url: url.to_owned(),
+            client: None,
+        }
+    }
+}
+
+impl Transport for WsTransport {
+    fn connect(&mut self) -> std::io::Result<()> {
+        let client = ClientBuilder::new(&self.url)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidInput, err))?
+            .connect_insecure()
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::NotConnected, err))?;
+        self.client = Some(client);
+        Ok(())
+    }
+
+    fn send(&mut self, request: &[u8]) -> std::io::Result<()> {
+        let client = self
+            .client
+            .as_mut()
+            .ok_or_else(|| std::io::Error::from(std::io::ErrorKind::NotConnected))?;
+        client
+            .send_message(&Message::binary(request))
+            .map_err(ws_error_to_io)
+    }
+
+    fn recv(&mut self) -> std::io::Result<Vec<u8>> {
+        let client = self
+            .client
+            .as_mut()
+            .ok_or_else(|| std::io::Error::from(std::io::ErrorKind::NotConnected))?;
+        loop {
+            match client.recv_message().map_err(ws_error_to_io)? {
+                OwnedMessage::Binary(bytes) => return Ok(bytes),
+                OwnedMessage::Ping(data) => client
+                    .send_message(&OwnedMessage::Pong(data))
+                    .map_err(ws_error_to_io)?,
+                OwnedMessage::Pong(_) => {}
+                OwnedMessage::Close(_) => {
+                    return Err(std::io::ErrorKind::ConnectionAborted.into());
+                }
+                OwnedMessage::Text(_) => {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        "expected binary message",
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Exponential backoff between reconnect attempts, the delay doubles from `base` up to `max`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Backoff {
+    base: Duration,
+    max: Duration,
+    max_retries: u32,
+}
+
+impl Backoff {
+    fn delay(&self, attempt: u32) -> Duration {
+        self.base
+            .checked_mul(2_u32.saturating_pow(attempt))
+            .map_or(self.max, |delay| delay.min(self.max))
+    }
+}
+
 pub struct GClient {
-    connection: Box<dyn NetworkStream + Send>,
+    connection: Box<dyn Transport + Send>,
+    backoff: Option<Backoff>,
     options: (),
 }
 
 impl GClient {
-    pub fn new(ip: &str) -> Self {
-        let connection = TcpStream::connect(ip).unwrap();
+    /// Connects to the server at `ip` without reconnecting, see [`GClient::builder`] for options.
+    ///
+    /// # Errors
+    ///
+    /// Returns the I/O error if the connection cannot be opened.
+    pub fn new(ip: &str) -> Result<Self, GremlinError> {
+        let mut connection = WsTransport::new(ip);
+        connection.connect()?;
+        Ok(GClient::with_transport(connection))
+    }
+
+    pub fn with_transport(connection: impl Transport + Send + 'static) -> Self {
         GClient {
-            connection,
+            connection: Box::new(connection),
+            backoff: None,
             options: (),
         }
     }
+
+    /// Returns a builder for a client of the server at `ip`, no connection is opened before
+    /// [`GClientBuilder::connect`].
+    pub fn builder(ip: &str) -> GClientBuilder {
+        GClientBuilder::with_transport(WsTransport::new(ip))
+    }
+
+    /// Sends the request and decodes the response. If sending fails with a connection error and
+    /// a [`GClientBuilder::reconnect_backoff`] is configured, the client reconnects and resends,
+    /// returning [`GremlinError::Disconnected`] once all retries are used up. Failures while
+    /// receiving the response are returned as they are.
+    pub fn submit(&mut self, request: &Request) -> Result<Response, GremlinError> {
+        let mut buf = Vec::new();
+        request.write_gb_bytes(&mut buf, MIME_TYPE)?;
+
+        let mut reconnect = false;
+        self.retry(|connection| {
+            if std::mem::replace(&mut reconnect, true) {
+                connection.connect()?;
+            }
+            connection.send(&buf)
+        })?;
+        let bytes = self.connection.recv()?;
+        Ok(Response::decode(&mut &bytes[..])?)
+    }
+
+    /// Runs `op` until it succeeds, connection errors are retried as long as the backoff allows.
+    fn retry<T>(
+        &mut self,
+        mut op: impl FnMut(&mut dyn Transport) -> std::io::Result<T>,
+    ) -> Result<T, GremlinError> {
+        let mut attempt = 0;
+        loop {
+            match op(self.connection.as_mut()) {
+                Ok(val) => return Ok(val),
+                Err(err) if !is_connection_error(&err) => return Err(err.into()),
+                Err(err) => match self.backoff {
+                    Some(backoff) if attempt < backoff.max_retries => {
+                        thread::sleep(backoff.delay(attempt));
+                        attempt += 1;
+                    }
+                    _ => {
+                        return Err(GremlinError::Disconnected {
+                            attempts: attempt,
+                            source: err,
+                        })
+                    }
+                },
+            }
+        }
+    }
 }
 
 pub struct GClientBuilder {
-    client: GClient,
+    connection: Box<dyn Transport + Send>,
+    backoff: Option<Backoff>,
 }
 
 impl GClientBuilder {
+    pub fn with_transport(connection: impl Transport + Send + 'static) -> Self {
+        GClientBuilder {
+            connection: Box::new(connection),
+            backoff: None,
+        }
+    }
+
     pub fn alias(&mut self, alias: &str) -> &mut Self {
         self
     }
@@ -41,12 +228,176 @@ impl GClientBuilder {
         self
     }
 
-    pub fn connect(self) -> GClient {
-        self.client
+    pub fn reconnect_backoff(
+        &mut self,
+        base: Duration,
+        max: Duration,
+        max_retries: u32,
+    ) -> &mut Self {
+        self.backoff = Some(Backoff {
+            base,
+            max,
+            max_retries,
+        });
+        self
+    }
+
+    /// Opens the connection, retried like a failed send if a
+    /// [`GClientBuilder::reconnect_backoff`] is configured.
+    pub fn connect(self) -> Result<GClient, GremlinError> {
+        let mut client = GClient {
+            connection: self.connection,
+            backoff: self.backoff,
+            options: (),
+        };
+        client.retry(|connection| connection.connect())?;
+        Ok(client)
     }
 }
 
 #[test]
+#[ignore = "needs a Gremlin Server on localhost:8182"]
 fn test() {
-    let mut client = GClient::new("ws://localhost:8182/gremlin");
+    let mut client = GClient::new("ws://localhost:8182/gremlin").unwrap();
+}
+
+/// Fails the first `failures` calls of the operation named by `fail`, counting all calls.
+#[cfg(test)]
+#[derive(Default)]
+struct Flaky {
+    fail: &'static str,
+    failures: usize,
+    kind: Option<std::io::ErrorKind>,
+    calls: std::sync::Arc<std::sync::Mutex<Vec<&'static str>>>,
+    response: Vec<u8>,
+}
+
+#[cfg(test)]
+impl Flaky {
+    fn call(&mut self, op: &'static str) -> std::io::Result<()> {
+        self.calls.lock().unwrap().push(op);
+        if op == self.fail && self.failures > 0 {
+            self.failures -= 1;
+            return Err(self
+                .kind
+                .unwrap_or(std::io::ErrorKind::ConnectionReset)
+                .into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+impl Transport for Flaky {
+    fn connect(&mut self) -> std::io::Result<()> {
+        self.call("connect")
+    }
+
+    fn send(&mut self, _request: &[u8]) -> std::io::Result<()> {
+        self.call("send")
+    }
+
+    fn recv(&mut self) -> std::io::Result<Vec<u8>> {
+        self.call("recv")?;
+        Ok(self.response.clone())
+    }
+}
+
+#[test]
+fn reconnect_after_disconnect() {
+    let response = vec![
+        0x81, 0x0, 0x0, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc,
+        0xdd, 0xee, 0xff, 0x0, 0x0, 0x0, 0xc8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x9, 0x0, 0x0, 0x0, 0x0, 0x1, 0x1, 0x0, 0x0, 0x0, 0x0, 0x1d,
+    ];
+    let request = Request::builder().eval().gremlin("g.V()").build();
+
+    let transport = Flaky {
+        fail: "send",
+        failures: 1,
+        response,
+        ..Flaky::default()
+    };
+    let calls = transport.calls.clone();
+    let mut builder = GClientBuilder::with_transport(transport);
+    builder.reconnect_backoff(Duration::from_millis(1), Duration::from_millis(4), 3);
+    let mut client = builder.connect().unwrap();
+    let resp = client.submit(&request).unwrap();
+    assert_eq!(&200, resp.status_code());
+    assert_eq!(
+        vec!["connect", "send", "connect", "send", "recv"],
+        *calls.lock().unwrap()
+    );
+
+    let mut client = GClient::with_transport(Flaky {
+        fail: "send",
+        failures: 1,
+        ..Flaky::default()
+    });
+    assert!(matches!(
+        client.submit(&request),
+        Err(GremlinError::Disconnected { attempts: 0, .. })
+    ));
+}
+
+#[test]
+fn failed_recv_is_not_resent() {
+    let request = Request::builder().eval().gremlin("g.V()").build();
+
+    for kind in [
+        std::io::ErrorKind::ConnectionReset,
+        std::io::ErrorKind::InvalidData,
+    ] {
+        let transport = Flaky {
+            fail: "recv",
+            failures: 1,
+            kind: Some(kind),
+            ..Flaky::default()
+        };
+        let calls = transport.calls.clone();
+        let mut builder = GClientBuilder::with_transport(transport);
+        builder.reconnect_backoff(Duration::from_millis(1), Duration::from_millis(4), 3);
+        let mut client = builder.connect().unwrap();
+        assert!(
+            matches!(client.submit(&request), Err(GremlinError::Io(err)) if err.kind() == kind)
+        );
+        assert_eq!(vec!["connect", "send", "recv"], *calls.lock().unwrap());
+    }
+}
+
+#[test]
+fn connect_with_backoff() {
+    let transport = Flaky {
+        fail: "connect",
+        failures: 2,
+        ..Flaky::default()
+    };
+    let calls = transport.calls.clone();
+    let mut builder = GClientBuilder::with_transport(transport);
+    builder.reconnect_backoff(Duration::from_millis(1), Duration::from_millis(4), 3);
+    assert!(builder.connect().is_ok());
+    assert_eq!(3, calls.lock().unwrap().len());
+
+    let builder = GClientBuilder::with_transport(Flaky {
+        fail: "connect",
+        failures: 1,
+        ..Flaky::default()
+    });
+    assert!(matches!(
+        builder.connect(),
+        Err(GremlinError::Disconnected { attempts: 0, .. })
+    ));
+}
+
+#[test]
+fn backoff_delay() {
+    let backoff = Backoff {
+        base: Duration::from_millis(10),
+        max: Duration::from_millis(50),
+        max_retries: 5,
+    };
+    assert_eq!(Duration::from_millis(10), backoff.delay(0));
+    assert_eq!(Duration::from_millis(40), backoff.delay(2));
+    assert_eq!(Duration::from_millis(50), backoff.delay(3));
+    assert_eq!(Duration::from_millis(50), backoff.delay(40));
 }
@@ -8,4 +8,12 @@ pub enum GremlinError {
     Encode(#[from] tinkerpop_io::error::EncodeError),
     #[error("reading from Reader")]
     GraphSon(#[from] tinkerpop_io::error::GraphSonError),
+    #[error("connection lost, gave up after {attempts} reconnect attempts")]
+    Disconnected {
+        attempts: u32,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("transport failed")]
+    Io(#[from] std::io::Error),
 }
@@ -4,7 +4,7 @@ use std::mem::size_of;
 #[macro_use]
 extern crate lazy_static;
 
-mod client;
+pub mod client;
 
 pub mod error;
 pub mod message;
@@ -3,7 +3,7 @@ use std::fmt::Display;
 use std::vec;
 
 use crate::error::GremlinError;
-use tinkerpop_io::binary::{Decode, Encode};
+use tinkerpop_io::binary::{check_version, protocol_version, Decode, Encode, PROTOCOL_VERSION};
 use tinkerpop_io::error::{DecodeError, EncodeError};
 use tinkerpop_io::structure::bytecode::Bytecode;
 use tinkerpop_io::structure::enums::T;
@@ -37,7 +37,7 @@ impl Request {
 impl Default for Request {
     fn default() -> Self {
         Self {
-            version: 0x81,
+            version: protocol_version(),
             request_id: Uuid::new_v4(),
             op: "".to_owned(),
             processor: String::default(),
@@ -81,9 +81,7 @@ impl RequestBuilder {
     }
     pub fn session(mut self, session_identifier: &str) -> Self {
         self.0.processor = "session".to_owned();
-        self.0
-            .args
-            .insert("session".into(), session_identifier.into());
+        insert_arg(&mut self.0.args, "session", session_identifier);
         self
     }
     pub fn op(mut self, op: &str) -> Self {
@@ -94,47 +92,119 @@ impl RequestBuilder {
         self.0.processor = processor.to_owned();
         self
     }
+    /// merges `args` into the arguments of the request, overwriting present keys. A `session`
+    /// argument switches the request to the session processor, arguments given here are kept
+    /// over the defaults of [`RequestBuilder::eval`] and [`RequestBuilder::bytecode`]
+    pub fn args(mut self, args: RequestArgs) -> Self {
+        if args.0.contains_key(&"session".into()) {
+            self.0.processor = "session".to_owned();
+        }
+        self.0.args.extend(args.build());
+        self
+    }
     pub fn authentication(mut self) -> AuthRequestBuilder {
         self.0.op = "authentication".to_owned();
         self.0.processor = "".to_owned();
-        self.0.args.insert("saslMechanism".into(), "PLAIN".into());
+        self.0
+            .args
+            .entry("saslMechanism".into())
+            .or_insert_with(|| "PLAIN".into());
         AuthRequestBuilder(self.0)
     }
     pub fn eval(mut self) -> EvalBuilder {
         self.0.op = "eval".to_owned();
         self.0
             .args
-            .insert("language".into(), "gremlin-groovy".into());
+            .entry("language".into())
+            .or_insert_with(|| "gremlin-groovy".into());
         EvalBuilder(self.0)
     }
     pub fn bytecode(mut self) -> BytecodeBuilder {
         self.0.op = "bytecode".to_owned();
-        self.0.processor = "traversal".to_owned();
-        self.0.args.insert(
-            MapKeys::String("aliases".to_string()),
-            GremlinValue::Map(HashMap::from([("g".into(), "g".into())])),
-        );
+        if self.0.processor != "session" {
+            self.0.processor = "traversal".to_owned();
+        }
+        self.0
+            .args
+            .entry("aliases".into())
+            .or_insert_with(|| GremlinValue::Map(HashMap::from([("g".into(), "g".into())])));
         BytecodeBuilder(self.0)
     }
     pub fn close(mut self, session_identifier: &str) -> Request {
         self.0.op = "close".into();
-        self.0.processor = "session".to_owned();
-        self.0
-            .args
-            .insert("session".into(), session_identifier.into());
+        self.session(session_identifier).0
+    }
+}
+
+/// Typed builder for the `args` map of a [`Request`], so every argument is written with the
+/// GraphBinary type the server expects.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct RequestArgs(HashMap<MapKeys, GremlinValue>);
+
+impl RequestArgs {
+    pub fn new() -> Self {
+        RequestArgs::default()
+    }
+    pub fn gremlin(mut self, script: &str) -> Self {
+        insert_arg(&mut self.0, "gremlin", script);
+        self
+    }
+    pub fn bytecode(mut self, bytecode: Bytecode) -> Self {
+        insert_arg(&mut self.0, "gremlin", bytecode);
+        self
+    }
+    pub fn bindings(mut self, bindings: HashMap<String, GremlinValue>) -> Self {
+        insert_arg(&mut self.0, "bindings", bindings);
+        self
+    }
+    pub fn aliases(mut self, aliases: HashMap<String, String>) -> Self {
+        insert_arg(&mut self.0, "aliases", aliases);
+        self
+    }
+    pub fn language(mut self, language: &str) -> Self {
+        insert_arg(&mut self.0, "language", language);
+        self
+    }
+    pub fn session(mut self, session_identifier: &str) -> Self {
+        insert_arg(&mut self.0, "session", session_identifier);
+        self
+    }
+    /// timeout in milliseconds, written as `Long` like the server reads it
+    pub fn evaluation_timeout(mut self, ms: i64) -> Self {
+        insert_arg(&mut self.0, "evaluationTimeout", GremlinValue::Long(ms));
+        self
+    }
+    pub fn build(self) -> HashMap<MapKeys, GremlinValue> {
         self.0
     }
 }
 
+/// Writes the argument `key` with the GraphBinary type of `value`, replacing a present one.
+fn insert_arg(
+    args: &mut HashMap<MapKeys, GremlinValue>,
+    key: &str,
+    value: impl Into<GremlinValue>,
+) {
+    args.insert(key.into(), value.into());
+}
+
 pub struct BytecodeBuilder(Request);
 
 impl BytecodeBuilder {
     pub fn gremlin(mut self, bytecode: Bytecode) -> Self {
-        self.0.args.insert("gremlin".into(), bytecode.into());
+        insert_arg(&mut self.0.args, "gremlin", bytecode);
         self
     }
     pub fn aliases(mut self, aliases: HashMap<String, String>) -> Self {
-        self.0.args.insert("aliases".into(), aliases.into());
+        insert_arg(&mut self.0.args, "aliases", aliases);
+        self
+    }
+    pub fn evaluation_timeout(mut self, ms: i64) -> Self {
+        insert_arg(
+            &mut self.0.args,
+            "evaluationTimeout",
+            GremlinValue::Long(ms),
+        );
         self
     }
     pub fn build(self) -> Request {
@@ -146,41 +216,40 @@ pub struct EvalBuilder(Request);
 
 impl EvalBuilder {
     pub fn bindings(mut self, bindings: HashMap<String, GremlinValue>) -> Self {
-        self.0.args.insert("bindings".into(), bindings.into());
+        insert_arg(&mut self.0.args, "bindings", bindings);
         self
     }
     pub fn gremlin(mut self, script: &str) -> Self {
-        self.0.args.insert("gremlin".into(), script.into());
+        insert_arg(&mut self.0.args, "gremlin", script);
         self
     }
     pub fn session(mut self, session_identifier: &str) -> Self {
         self.0.processor = "session".to_owned();
-        self.0
-            .args
-            .insert("session".into(), session_identifier.into());
+        insert_arg(&mut self.0.args, "session", session_identifier);
         self
     }
+    /// adds `aliases` to the aliases already set on the request
     pub fn aliases(mut self, aliases: HashMap<String, String>) -> Self {
-        if let Some(GremlinValue::Map(map)) = self.0.args.get_mut(&"aliases".into()) {
-            map.extend(aliases.into_iter().map(|(k, v)| (k.into(), v.into())));
+        if let Some(GremlinValue::Map(present)) = self.0.args.get_mut(&"aliases".into()) {
+            present.extend(aliases.into_iter().map(|(k, v)| (k.into(), v.into())));
         } else {
-            self.0.args.insert("aliases".into(), aliases.into());
+            insert_arg(&mut self.0.args, "aliases", aliases);
         }
         self
     }
-    pub fn alias(mut self, source: &str, alias: &str) -> Self {
-        if let Some(GremlinValue::Map(map)) = self.0.args.get_mut(&"aliases".into()) {
-            map.insert(source.into(), alias.into());
-        } else {
-            self.0.args.insert(
-                "aliases".into(),
-                HashMap::<MapKeys, GremlinValue>::from([(source.into(), alias.into())]).into(),
-            );
-        }
-        self
+    pub fn alias(self, source: &str, alias: &str) -> Self {
+        self.aliases(HashMap::from([(source.to_owned(), alias.to_owned())]))
     }
     pub fn language(mut self, language: &str) -> Self {
-        self.0.args.insert("language".into(), language.into());
+        insert_arg(&mut self.0.args, "language", language);
+        self
+    }
+    pub fn evaluation_timeout(mut self, ms: i64) -> Self {
+        insert_arg(
+            &mut self.0.args,
+            "evaluationTimeout",
+            GremlinValue::Long(ms),
+        );
         self
     }
     pub fn build(self) -> Request {
@@ -202,9 +271,7 @@ impl AuthRequestBuilder {
     }
     pub fn session(mut self, session_identifier: &str) -> Self {
         self.0.processor = "session".to_owned();
-        self.0
-            .args
-            .insert("session".into(), session_identifier.into());
+        insert_arg(&mut self.0.args, "session", session_identifier);
         self
     }
     pub fn build(self) -> Request {
@@ -226,7 +293,7 @@ pub struct Response {
 impl Default for Response {
     fn default() -> Self {
         Self {
-            version: 0x81,
+            version: protocol_version(),
             request_id: None,
             status_code: 200,
             status_message: None,
@@ -240,7 +307,7 @@ impl Default for Response {
 impl Response {
     fn new() -> Self {
         Response {
-            version: u8::default(),
+            version: PROTOCOL_VERSION,
             request_id: None,
             status_code: i32::default(),
             status_message: None,
@@ -366,6 +433,7 @@ impl Decode for Response {
         Self: std::marker::Sized,
     {
         let version = u8::partial_decode(reader)?;
+        check_version(version)?;
         let uuid = Uuid::nullable_decode(reader)?;
         let status_code = i32::partial_decode(reader)?;
         let status_message = String::nullable_decode(reader)?;
@@ -787,3 +855,112 @@ fn test() {
         req
     )
 }
+
+#[test]
+fn response_unsupported_version() {
+    let bytes = [
+        0x82, 0x0, 0x0, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc,
+        0xdd, 0xee, 0xff, 0x0, 0x0, 0x0, 0xc8,
+    ];
+    assert!(matches!(
+        Response::decode(&mut &bytes[..]),
+        Err(DecodeError::UnsupportedVersion(0x82))
+    ));
+}
+
+#[test]
+fn request_args_typed() {
+    let args = RequestArgs::new()
+        .gremlin("g.V(x)")
+        .bindings(HashMap::from([("x".to_string(), 1_i32.into())]))
+        .aliases(HashMap::from([("g".to_string(), "social".to_string())]))
+        .evaluation_timeout(3000)
+        .build();
+
+    assert_eq!(
+        Some(&GremlinValue::from("g.V(x)")),
+        args.get(&"gremlin".into())
+    );
+    assert_eq!(
+        Some(&GremlinValue::Map(HashMap::from([(
+            "x".into(),
+            1_i32.into()
+        )]))),
+        args.get(&"bindings".into())
+    );
+    assert_eq!(
+        Some(&GremlinValue::Map(HashMap::from([(
+            "g".into(),
+            "social".into()
+        )]))),
+        args.get(&"aliases".into())
+    );
+    assert_eq!(
+        Some(&GremlinValue::Long(3000)),
+        args.get(&"evaluationTimeout".into())
+    );
+
+    let req = Request::builder()
+        .args(RequestArgs::new().evaluation_timeout(10))
+        .bytecode()
+        .build();
+    assert_eq!(
+        Some(&GremlinValue::Long(10)),
+        req.args.get(&"evaluationTimeout".into())
+    );
+}
+
+#[test]
+fn request_args_kept_over_defaults() {
+    let req = Request::builder()
+        .args(
+            RequestArgs::new()
+                .language("gremlin-lang")
+                .aliases(HashMap::from([("g".to_string(), "social".to_string())])),
+        )
+        .eval()
+        .alias("t", "test")
+        .build();
+    assert_eq!(
+        Some(&GremlinValue::from("gremlin-lang")),
+        req.args.get(&"language".into())
+    );
+    assert_eq!(
+        Some(&GremlinValue::Map(HashMap::from([
+            ("g".into(), "social".into()),
+            ("t".into(), "test".into()),
+        ]))),
+        req.args.get(&"aliases".into())
+    );
+
+    let req = Request::builder()
+        .args(RequestArgs::new().aliases(HashMap::from([("g".to_string(), "social".to_string())])))
+        .bytecode()
+        .build();
+    assert_eq!(
+        Some(&GremlinValue::Map(HashMap::from([(
+            "g".into(),
+            "social".into()
+        )]))),
+        req.args.get(&"aliases".into())
+    );
+}
+
+#[test]
+fn request_args_session_processor() {
+    let req = Request::builder()
+        .args(RequestArgs::new().session("abc"))
+        .eval()
+        .build();
+    assert_eq!("session", req.processor);
+    assert_eq!(
+        Some(&GremlinValue::from("abc")),
+        req.args.get(&"session".into())
+    );
+
+    let req = Request::builder()
+        .args(RequestArgs::new().session("abc"))
+        .bytecode()
+        .build();
+    assert_eq!("session", req.processor);
+}
@@ -35,7 +35,7 @@ impl<T> Decode for P<T> {
     where
         Self: std::marker::Sized,
     {
-        let predicate = String::decode(reader)?;
+        let predicate = String::partial_decode(reader)?;
         let value = Vec::<GremlinValue>::partial_decode(reader)?;
 
         Ok(P {
@@ -69,7 +69,7 @@ impl Decode for TextP {
     where
         Self: std::marker::Sized,
     {
-        let predicate = String::decode(reader)?;
+        let predicate = String::partial_decode(reader)?;
         let value = Vec::<GremlinValue>::partial_decode(reader)?;
 
         Ok(TextP { predicate, value })
@@ -124,7 +124,7 @@ graph_binary_impls!(
 
 #[test]
 fn t_decode() {
-    let reader = vec![0x03, 0x0, 0x0, 0x0, 0x0, 0x02, b'i', b'd'];
+    let reader = [0x03, 0x0, 0x0, 0x0, 0x0, 0x02, b'i', b'd'];
 
     let p = T::partial_decode(&mut &reader[..]);
 
@@ -134,9 +134,8 @@ fn t_decode() {
 #[test]
 fn p_decode() {
     let reader = vec![
-        0x03, 0x0, 0x0, 0x0, 0x0, 0x07, b'w', b'i', b't', b'h', b'o', b'u', b't', 0x0, 0x0, 0x0,
-        0x03, 0x1, 0x0, 0x0, 0x0, 0x0, 0x01, 0x01, 0x0, 0x0, 0x0, 0x0, 0x2, 0x01, 0x00, 0x0, 0x0,
-        0x0, 0x3,
+        0x0, 0x0, 0x0, 0x07, b'w', b'i', b't', b'h', b'o', b'u', b't', 0x0, 0x0, 0x0, 0x03, 0x1,
+        0x0, 0x0, 0x0, 0x0, 0x01, 0x01, 0x0, 0x0, 0x0, 0x0, 0x2, 0x01, 0x00, 0x0, 0x0, 0x0, 0x3,
     ];
 
     let p = P::<GremlinValue>::partial_decode(&mut &reader[..]);
@@ -147,8 +146,8 @@ fn p_decode() {
 #[test]
 fn p_decode_inside() {
     let reader = vec![
-        0x03, 0x0, 0x0, 0x0, 0x0, 0x06, b'i', b'n', b's', b'i', b'd', b'e', 0x0, 0x0, 0x0, 0x02,
-        0x1, 0x0, 0x0, 0x0, 0x0, 0x01, 0x01, 0x0, 0x0, 0x0, 0x0, 0xff,
+        0x0, 0x0, 0x0, 0x06, b'i', b'n', b's', b'i', b'd', b'e', 0x0, 0x0, 0x0, 0x02, 0x1, 0x0,
+        0x0, 0x0, 0x0, 0x01, 0x01, 0x0, 0x0, 0x0, 0x0, 0xff,
     ];
 
     let p = P::<i32>::partial_decode(&mut &reader[..]);
@@ -173,11 +172,89 @@ fn p_encode() {
 #[test]
 fn text_p_decode() {
     let reader = vec![
-        0x28, 0x00, 0x03, 0x0, 0x0, 0x0, 0x0, 0x0c, b's', b't', b'a', b'r', b't', b'i', b'n', b'g',
-        b'W', b'i', b't', b'h', 0x0, 0x0, 0x0, 0x01, 0x3, 0x0, 0x0, 0x0, 0x0, 0x04, b't', b'e',
-        b's', b't',
+        0x28, 0x00, 0x0, 0x0, 0x0, 0x0c, b's', b't', b'a', b'r', b't', b'i', b'n', b'g', b'W',
+        b'i', b't', b'h', 0x0, 0x0, 0x0, 0x01, 0x3, 0x0, 0x0, 0x0, 0x0, 0x04, b't', b'e', b's',
+        b't',
     ];
 
     let p = TextP::decode(&mut &reader[..]).unwrap();
     assert_eq!(p, TextP::starting_with("test"));
 }
+
+#[test]
+fn order_desc_roundtrip() {
+    let expected = [
+        CoreType::Order.into(),
+        0x0,
+        0x03,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x04,
+        b'd',
+        b'e',
+        b's',
+        b'c',
+    ];
+
+    let mut w = vec![];
+    Order::Desc.encode(&mut w).unwrap();
+    assert_eq!(w, expected);
+    assert_eq!(Order::Desc, Order::decode(&mut &w[..]).unwrap());
+}
+
+#[test]
+fn cardinality_list_roundtrip() {
+    let expected = [
+        CoreType::Cardinality.into(),
+        0x0,
+        0x03,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x04,
+        b'l',
+        b'i',
+        b's',
+        b't',
+    ];
+
+    let mut w = vec![];
+    Cardinality::List.encode(&mut w).unwrap();
+    assert_eq!(w, expected);
+    assert_eq!(Cardinality::List, Cardinality::decode(&mut &w[..]).unwrap());
+}
+
+#[test]
+fn enums_roundtrip() {
+    fn roundtrip<E: Encode + Decode + PartialEq + std::fmt::Debug>(variants: &[E]) {
+        for variant in variants {
+            let mut w = vec![];
+            variant.encode(&mut w).unwrap();
+            assert_eq!(variant, &E::decode(&mut &w[..]).unwrap());
+        }
+    }
+
+    roundtrip(&[Barrier::NormSack]);
+    roundtrip(&[Cardinality::List, Cardinality::Set, Cardinality::Single]);
+    roundtrip(&[Column::Keys, Column::Values]);
+    roundtrip(&[
+        Operator::AddAll,
+        Operator::And,
+        Operator::Assign,
+        Operator::Div,
+        Operator::Max,
+        Operator::Min,
+        Operator::Minus,
+        Operator::Mult,
+        Operator::Or,
+        Operator::Sum,
+        Operator::SumLong,
+    ]);
+    roundtrip(&[Order::Shuffle, Order::Asc, Order::Desc]);
+    roundtrip(&[Pick::Any, Pick::None]);
+    roundtrip(&[Pop::All, Pop::First, Pop::Last, Pop::Mixed]);
+    roundtrip(&[Scope::Local, Scope::Global]);
+}
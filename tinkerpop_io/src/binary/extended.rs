@@ -409,73 +409,6 @@ impl Decode for Instant {
     }
 }
 
-#[cfg(feature = "graph_binary")]
-impl Encode for char {
-    fn type_code() -> u8 {
-        CoreType::Char.into()
-    }
-
-    fn partial_encode<W: std::io::Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
-        let mut buf = [0; 4];
-        let slice = self.encode_utf8(&mut buf);
-        writer.write_all(slice.as_bytes())?;
-        Ok(())
-    }
-}
-
-#[cfg(feature = "graph_binary")]
-impl Decode for char {
-    fn expected_type_code() -> u8 {
-        CoreType::Char.into()
-    }
-
-    fn partial_decode<R: Read>(reader: &mut R) -> Result<Self, DecodeError>
-    where
-        Self: std::marker::Sized,
-    {
-        let mut first_byte = [0_u8; 1];
-        reader.read_exact(&mut first_byte)?;
-
-        match first_byte[0] {
-            one if one < 0b1000_0000 => Ok(char::from(one)),
-            two if (0b1100_0000..0b1110_0000).contains(&two) => {
-                let mut second_byte = [0_u8; 1];
-                reader.read_exact(&mut second_byte)?;
-                std::str::from_utf8(&[first_byte[0], second_byte[0]])?
-                    .chars()
-                    .next()
-                    .ok_or_else(|| {
-                        DecodeError::DecodeError("error converting u32 to char".to_string())
-                    })
-            }
-            three if (0b1110_0000..0b1111_0000).contains(&three) => {
-                let mut rest = [0_u8; 2];
-                reader.read_exact(&mut rest)?;
-                std::str::from_utf8(&[first_byte[0], rest[0], rest[1]])?
-                    .chars()
-                    .next()
-                    .ok_or_else(|| {
-                        DecodeError::DecodeError("error converting u32 to char".to_string())
-                    })
-            }
-            four if (0b1111_0000..0b1111_1000).contains(&four) => {
-                let mut rest = [0_u8; 3];
-                reader.read_exact(&mut rest)?;
-                std::str::from_utf8(&[first_byte[0], rest[0], rest[1], rest[2]])?
-                    .chars()
-                    .next()
-                    .ok_or_else(|| {
-                        DecodeError::DecodeError("error converting u32 to char".to_string())
-                    })
-            }
-            rest => Err(DecodeError::DecodeError(format!(
-                "not a valid utf-8 first byte: value {:b}",
-                rest
-            ))),
-        }
-    }
-}
-
 #[cfg(feature = "graph_binary")]
 impl Encode for IpAddr {
     fn type_code() -> u8 {
@@ -550,6 +483,26 @@ fn instant_decode() {
     assert_eq!(res, expected)
 }
 
+#[test]
+fn instant_nanos_roundtrip() {
+    use crate::GremlinValue;
+
+    let expected = [
+        0x83, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x7b,
+    ];
+    let instant = Instant::new(1, 123);
+    assert_eq!("1970-01-01T00:00:01.000000123Z", instant.to_string());
+
+    let mut buf = vec![];
+    GremlinValue::Instant(instant.clone())
+        .encode(&mut buf)
+        .unwrap();
+    assert_eq!(buf, expected);
+
+    let res = GremlinValue::decode(&mut &buf[..]).unwrap();
+    assert_eq!(GremlinValue::Instant(instant), res)
+}
+
 #[test]
 fn local_date_encode() {
     let expected = [0x84, 0x0, 0x0, 0x0, 0x7, 0xE6, 6, 13];
@@ -565,7 +518,7 @@ fn local_date_encode() {
 
 #[test]
 fn local_date_decode() {
-    let buf = vec![0x84, 0x0, 0x0, 0x0, 0x7, 0xE6, 6, 13];
+    let buf = [0x84, 0x0, 0x0, 0x0, 0x7, 0xE6, 6, 13];
 
     let expected = NaiveDate::from_ymd_opt(2022, 6, 13).expect("invalid or out-of-range date");
     let res = NaiveDate::decode(&mut &buf[..]).unwrap();
@@ -620,38 +573,6 @@ fn date_time_offset_decode() {
     assert_eq!(res, expected)
 }
 
-#[test]
-fn char_decode_utf8() {
-    let reader = [0x80_u8, 0x0, 0xe2, 0x99, 0xa5];
-    let c = char::decode(&mut &reader[..]).unwrap();
-
-    assert_eq!('♥', c)
-}
-
-#[test]
-fn char_decode() {
-    let reader = [0x80_u8, 0x0, 65];
-    let c = char::decode(&mut &reader[..]).unwrap();
-
-    assert_eq!('A', c)
-}
-
-#[test]
-fn char_decode_2() {
-    let reader = [0x80_u8, 0x0, 0xc3, 0x9f];
-    let c = char::decode(&mut &reader[..]).unwrap();
-
-    assert_eq!('ß', c)
-}
-
-#[test]
-fn char_decode_3() {
-    let reader = [0x80_u8, 0x0, 0xf0, 0x9f, 0xa6, 0x80];
-    let c = char::decode(&mut &reader[..]).unwrap();
-
-    assert_eq!('🦀', c)
-}
-
 #[test]
 fn ip_v4_encode() {
     use std::str::FromStr;
@@ -1,13 +1,16 @@
 #[cfg(feature = "custom")]
 use crate::custom::Custom;
 use crate::error::{DecodeError, EncodeError};
-use std::collections::HashMap;
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
 use std::fs::File;
-use std::io::{BufReader, BufWriter, Read, Write};
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::sync::Arc;
 
 use crate::structure::bulkset::BulkSet;
 use crate::structure::bytebuffer::ByteBuffer;
 use crate::structure::bytecode::Bytecode;
+use crate::structure::edge::Edge;
 use crate::structure::enums::{
     Barrier, Cardinality, Column, Direction, Merge, Operator, Order, Pick, Pop, Scope, TextP, P, T,
 };
@@ -19,15 +22,17 @@ use crate::structure::path::Path;
 use crate::structure::property::Property;
 use crate::structure::set::Set;
 use crate::structure::traverser::{TraversalStrategy, Traverser};
+use crate::structure::tree::Tree;
 use crate::structure::vertex::Vertex;
 use crate::structure::vertex_property::VertexProperty;
 use crate::Binding;
 use crate::GremlinValue;
-use crate::{specs::CoreType, structure::edge::Edge};
 use bigdecimal::BigDecimal;
 use num::BigInt;
 use uuid::Uuid;
 
+pub use crate::specs::CoreType;
+
 mod enums;
 #[cfg(feature = "extended")]
 mod extended;
@@ -42,6 +47,23 @@ use crate::extended::chrono::{
 #[cfg(feature = "extended")]
 use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
 
+/// Version byte of GraphBinary 1.0 request and response messages.
+pub const PROTOCOL_VERSION: u8 = 0x81;
+
+#[must_use]
+pub const fn protocol_version() -> u8 {
+    PROTOCOL_VERSION
+}
+
+/// Checks that a message version byte announces GraphBinary 1.0.
+pub fn check_version(byte: u8) -> Result<(), DecodeError> {
+    if byte == PROTOCOL_VERSION {
+        Ok(())
+    } else {
+        Err(DecodeError::UnsupportedVersion(byte))
+    }
+}
+
 pub fn from_file<T: Decode>(path: &std::path::Path) -> Result<T, DecodeError> {
     let file = File::open(path).map_err(|err| DecodeError::DecodeError(err.to_string()))?;
     let mut buf = BufReader::new(file);
@@ -52,6 +74,363 @@ pub fn from_slice<T: Decode>(slice: &mut &[u8]) -> Result<T, DecodeError> {
     T::decode(slice)
 }
 
+/// Decodes a value from the slice like [`from_slice`], but fails with
+/// [`DecodeError::TrailingBytes`] if the slice is not fully consumed.
+pub fn from_slice_exact<T: Decode>(slice: &[u8]) -> Result<T, DecodeError> {
+    let mut remaining = slice;
+    decode_exact(&mut remaining, |reader| T::decode(reader))
+}
+
+/// Decodes a value from the slice like [`from_slice_exact`], but wraps a failure in
+/// [`DecodeError::AtOffset`] carrying a [`hexdump`] of the bytes around the failing offset.
+pub fn from_slice_debug<T: Decode>(slice: &[u8]) -> Result<T, DecodeError> {
+    let mut remaining = slice;
+    decode_exact(&mut remaining, |reader| T::decode(reader)).map_err(|err| {
+        let offset = slice.len() - remaining.len();
+        let start = offset.saturating_sub(16) / 16 * 16;
+        let end = (offset + 16).min(slice.len());
+        DecodeError::AtOffset {
+            offset,
+            snippet: Some(hexdump_at(&slice[start..end], start)),
+            source: Box::new(err),
+        }
+    })
+}
+
+fn decode_exact<T>(
+    remaining: &mut &[u8],
+    decode: impl FnOnce(&mut &[u8]) -> Result<T, DecodeError>,
+) -> Result<T, DecodeError> {
+    let value = decode(remaining)?;
+    match remaining.len() {
+        0 => Ok(value),
+        n => Err(DecodeError::TrailingBytes(n)),
+    }
+}
+
+/// Decodes GraphBinary bytes into plain JSON, see [`GremlinValue::to_plain_json`].
+#[cfg(feature = "graph_son")]
+pub fn to_json(bytes: &[u8]) -> Result<serde_json::Value, DecodeError> {
+    from_slice_exact::<GremlinValue>(bytes).map(|value| value.to_plain_json())
+}
+
+/// Formats the bytes as a table of offset, hex and ascii columns like `xxd`.
+/// Every line holds 16 bytes, non printable bytes are shown as `.` in the ascii column.
+#[must_use]
+pub fn hexdump(bytes: &[u8]) -> String {
+    hexdump_at(bytes, 0)
+}
+
+fn hexdump_at(bytes: &[u8], base_offset: usize) -> String {
+    let mut dump = String::new();
+    for (line, chunk) in bytes.chunks(16).enumerate() {
+        let hex = chunk
+            .chunks(2)
+            .map(|pair| pair.iter().map(|b| format!("{b:02x}")).collect::<String>())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let ascii: String = chunk
+            .iter()
+            .map(|b| {
+                if b.is_ascii_graphic() || *b == b' ' {
+                    char::from(*b)
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+        dump.push_str(&format!(
+            "{:08x}: {hex:<39}  {ascii}\n",
+            base_offset + line * 16
+        ));
+    }
+    dump
+}
+
+/// A set of [`CoreType`]s which are allowed to be decoded by [`from_slice_allowed`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DecodeAllowlist(HashSet<CoreType>);
+
+impl DecodeAllowlist {
+    #[must_use]
+    pub fn new(types: impl IntoIterator<Item = CoreType>) -> Self {
+        DecodeAllowlist(types.into_iter().collect())
+    }
+
+    #[must_use]
+    pub fn contains(&self, core_type: CoreType) -> bool {
+        self.0.contains(&core_type)
+    }
+}
+
+impl FromIterator<CoreType> for DecodeAllowlist {
+    fn from_iter<I: IntoIterator<Item = CoreType>>(iter: I) -> Self {
+        DecodeAllowlist::new(iter)
+    }
+}
+
+/// Decodes a `GremlinValue` from the slice like [`from_slice`], but fails with
+/// [`DecodeError::DisallowedType`] if the type code of the value, or of any `GremlinValue`
+/// nested in it such as list items, map keys and values or the ids of elements, is not part
+/// of the allowlist. Values with a fixed type inside a structure, like the label `String` of
+/// a `Vertex`, are not checked. The slice is not advanced on rejection.
+pub fn from_slice_allowed(
+    slice: &mut &[u8],
+    allowlist: &DecodeAllowlist,
+) -> Result<GremlinValue, DecodeError> {
+    let mut remaining = *slice;
+    let mut scope = DecodeScope {
+        allowlist: Some(allowlist.clone()),
+        ..DecodeScope::default()
+    };
+    let value = with_decode_scope(&mut scope, || GremlinValue::decode(&mut remaining))?;
+    *slice = remaining;
+    Ok(value)
+}
+
+/// State consulted for every value decoded on the current thread while [`with_decode_scope`]
+/// runs, including values nested in structures.
+#[derive(Debug, Default)]
+struct DecodeScope {
+    allowlist: Option<DecodeAllowlist>,
+    interner: Option<StringInterner>,
+    budget: Option<DecodeBudget>,
+}
+
+thread_local! {
+    static DECODE_SCOPE: RefCell<Option<DecodeScope>> = const { RefCell::new(None) };
+}
+
+/// Hands the scope back to its owner and restores the enclosing scope, also if decoding panics.
+struct DecodeScopeGuard<'a> {
+    scope: &'a mut DecodeScope,
+    previous: Option<DecodeScope>,
+}
+
+impl Drop for DecodeScopeGuard<'_> {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        let current = DECODE_SCOPE.with(|scope| scope.replace(previous));
+        *self.scope = current.unwrap_or_default();
+    }
+}
+
+fn with_decode_scope<T>(
+    scope: &mut DecodeScope,
+    decode: impl FnOnce() -> Result<T, DecodeError>,
+) -> Result<T, DecodeError> {
+    let current = std::mem::take(scope);
+    let previous = DECODE_SCOPE.with(|scope| scope.replace(Some(current)));
+    let _guard = DecodeScopeGuard { scope, previous };
+    decode()
+}
+
+/// Reads the type code and value flag of a fully qualified `GremlinValue` and checks the type
+/// against the allowlist of the active [`DecodeScope`].
+fn decode_value_header<R: Read>(reader: &mut R) -> Result<(CoreType, ValueFlag), DecodeError> {
+    let mut buf = [255_u8; 2];
+    reader.read_exact(&mut buf)?;
+
+    let identifier = CoreType::try_from(buf[0])?;
+    DECODE_SCOPE.with(|scope| match &*scope.borrow() {
+        Some(DecodeScope {
+            allowlist: Some(allowlist),
+            ..
+        }) if !allowlist.contains(identifier) => Err(DecodeError::DisallowedType(buf[0])),
+        _ => Ok(()),
+    })?;
+    let value_flag = ValueFlag::try_from(buf[1])?;
+    Ok((identifier, value_flag))
+}
+
+/// Reads a label or key of `len` bytes, shared through the interner of the active
+/// [`DecodeScope`] if any.
+pub(crate) fn decode_shared_string<R: Read>(
+    reader: &mut R,
+    len: usize,
+) -> Result<Arc<str>, DecodeError> {
+    DECODE_SCOPE.with(|scope| match &mut *scope.borrow_mut() {
+        Some(DecodeScope {
+            interner: Some(interner),
+            ..
+        }) => interner.decode(reader, len),
+        _ => Ok(String::from_utf8(read_bytes(reader, len)?)?.into()),
+    })
+}
+
+/// Cache of the labels and property keys decoded with [`from_slice_with_interner`] or with
+/// [`DecodeConfig::intern_strings`].
+///
+/// Labels and property keys of large results repeat many times. A repeated one is looked up by
+/// its bytes and handed out as a clone of the cached `Arc<str>`, so all decoded elements share one
+/// allocation per distinct string. The interner can be reused across calls.
+#[derive(Debug, Default)]
+pub struct StringInterner {
+    strings: HashSet<InternedString>,
+    scratch: Vec<u8>,
+    hits: usize,
+}
+
+impl StringInterner {
+    #[must_use]
+    pub fn new() -> Self {
+        StringInterner::default()
+    }
+
+    /// Returns the number of distinct strings in the cache.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// Returns how many decoded strings were taken from the cache.
+    #[must_use]
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    fn decode<R: Read>(&mut self, reader: &mut R, len: usize) -> Result<Arc<str>, DecodeError> {
+        self.scratch.clear();
+        reader.take(len as u64).read_to_end(&mut self.scratch)?;
+        if self.scratch.len() != len {
+            return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+        }
+        if let Some(InternedString(s)) = self.strings.get(self.scratch.as_slice()) {
+            self.hits += 1;
+            return Ok(Arc::clone(s));
+        }
+        let s: Arc<str> = std::str::from_utf8(&self.scratch)?.into();
+        self.strings.insert(InternedString(Arc::clone(&s)));
+        Ok(s)
+    }
+}
+
+/// An `Arc<str>` hashed by its bytes, so the cache can be queried with undecoded bytes.
+#[derive(Debug, PartialEq, Eq)]
+struct InternedString(Arc<str>);
+
+impl std::hash::Hash for InternedString {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.as_bytes().hash(state);
+    }
+}
+
+impl std::borrow::Borrow<[u8]> for InternedString {
+    fn borrow(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
+/// Options for decoding a `GremlinValue` with [`from_slice_with_config`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DecodeConfig {
+    /// Skips map entries with a null value instead of inserting
+    /// `GremlinValue::UnspecifiedNullObject`. Applies to maps nested in lists and maps as well.
+    pub null_as_absent: bool,
+    /// Fails with [`DecodeError::BudgetExceeded`] once more values are decoded in total.
+    /// The decoded value and every item of a collection count as one, also the items of the
+    /// collections held by structures like the objects of a path or the entries of a bulk set.
+    /// `None` decodes without a limit.
+    pub max_total_elements: Option<usize>,
+    /// Shares repeated labels and property keys through a [`StringInterner`] that lives for one
+    /// call.
+    pub intern_strings: bool,
+}
+
+impl DecodeConfig {
+    #[must_use]
+    pub fn new() -> Self {
+        DecodeConfig::default()
+    }
+
+    #[must_use]
+    pub fn null_as_absent(mut self, null_as_absent: bool) -> Self {
+        self.null_as_absent = null_as_absent;
+        self
+    }
+
+    #[must_use]
+    pub fn max_total_elements(mut self, max_total_elements: usize) -> Self {
+        self.max_total_elements = Some(max_total_elements);
+        self
+    }
+
+    #[must_use]
+    pub fn intern_strings(mut self, intern_strings: bool) -> Self {
+        self.intern_strings = intern_strings;
+        self
+    }
+}
+
+/// Counts the values decoded with a [`DecodeConfig`] against `max_total_elements`.
+#[derive(Debug)]
+struct DecodeBudget {
+    used: usize,
+    max: usize,
+}
+
+impl DecodeBudget {
+    fn take(&mut self, n: usize) -> Result<(), DecodeError> {
+        self.used = self.used.saturating_add(n);
+        if self.used > self.max {
+            return Err(DecodeError::BudgetExceeded(self.max));
+        }
+        Ok(())
+    }
+}
+
+/// Decodes a `GremlinValue` from the slice like [`from_slice`], applying the options of `config`.
+pub fn from_slice_with_config(
+    slice: &mut &[u8],
+    config: &DecodeConfig,
+) -> Result<GremlinValue, DecodeError> {
+    if config.intern_strings {
+        return from_slice_with_interner(slice, config, &mut StringInterner::new());
+    }
+    let mut scope = DecodeScope {
+        budget: decode_budget(config)?,
+        ..DecodeScope::default()
+    };
+    with_decode_scope(&mut scope, || {
+        decode_gremlin_value_with_config(slice, config)
+    })
+}
+
+/// Decodes a `GremlinValue` like [`from_slice_with_config`], taking repeated strings from
+/// `interner`. The interner keeps the strings of this call for later calls.
+pub fn from_slice_with_interner(
+    slice: &mut &[u8],
+    config: &DecodeConfig,
+    interner: &mut StringInterner,
+) -> Result<GremlinValue, DecodeError> {
+    let mut scope = DecodeScope {
+        interner: Some(std::mem::take(interner)),
+        budget: decode_budget(config)?,
+        ..DecodeScope::default()
+    };
+    let res = with_decode_scope(&mut scope, || {
+        decode_gremlin_value_with_config(slice, config)
+    });
+    *interner = scope.interner.take().unwrap_or_default();
+    res
+}
+
+/// The budget of a decode with `config`, already charged with the decoded value itself.
+fn decode_budget(config: &DecodeConfig) -> Result<Option<DecodeBudget>, DecodeError> {
+    config
+        .max_total_elements
+        .map(|max| {
+            let mut budget = DecodeBudget { used: 0, max };
+            budget.take(1).map(|()| budget)
+        })
+        .transpose()
+}
+
 pub fn from_reader<R: Read, T: Decode>(reader: &mut R) -> Result<T, DecodeError> {
     T::decode(reader)
 }
@@ -65,6 +444,191 @@ pub fn to_writer<W: Write, T: Encode>(value: T, writer: &mut W) -> Result<(), En
     value.encode(writer)
 }
 
+/// Encodes `values` into one buffer, starting with the number of values as a 4 byte integer
+/// followed by each fully qualified value. The counterpart is [`decode_many`].
+pub fn encode_many(values: &[GremlinValue]) -> Result<Vec<u8>, EncodeError> {
+    let mut buf = Vec::new();
+    values.partial_encode(&mut buf)?;
+    Ok(buf)
+}
+
+/// Encodes `value` like [`Encode::encode`] but writes map entries ordered by their encoded key bytes,
+/// so equal values always produce identical bytes regardless of the `HashMap` iteration order.
+///
+/// Every map written while encoding is ordered, also maps nested in graph elements and their
+/// properties, traversers, paths, bulk sets, metrics and strategy configurations.
+pub fn to_bytes_deterministic(value: &GremlinValue) -> Result<Vec<u8>, EncodeError> {
+    let _guard = SortedMapsGuard(SORTED_MAPS.with(|sorted| sorted.replace(true)));
+    let mut buf = Vec::new();
+    value.encode(&mut buf)?;
+    Ok(buf)
+}
+
+thread_local! {
+    static SORTED_MAPS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Restores the enclosing setting when [`to_bytes_deterministic`] returns, also if encoding panics.
+struct SortedMapsGuard(bool);
+
+impl Drop for SortedMapsGuard {
+    fn drop(&mut self) {
+        SORTED_MAPS.with(|sorted| sorted.set(self.0));
+    }
+}
+
+/// Whether maps are encoded ordered by their key bytes on the current thread.
+pub(crate) fn sorted_maps() -> bool {
+    SORTED_MAPS.with(Cell::get)
+}
+
+/// Decodes a buffer written by [`encode_many`], fails with [`DecodeError::TrailingBytes`]
+/// if the buffer is not fully consumed.
+pub fn decode_many(bytes: &[u8]) -> Result<Vec<GremlinValue>, DecodeError> {
+    let mut remaining = bytes;
+    decode_exact(&mut remaining, |reader| Vec::partial_decode(reader))
+}
+
+/// Writes `value` as a length framed record: the length of the encoded value as a 4 byte integer
+/// followed by the fully qualified value. The counterpart is [`read_framed`].
+pub fn write_framed<W: Write>(value: &GremlinValue, writer: &mut W) -> Result<(), EncodeError> {
+    let mut buf = Vec::new();
+    value.encode(&mut buf)?;
+    i32::try_from(buf.len())?.partial_encode(writer)?;
+    writer.write_all(&buf)?;
+    Ok(())
+}
+
+/// Reads the next record written by [`write_framed`], returns None if the reader is at EOF.
+///
+/// Fails with [`DecodeError::TrailingBytes`] if the value does not consume the whole frame.
+pub fn read_framed<R: BufRead>(reader: &mut R) -> Result<Option<GremlinValue>, DecodeError> {
+    if reader.fill_buf()?.is_empty() {
+        return Ok(None);
+    }
+    let len = decode_len(reader)?;
+    let frame = read_bytes(reader, len)?;
+    from_slice_exact(&frame).map(Some)
+}
+
+/// Push based decoder for values arriving in chunks, e.g. from consecutive reads of a socket.
+///
+/// Bytes passed to [`PartialDecoder::feed`] are buffered until a complete value can be decoded,
+/// bytes following the value are retained for the next value.
+///
+/// Every attempt decodes the value from its first byte. After an attempt ran out of bytes, the
+/// next one waits until the buffer holds the bytes that attempt tried to read. Strings and other
+/// byte runs are then retried a logarithmic number of times, but values made of many small items,
+/// fed in small chunks, can still take time quadratic in their size.
+#[derive(Debug, Clone, Default)]
+pub struct PartialDecoder {
+    buf: Vec<u8>,
+    needed: usize,
+}
+
+impl PartialDecoder {
+    #[must_use]
+    pub fn new() -> Self {
+        PartialDecoder::default()
+    }
+
+    /// Appends `bytes` to the buffer and tries to decode a value from it.
+    /// Returns `Ok(None)` if more bytes are needed to complete the value.
+    /// Once a value was returned, call `feed` with an empty slice to decode further values
+    /// already buffered.
+    pub fn feed(&mut self, bytes: &[u8]) -> Result<Option<GremlinValue>, DecodeError> {
+        self.buf.extend_from_slice(bytes);
+        if self.buf.is_empty() || self.buf.len() < self.needed {
+            return Ok(None);
+        }
+
+        let mut reader = ShortReadTracker {
+            slice: &self.buf,
+            pos: 0,
+            needed: 0,
+        };
+        match GremlinValue::decode(&mut reader) {
+            Ok(value) => {
+                let consumed = reader.pos;
+                self.buf.drain(..consumed);
+                self.needed = 0;
+                Ok(Some(value))
+            }
+            Err(DecodeError::IoError(err)) if err.kind() == std::io::ErrorKind::UnexpectedEof => {
+                self.needed = reader.needed;
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Returns the buffered bytes which are not yet decoded.
+    #[must_use]
+    pub fn buffered(&self) -> &[u8] {
+        &self.buf
+    }
+}
+
+/// Reader over a slice remembering how far the reads which hit its end wanted to read.
+struct ShortReadTracker<'a> {
+    slice: &'a [u8],
+    pos: usize,
+    needed: usize,
+}
+
+impl Read for ShortReadTracker<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = buf.len().min(self.slice.len() - self.pos);
+        buf[..n].copy_from_slice(&self.slice[self.pos..self.pos + n]);
+        if n < buf.len() {
+            self.needed = self.needed.max(self.pos + buf.len());
+        }
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+/// Upper bound of items allocated up front when decoding a collection, the declared length
+/// of a collection is not trusted before its items are actually read.
+pub(crate) const MAX_PREALLOCATED_ITEMS: usize = 1024;
+
+/// Reads a length prefix, rejecting negative lengths with [`DecodeError::InvalidLength`].
+pub(crate) fn decode_len<R: Read>(reader: &mut R) -> Result<usize, DecodeError> {
+    let len = i32::partial_decode(reader)?;
+    usize::try_from(len).map_err(|_| DecodeError::InvalidLength(len))
+}
+
+/// Reads the item count of a collection like [`decode_len`] and charges the items to the budget
+/// of the active [`DecodeScope`], before any of them is read.
+pub(crate) fn decode_items_len<R: Read>(reader: &mut R) -> Result<usize, DecodeError> {
+    let len = decode_len(reader)?;
+    DECODE_SCOPE.with(|scope| match &mut *scope.borrow_mut() {
+        Some(DecodeScope {
+            budget: Some(budget),
+            ..
+        }) => budget.take(len),
+        _ => Ok(()),
+    })?;
+    Ok(len)
+}
+
+/// Reads exactly `len` bytes, the buffer grows with the bytes actually read instead of
+/// allocating `len` bytes up front.
+pub(crate) fn read_bytes<R: Read>(reader: &mut R, len: usize) -> Result<Vec<u8>, DecodeError> {
+    let mut buf = Vec::with_capacity(len.min(MAX_PREALLOCATED_ITEMS));
+    reader.take(len as u64).read_to_end(&mut buf)?;
+    if buf.len() != len {
+        return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+    }
+    Ok(buf)
+}
+
+/// Writes a typed null, the type code of `core` followed by [`ValueFlag::Null`].
+pub fn write_typed_null<W: Write>(core: CoreType, writer: &mut W) -> Result<(), EncodeError> {
+    writer.write_all(&[core.into(), ValueFlag::Null.into()])?;
+    Ok(())
+}
+
 pub(super) fn encode_null_object<W: Write>(writer: &mut W) -> Result<(), EncodeError> {
     writer.write_all(&[
         CoreType::UnspecifiedNullObject.into(),
@@ -73,13 +637,62 @@ pub(super) fn encode_null_object<W: Write>(writer: &mut W) -> Result<(), EncodeE
     Ok(())
 }
 
+/// Reads a type from GraphBinary, the counterpart of [`Encode`].
+///
+/// Implementing both traits lets a domain type be read and written like the types of this
+/// crate, here a newtype that is transferred as a GraphBinary `String`:
+///
+/// ```
+/// use std::io::{Read, Write};
+///
+/// use tinkerpop_io::binary::{from_slice_exact, CoreType, Decode, Encode};
+/// use tinkerpop_io::error::{DecodeError, EncodeError};
+/// use tinkerpop_io::GremlinValue;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Email(String);
+///
+/// impl Encode for Email {
+///     fn type_code() -> u8 {
+///         CoreType::String.into()
+///     }
+///
+///     fn partial_encode<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+///         self.0.partial_encode(writer)
+///     }
+/// }
+///
+/// impl Decode for Email {
+///     fn expected_type_code() -> u8 {
+///         CoreType::String.into()
+///     }
+///
+///     fn partial_decode<R: Read>(reader: &mut R) -> Result<Self, DecodeError> {
+///         String::partial_decode(reader).map(Email)
+///     }
+/// }
+///
+/// let email = Email("marko@example.com".to_string());
+/// let mut bytes = Vec::new();
+/// email.encode(&mut bytes).unwrap();
+///
+/// assert_eq!(email, from_slice_exact::<Email>(&bytes).unwrap());
+/// assert_eq!(
+///     GremlinValue::from("marko@example.com"),
+///     from_slice_exact::<GremlinValue>(&bytes).unwrap()
+/// );
+/// ```
 pub trait Decode {
     fn expected_type_code() -> u8;
 
+    /// Decodes the value bytes only, the type code and value flag must already be consumed.
+    /// Advances the reader past the value bytes.
     fn partial_decode<R: Read>(reader: &mut R) -> Result<Self, DecodeError>
     where
         Self: std::marker::Sized;
 
+    /// Decodes a fully qualified value. Advances the reader past the type code,
+    /// the value flag and the value bytes.
     fn decode<R: Read>(reader: &mut R) -> Result<Self, DecodeError>
     where
         Self: std::marker::Sized,
@@ -97,6 +710,8 @@ pub trait Decode {
         }
     }
 
+    /// Decodes a value prefixed only by its value flag. Advances the reader past the
+    /// value flag and, if the flag is set, past the value bytes.
     fn nullable_decode<R: Read>(reader: &mut R) -> Result<Option<Self>, DecodeError>
     where
         Self: std::marker::Sized,
@@ -104,7 +719,7 @@ pub trait Decode {
         let mut buf = [255_u8; 1];
         reader.read_exact(&mut buf)?;
         match buf[0] {
-            0 => Ok(Self::partial_decode(reader).ok()),
+            0 => Self::partial_decode(reader).map(Some),
             1 => Ok(None),
             err => Err(DecodeError::DecodeError(format!(
                 "found {} expected 0 or 1",
@@ -114,6 +729,8 @@ pub trait Decode {
     }
 }
 
+/// Writes a type as GraphBinary, see [`Decode`] for an example of implementing both traits
+/// for a domain type.
 pub trait Encode {
     fn type_code() -> u8;
 
@@ -180,7 +797,10 @@ impl Encode for GremlinValue {
                 writer.write_all(&[0x05, 0x0])?;
                 val.partial_encode(writer)
             }
-            GremlinValue::Class(val) => val.encode(writer),
+            GremlinValue::Class(val) => {
+                writer.write_all(&[0x06, 0x0])?;
+                val.partial_encode(writer)
+            }
             GremlinValue::Double(val) => val.encode(writer),
             GremlinValue::Float(val) => val.encode(writer),
             GremlinValue::List(val) => val.encode(writer),
@@ -217,11 +837,11 @@ impl Encode for GremlinValue {
             GremlinValue::TextP(val) => val.encode(writer),
             GremlinValue::TraversalStrategy(val) => val.encode(writer),
             GremlinValue::BulkSet(val) => val.encode(writer),
+            GremlinValue::Tree(val) => val.encode(writer),
             GremlinValue::Metrics(val) => val.encode(writer),
             GremlinValue::TraversalMetrics(val) => val.encode(writer),
             GremlinValue::Merge(val) => val.encode(writer),
             GremlinValue::UnspecifiedNullObject => encode_null_object(writer),
-            #[cfg(feature = "extended")]
             GremlinValue::Char(val) => val.encode(writer),
             #[cfg(feature = "extended")]
             GremlinValue::Duration(val) => val.encode(writer),
@@ -259,12 +879,51 @@ impl Encode for GremlinValue {
 }
 
 fn decode_gremlin_value<R: Read>(reader: &mut R) -> Result<GremlinValue, DecodeError> {
-    let mut buf = [255_u8; 2];
-    reader.read_exact(&mut buf)?;
+    let (identifier, value_flag) = decode_value_header(reader)?;
+    decode_gremlin_value_body(identifier, value_flag, reader)
+}
 
-    let identifier = CoreType::try_from(buf[0])?;
-    let value_flag = ValueFlag::try_from(buf[1])?;
+fn decode_gremlin_value_with_config<R: Read>(
+    reader: &mut R,
+    config: &DecodeConfig,
+) -> Result<GremlinValue, DecodeError> {
+    let (identifier, value_flag) = decode_value_header(reader)?;
 
+    match (identifier, value_flag) {
+        (CoreType::List | CoreType::Set, ValueFlag::Set) => {
+            let len = decode_items_len(reader)?;
+            let mut list = Vec::with_capacity(len.min(MAX_PREALLOCATED_ITEMS));
+            for _ in 0..len {
+                list.push(decode_gremlin_value_with_config(reader, config)?);
+            }
+            if identifier == CoreType::Set {
+                Ok(GremlinValue::Set(Set::new(list)))
+            } else {
+                Ok(GremlinValue::List(list))
+            }
+        }
+        (CoreType::Map, ValueFlag::Set) => {
+            let len = decode_items_len(reader)?;
+            let mut map = HashMap::with_capacity(len.min(MAX_PREALLOCATED_ITEMS));
+            for _ in 0..len {
+                let key = MapKeys::decode(reader)?;
+                let value = decode_gremlin_value_with_config(reader, config)?;
+                if config.null_as_absent && value == GremlinValue::UnspecifiedNullObject {
+                    continue;
+                }
+                map.insert(key, value);
+            }
+            Ok(GremlinValue::Map(map))
+        }
+        (identifier, value_flag) => decode_gremlin_value_body(identifier, value_flag, reader),
+    }
+}
+
+fn decode_gremlin_value_body<R: Read>(
+    identifier: CoreType,
+    value_flag: ValueFlag,
+    reader: &mut R,
+) -> Result<GremlinValue, DecodeError> {
     match (identifier, value_flag) {
         (_, ValueFlag::Null) => Ok(GremlinValue::UnspecifiedNullObject),
         (CoreType::Int32, _) => Ok(GremlinValue::Int(i32::partial_decode(reader)?)),
@@ -328,10 +987,10 @@ fn decode_gremlin_value<R: Read>(reader: &mut R) -> Result<GremlinValue, DecodeE
         )),
         (CoreType::Merge, _) => Ok(GremlinValue::Merge(Merge::partial_decode(reader)?)),
         (CoreType::BulkSet, _) => Ok(GremlinValue::BulkSet(BulkSet::partial_decode(reader)?)),
+        (CoreType::Tree, _) => Ok(GremlinValue::Tree(Tree::partial_decode(reader)?)),
         (CoreType::UnspecifiedNullObject, _) => Err(DecodeError::DecodeError(
-            "UnspecifiedNullObject wrong valueflag".to_string(),
+            "found [0xfe,0x0], UnspecifiedNullObject must carry the null value flag".to_string(),
         )),
-        #[cfg(feature = "extended")]
         (CoreType::Char, _) => Ok(GremlinValue::Char(char::partial_decode(reader)?)),
         #[cfg(feature = "extended")]
         (CoreType::Duration, _) => Ok(GremlinValue::Duration(Duration::partial_decode(reader)?)),
@@ -375,7 +1034,10 @@ fn decode_gremlin_value<R: Read>(reader: &mut R) -> Result<GremlinValue, DecodeE
         )?)),
         #[cfg(feature = "custom")]
         (CoreType::Custom, _) => Ok(GremlinValue::Custom(Custom::partial_decode(reader)?)),
-        // _ => Err(DecodeError::DecodeError(format!(""))),
+        #[cfg(not(all(feature = "extended", feature = "custom")))]
+        (core_type, _) => Err(DecodeError::DecodeError(format!(
+            "decoding {core_type:?} requires the extended or custom feature"
+        ))),
     }
 }
 
@@ -408,3 +1070,605 @@ impl From<ValueFlag> for u8 {
         }
     }
 }
+
+#[test]
+fn from_slice_exact_test() {
+    let reader = [0x01, 0x0, 0x0, 0x0, 0x0, 0x2a];
+    assert_eq!(from_slice_exact::<i32>(&reader).unwrap(), 42);
+}
+
+#[test]
+fn from_slice_exact_trailing_bytes() {
+    let reader = [0x01, 0x0, 0x0, 0x0, 0x0, 0x2a, 0xff];
+    let res = from_slice_exact::<i32>(&reader);
+    assert!(matches!(res, Err(DecodeError::TrailingBytes(1))));
+}
+
+#[test]
+fn from_slice_allowed_test() {
+    let allowlist = DecodeAllowlist::new([CoreType::Int32, CoreType::Long]);
+
+    let bytes = [0x01, 0x0, 0x0, 0x0, 0x0, 0x01];
+    let res = from_slice_allowed(&mut &bytes[..], &allowlist).unwrap();
+    assert_eq!(res, GremlinValue::Int(1));
+
+    let bytes = [0x03, 0x0, 0x0, 0x0, 0x0, 0x01, b'a'];
+    let mut slice = &bytes[..];
+    let res = from_slice_allowed(&mut slice, &allowlist);
+    assert!(matches!(res, Err(DecodeError::DisallowedType(0x03))));
+    assert_eq!(slice.len(), bytes.len());
+}
+
+#[test]
+fn from_slice_allowed_nested() {
+    let allowlist = DecodeAllowlist::new([CoreType::List, CoreType::Int32]);
+
+    let bytes = [
+        0x09, 0x0, 0x0, 0x0, 0x0, 0x02, // list with two items
+        0x01, 0x0, 0x0, 0x0, 0x0, 0x01, // 1
+        0x03, 0x0, 0x0, 0x0, 0x0, 0x01, b'a', // "a"
+    ];
+    let mut slice = &bytes[..];
+    let res = from_slice_allowed(&mut slice, &allowlist);
+    assert!(matches!(res, Err(DecodeError::DisallowedType(0x03))));
+    assert_eq!(slice.len(), bytes.len());
+
+    // the limits only apply inside from_slice_allowed
+    let res = GremlinValue::decode(&mut &bytes[..]).unwrap();
+    assert_eq!(res, GremlinValue::List(vec![1.into(), "a".into()]));
+
+    let allowlist = DecodeAllowlist::new([CoreType::List, CoreType::Int32, CoreType::String]);
+    let res = from_slice_allowed(&mut &bytes[..], &allowlist).unwrap();
+    assert_eq!(res, GremlinValue::List(vec![1.into(), "a".into()]));
+}
+
+#[test]
+fn map_null_as_absent() {
+    let bytes = [
+        0x0a, 0x0, 0x0, 0x0, 0x0, 0x02, // map with two entries
+        0x03, 0x0, 0x0, 0x0, 0x0, 0x01, b'a', 0x01, 0x0, 0x0, 0x0, 0x0, 0x01, // "a": 1
+        0x03, 0x0, 0x0, 0x0, 0x0, 0x01, b'b', 0xfe, 0x01, // "b": null
+    ];
+
+    let res = from_slice_with_config(&mut &bytes[..], &DecodeConfig::new()).unwrap();
+    let expected = HashMap::from([
+        (MapKeys::from("a"), GremlinValue::Int(1)),
+        (MapKeys::from("b"), GremlinValue::UnspecifiedNullObject),
+    ]);
+    assert_eq!(res, GremlinValue::Map(expected));
+
+    let config = DecodeConfig::new().null_as_absent(true);
+    let res = from_slice_with_config(&mut &bytes[..], &config).unwrap();
+    let expected = HashMap::from([(MapKeys::from("a"), GremlinValue::Int(1))]);
+    assert_eq!(res, GremlinValue::Map(expected));
+}
+
+#[test]
+fn decode_budget_exceeded() {
+    let len = 1_000_000;
+    let mut bytes = vec![0x09, 0x0];
+    bytes.extend_from_slice(&(len as i32).to_be_bytes());
+    for i in 0..len {
+        bytes.extend_from_slice(&[0x01, 0x0]);
+        bytes.extend_from_slice(&(i as i32).to_be_bytes());
+    }
+
+    let config = DecodeConfig::new().max_total_elements(100_000);
+    let res = from_slice_with_config(&mut &bytes[..], &config);
+    assert!(matches!(res, Err(DecodeError::BudgetExceeded(100_000))));
+
+    let config = DecodeConfig::new().max_total_elements(len + 1);
+    let res = from_slice_with_config(&mut &bytes[..], &config).unwrap();
+    assert!(matches!(res, GremlinValue::List(list) if list.len() == len));
+
+    // nested lists announce few items each but exceed the budget in total
+    let mut bytes = vec![0x09, 0x0, 0x0, 0x0, 0x0, 0x03];
+    for _ in 0..3 {
+        bytes.extend_from_slice(&[0x09, 0x0, 0x0, 0x0, 0x0, 0x02]);
+        bytes.extend_from_slice(&[
+            0x01, 0x0, 0x0, 0x0, 0x0, 0x01, 0x01, 0x0, 0x0, 0x0, 0x0, 0x02,
+        ]);
+    }
+    let config = DecodeConfig::new().max_total_elements(9);
+    let res = from_slice_with_config(&mut &bytes[..], &config);
+    assert!(matches!(res, Err(DecodeError::BudgetExceeded(9))));
+    let config = DecodeConfig::new().max_total_elements(10);
+    assert!(from_slice_with_config(&mut &bytes[..], &config).is_ok());
+}
+
+#[test]
+fn decode_budget_exceeded_in_structures() {
+    let len = 100_000;
+    let bulk_set = GremlinValue::BulkSet(BulkSet::new(
+        (0..len).map(|i| (GremlinValue::Int(i), 1)).collect(),
+    ));
+    let path = GremlinValue::Path(Path {
+        labels: (0..len).map(|i| Set::new(vec![i.to_string()])).collect(),
+        objects: (0..len).map(GremlinValue::Int).collect(),
+    });
+
+    for value in [bulk_set, path] {
+        let mut bytes = Vec::new();
+        value.encode(&mut bytes).unwrap();
+
+        let config = DecodeConfig::new().max_total_elements(1_000);
+        let res = from_slice_with_config(&mut &bytes[..], &config);
+        assert!(matches!(res, Err(DecodeError::BudgetExceeded(1_000))));
+
+        let config = DecodeConfig::new()
+            .max_total_elements(1_000)
+            .intern_strings(true);
+        let res = from_slice_with_config(&mut &bytes[..], &config);
+        assert!(matches!(res, Err(DecodeError::BudgetExceeded(1_000))));
+
+        let config = DecodeConfig::new().max_total_elements(4 * len as usize);
+        assert_eq!(
+            value,
+            from_slice_with_config(&mut &bytes[..], &config).unwrap()
+        );
+    }
+}
+
+#[test]
+fn decode_interned_graph_memory() {
+    let vertices = (0..1000_i64)
+        .map(|id| {
+            let name = VertexProperty::new(
+                id + 1000,
+                "name",
+                format!("person {id}"),
+                None,
+                Some(Vec::new()),
+            );
+            Vertex::new(id, "person", Some(vec![name]))
+        })
+        .collect();
+    let graph = GremlinValue::Graph(Graph {
+        vertices,
+        edges: Vec::new(),
+    });
+    let mut bytes = Vec::new();
+    graph.encode(&mut bytes).unwrap();
+
+    let plain = from_slice_with_config(&mut &bytes[..], &DecodeConfig::new()).unwrap();
+    let config = DecodeConfig::new().intern_strings(true);
+    let interned = from_slice_with_config(&mut &bytes[..], &config).unwrap();
+    assert_eq!(plain, graph);
+    assert_eq!(interned, graph);
+
+    let GremlinValue::Graph(Graph { vertices, .. }) = &interned else {
+        panic!("expected a graph")
+    };
+    assert!(vertices
+        .iter()
+        .all(|vertex| Arc::ptr_eq(&vertex.label, &vertices[0].label)));
+    assert_eq!(Arc::strong_count(&vertices[0].label), 1000);
+
+    // each vertex drops its own copy of the label and the property key
+    let saved = plain.heap_size() - interned.heap_size();
+    assert!(saved >= 1000 * ("person".len() + "name".len()));
+
+    let mut interner = StringInterner::new();
+    let res = from_slice_with_interner(&mut &bytes[..], &DecodeConfig::new(), &mut interner);
+    assert_eq!(res.unwrap(), graph);
+    assert_eq!(interner.len(), 2);
+    assert_eq!(interner.hits(), 2 * 999);
+}
+
+#[test]
+fn encode_decode_many() {
+    let values = vec![
+        GremlinValue::Int(1),
+        GremlinValue::from("test"),
+        GremlinValue::Vertex(Vertex::new(1_i64, "person", None)),
+    ];
+
+    let bytes = encode_many(&values).unwrap();
+    assert_eq!(&bytes[..4], &[0x0, 0x0, 0x0, 0x03]);
+    assert_eq!(decode_many(&bytes).unwrap(), values);
+}
+
+#[test]
+fn typed_null_int() {
+    let mut buf = Vec::new();
+    write_typed_null(CoreType::Int32, &mut buf).unwrap();
+    assert_eq!(buf, [0x01, 0x01]);
+
+    assert_eq!(
+        GremlinValue::decode(&mut &buf[..]).unwrap(),
+        GremlinValue::UnspecifiedNullObject
+    );
+    assert_eq!(Option::<i32>::decode(&mut &buf[..]).unwrap(), None);
+}
+
+#[test]
+fn partial_decoder_byte_by_byte() {
+    let bytes = [0x03, 0x0, 0x0, 0x0, 0x0, 0x04, b't', b'e', b's', b't', 0x01];
+    let mut decoder = PartialDecoder::new();
+
+    let mut values = Vec::new();
+    for byte in &bytes[..bytes.len() - 1] {
+        if let Some(value) = decoder.feed(&[*byte]).unwrap() {
+            values.push(value);
+        }
+    }
+    assert_eq!(values, vec![GremlinValue::from("test")]);
+    assert!(decoder.buffered().is_empty());
+
+    assert_eq!(decoder.feed(&bytes[bytes.len() - 1..]).unwrap(), None);
+    assert_eq!(decoder.buffered(), &[0x01]);
+}
+
+#[test]
+fn partial_decoder_long_string() {
+    let text = "a".repeat(1 << 20);
+    let mut bytes = Vec::new();
+    text.as_str().encode(&mut bytes).unwrap();
+
+    let mut decoder = PartialDecoder::new();
+    let mut values = Vec::new();
+    for chunk in bytes.chunks(4) {
+        values.extend(decoder.feed(chunk).unwrap());
+    }
+    assert_eq!(values, vec![GremlinValue::String(text)]);
+    assert!(decoder.buffered().is_empty());
+}
+
+#[test]
+fn unspecified_null_object_value_flag_set() {
+    let bytes = [0xfe, 0x00];
+    assert!(GremlinValue::decode(&mut &bytes[..]).is_err());
+    assert!(from_slice_with_config(&mut &bytes[..], &DecodeConfig::new()).is_err());
+    assert!(Option::<i32>::decode(&mut &bytes[..]).is_err());
+
+    let bytes = [0xfe, 0x01];
+    assert_eq!(
+        GremlinValue::decode(&mut &bytes[..]).unwrap(),
+        GremlinValue::UnspecifiedNullObject
+    );
+}
+
+#[test]
+fn hexdump_format() {
+    let bytes = [
+        0x03, 0x00, 0x00, 0x00, 0x00, 0x05, b'm', b'a', b'r', b'k', b'o', 0x01, 0x00, 0x00, 0x00,
+        0x00, 0x1d, 0x01,
+    ];
+    assert_eq!(
+        "00000000: 0300 0000 0005 6d61 726b 6f01 0000 0000  ......marko.....\n\
+         00000010: 1d01                                     ..\n",
+        hexdump(&bytes)
+    );
+}
+
+#[test]
+fn decode_error_at_offset() {
+    let bytes = [0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0xff];
+    match from_slice_debug::<GremlinValue>(&bytes) {
+        Err(DecodeError::AtOffset {
+            offset,
+            snippet: Some(snippet),
+            ..
+        }) => {
+            assert_eq!(6, offset);
+            assert_eq!(
+                "00000000: 0100 0000 0001 ff                        .......\n",
+                snippet
+            );
+        }
+        other => panic!("expected AtOffset error, got {other:?}"),
+    }
+}
+
+#[cfg(feature = "graph_son")]
+#[test]
+fn map_to_json() {
+    let bytes = [
+        0x0a, 0x00, 0x00, 0x00, 0x00, 0x01, 0x03, 0x00, 0x00, 0x00, 0x00, 0x04, b'n', b'a', b'm',
+        b'e', 0x09, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0x00, 0x00, 0x00, 0x00, 0x05, b'm', b'a',
+        b'r', b'k', b'o', 0x01, 0x00, 0x00, 0x00, 0x00, 0x1d,
+    ];
+
+    assert_eq!(
+        serde_json::json!({"name": ["marko", 29]}),
+        to_json(&bytes).unwrap()
+    );
+}
+
+#[cfg(feature = "graph_son")]
+#[test]
+fn bulk_set_to_json() {
+    let bytes = [
+        0x2a, 0x00, 0x00, 0x00, 0x00, 0x01, 0x03, 0x00, 0x00, 0x00, 0x00, 0x01, b'a', 0x7f, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    ];
+    assert_eq!(
+        serde_json::json!([{"value": "a", "bulk": i64::MAX}]),
+        to_json(&bytes).unwrap()
+    );
+
+    // a bulk set announcing i32::MAX items without any following
+    let bytes = [0x2a, 0x00, 0x7f, 0xff, 0xff, 0xff];
+    assert!(to_json(&bytes).is_err());
+}
+
+#[test]
+fn check_protocol_version() {
+    assert!(check_version(protocol_version()).is_ok());
+    assert!(matches!(
+        check_version(0x82),
+        Err(DecodeError::UnsupportedVersion(0x82))
+    ));
+}
+
+/// Returns a sample value for every `CoreType`, or None if the type needs a disabled feature or
+/// a registered custom type. The match deliberately has no wildcard, so a new `CoreType` fails
+/// to compile until it is mapped to a `GremlinValue` variant here.
+#[cfg(test)]
+fn coretype_exhaustive(core_type: CoreType) -> Option<GremlinValue> {
+    let value = match core_type {
+        CoreType::Int32 => GremlinValue::Int(1),
+        CoreType::Long => GremlinValue::Long(1),
+        CoreType::String => GremlinValue::from("marko"),
+        CoreType::Date => GremlinValue::Date(1),
+        CoreType::Timestamp => GremlinValue::Timestamp(1),
+        CoreType::Class => GremlinValue::Class("java.lang.String".to_string()),
+        CoreType::Double => GremlinValue::Double(1.0),
+        CoreType::Float => GremlinValue::Float(1.0),
+        CoreType::List => GremlinValue::List(vec![1.into()]),
+        CoreType::Set => GremlinValue::Set(Set::new(vec![1.into()])),
+        CoreType::Map => GremlinValue::Map(HashMap::from([("marko".into(), 1.into())])),
+        CoreType::Uuid => GremlinValue::Uuid(Uuid::from_u128(1)),
+        CoreType::Edge => GremlinValue::Edge(Edge {
+            id: 7.into(),
+            label: "knows".into(),
+            in_v_id: 2.into(),
+            in_v_label: "person".into(),
+            out_v_id: 1.into(),
+            out_v_label: "person".into(),
+            parent: None,
+            properties: None,
+        }),
+        CoreType::Path => GremlinValue::Path(Path {
+            labels: vec![Set::new(vec!["a".to_string()])],
+            objects: vec![1.into()],
+        }),
+        CoreType::Property => GremlinValue::Property(Property::new(
+            "weight",
+            0.5_f64,
+            crate::structure::property::EitherParent::None,
+        )),
+        CoreType::Graph => GremlinValue::Graph(Graph {
+            vertices: vec![Vertex::new(1, "person", Some(Vec::new()))],
+            edges: Vec::new(),
+        }),
+        CoreType::Vertex => GremlinValue::Vertex(Vertex::new(1, "person", None)),
+        CoreType::VertexProperty => {
+            GremlinValue::VertexProperty(VertexProperty::new(0_i64, "name", "marko", None, None))
+        }
+        CoreType::Barrier => GremlinValue::Barrier(Barrier::NormSack),
+        CoreType::Binding => GremlinValue::Binding(Binding::new("x", 1)),
+        CoreType::ByteCode => GremlinValue::Bytecode(Bytecode::new()),
+        CoreType::Cardinality => GremlinValue::Cardinality(Cardinality::List),
+        CoreType::Column => GremlinValue::Column(Column::Keys),
+        CoreType::Direction => GremlinValue::Direction(Direction::Out),
+        CoreType::Operator => GremlinValue::Operator(Operator::Sum),
+        CoreType::Order => GremlinValue::Order(Order::Asc),
+        CoreType::Pick => GremlinValue::Pick(Pick::Any),
+        CoreType::Pop => GremlinValue::Pop(Pop::All),
+        CoreType::Lambda => GremlinValue::Lambda(Lambda::new("it.get()")),
+        CoreType::P => GremlinValue::P(P::eq(1.into())),
+        CoreType::Scope => GremlinValue::Scope(Scope::Local),
+        CoreType::T => GremlinValue::T(T::Id),
+        CoreType::Traverser => GremlinValue::Traverser(Traverser::new(1.into())),
+        CoreType::BigInteger => GremlinValue::BigInteger(BigInt::from(1)),
+        CoreType::BigDecimal => GremlinValue::BigDecimal(BigDecimal::from(1)),
+        CoreType::Byte => GremlinValue::Byte(1),
+        CoreType::ByteBuffer => GremlinValue::ByteBuffer(ByteBuffer::new(vec![1])),
+        CoreType::Short => GremlinValue::Short(1),
+        CoreType::Boolean => GremlinValue::Boolean(true),
+        CoreType::TextP => GremlinValue::TextP(TextP::starting_with("ma")),
+        CoreType::TraversalStrategy => GremlinValue::TraversalStrategy(TraversalStrategy::new(
+            "org.apache.tinkerpop.gremlin.process.traversal.strategy.decoration.SubgraphStrategy",
+            HashMap::new(),
+        )),
+        CoreType::BulkSet => GremlinValue::BulkSet(BulkSet::new(vec![(1.into(), 2)])),
+        CoreType::Tree => GremlinValue::Tree(Tree::new()),
+        CoreType::Metrics => GremlinValue::Metrics(Metrics {
+            id: "7.0.0()".to_string(),
+            name: "TinkerGraphStep(vertex,[])".to_string(),
+            duration: 1,
+            counts: HashMap::new(),
+            annotations: HashMap::new(),
+            nested_metrics: Vec::new(),
+        }),
+        CoreType::TraversalMetrics => {
+            GremlinValue::TraversalMetrics(TraversalMetrics::new(1, Vec::new()))
+        }
+        CoreType::Merge => GremlinValue::Merge(Merge::OnCreate),
+        CoreType::Custom => return None,
+        CoreType::UnspecifiedNullObject => GremlinValue::UnspecifiedNullObject,
+        CoreType::Char => GremlinValue::Char('a'),
+        #[cfg(feature = "extended")]
+        CoreType::Duration => GremlinValue::Duration(Duration::seconds(1)),
+        #[cfg(feature = "extended")]
+        CoreType::InetAddress => GremlinValue::InetAddress(std::net::Ipv4Addr::LOCALHOST.into()),
+        #[cfg(feature = "extended")]
+        CoreType::Instant => GremlinValue::Instant(Instant::new(1, 0)),
+        #[cfg(feature = "extended")]
+        CoreType::LocalDate => {
+            GremlinValue::LocalDate(NaiveDate::from_ymd_opt(2022, 1, 1).unwrap())
+        }
+        #[cfg(feature = "extended")]
+        CoreType::LocalDateTime => GremlinValue::LocalDateTime(
+            NaiveDate::from_ymd_opt(2022, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+        ),
+        #[cfg(feature = "extended")]
+        CoreType::LocalTime => GremlinValue::LocalTime(NaiveTime::from_hms_opt(1, 2, 3).unwrap()),
+        #[cfg(feature = "extended")]
+        CoreType::MonthDay => GremlinValue::MonthDay(MonthDay { month: 1, day: 2 }),
+        #[cfg(feature = "extended")]
+        CoreType::OffsetDateTime => GremlinValue::OffsetDateTime(
+            DateTime::parse_from_rfc3339("2022-01-01T00:00:00+01:00").unwrap(),
+        ),
+        #[cfg(feature = "extended")]
+        CoreType::OffsetTime => GremlinValue::OffsetTime(OffsetTime {
+            time: NaiveTime::from_hms_opt(1, 2, 3).unwrap(),
+            offset: FixedOffset::east_opt(3600).unwrap(),
+        }),
+        #[cfg(feature = "extended")]
+        CoreType::Period => GremlinValue::Period(Period::new(1, 2, 3)),
+        #[cfg(feature = "extended")]
+        CoreType::Year => GremlinValue::Year(Year(2022)),
+        #[cfg(feature = "extended")]
+        CoreType::YearMonth => GremlinValue::YearMonth(YearMonth {
+            year: 2022,
+            month: 1,
+        }),
+        #[cfg(feature = "extended")]
+        CoreType::ZonedDateTime => GremlinValue::ZonedDateTime(ZonedDateTime(
+            DateTime::parse_from_rfc3339("2022-01-01T00:00:00+01:00").unwrap(),
+        )),
+        #[cfg(feature = "extended")]
+        CoreType::ZoneOffset => GremlinValue::ZoneOffset(FixedOffset::east_opt(3600).unwrap()),
+        #[cfg(not(feature = "extended"))]
+        CoreType::Duration
+        | CoreType::InetAddress
+        | CoreType::Instant
+        | CoreType::LocalDate
+        | CoreType::LocalDateTime
+        | CoreType::LocalTime
+        | CoreType::MonthDay
+        | CoreType::OffsetDateTime
+        | CoreType::OffsetTime
+        | CoreType::Period
+        | CoreType::Year
+        | CoreType::YearMonth
+        | CoreType::ZonedDateTime
+        | CoreType::ZoneOffset => return None,
+    };
+    Some(value)
+}
+
+#[test]
+fn coretype_maps_to_gremlin_value() {
+    for code in 0..=u8::MAX {
+        let Ok(core_type) = CoreType::try_from(code) else {
+            continue;
+        };
+        let Some(value) = coretype_exhaustive(core_type) else {
+            continue;
+        };
+        let mut buf = Vec::new();
+        value.encode(&mut buf).unwrap();
+        assert_eq!(
+            code, buf[0],
+            "{core_type:?} is encoded with another type code"
+        );
+        assert_eq!(
+            value,
+            from_slice_exact::<GremlinValue>(&buf).unwrap(),
+            "{core_type:?} does not roundtrip"
+        );
+    }
+}
+
+#[test]
+fn deterministic_map_bytes() {
+    let keys = ["a", "b", "c", "d", "e", "f", "g", "h"];
+
+    let mut forward = HashMap::new();
+    for (i, key) in keys.iter().enumerate() {
+        forward.insert(MapKeys::from(*key), GremlinValue::Int(i as i32));
+    }
+    let mut backward = HashMap::new();
+    for (i, key) in keys.iter().enumerate().rev() {
+        backward.insert(MapKeys::from(*key), GremlinValue::Int(i as i32));
+    }
+
+    let forward = GremlinValue::List(vec![GremlinValue::Map(forward)]);
+    let backward = GremlinValue::List(vec![GremlinValue::Map(backward)]);
+
+    let bytes = to_bytes_deterministic(&forward).unwrap();
+    assert_eq!(bytes, to_bytes_deterministic(&backward).unwrap());
+    assert_eq!(forward, from_slice_exact::<GremlinValue>(&bytes).unwrap());
+}
+
+#[test]
+fn deterministic_map_bytes_in_structures() {
+    let nested = |keys: &mut dyn Iterator<Item = &str>| {
+        let map: HashMap<MapKeys, GremlinValue> =
+            keys.map(|key| (key.into(), key.into())).collect();
+        let map = GremlinValue::Map(map);
+        let vertex = Vertex::new(
+            1,
+            "person",
+            Some(vec![VertexProperty::new(
+                0_i64,
+                "name",
+                map.clone(),
+                None,
+                None,
+            )]),
+        );
+        let edge = Edge {
+            id: 7.into(),
+            label: "knows".into(),
+            in_v_id: 2.into(),
+            in_v_label: "person".into(),
+            out_v_id: 1.into(),
+            out_v_label: "person".into(),
+            parent: None,
+            properties: Some(vec![Property::new(
+                "weight",
+                map.clone(),
+                crate::structure::property::EitherParent::None,
+            )]),
+        };
+        GremlinValue::List(vec![
+            vertex.into(),
+            edge.into(),
+            Traverser::new(map.clone()).into(),
+            GremlinValue::Path(Path {
+                labels: vec![Set::new(vec!["a".to_string()])],
+                objects: vec![map.clone()],
+            }),
+            GremlinValue::BulkSet(BulkSet::new(vec![(map, 2)])),
+        ])
+    };
+    let keys = ["a", "b", "c", "d", "e", "f", "g", "h"];
+
+    let forward = nested(&mut keys.iter().copied());
+    let backward = nested(&mut keys.iter().rev().copied());
+
+    let bytes = to_bytes_deterministic(&forward).unwrap();
+    assert_eq!(bytes, to_bytes_deterministic(&backward).unwrap());
+    assert_eq!(forward, from_slice_exact::<GremlinValue>(&bytes).unwrap());
+}
+
+#[test]
+fn framed_roundtrip() {
+    let values = [
+        GremlinValue::from("marko"),
+        GremlinValue::List(vec![1.into(), 2.into()]),
+    ];
+    let mut buf = Vec::new();
+    for value in &values {
+        write_framed(value, &mut buf).unwrap();
+    }
+
+    let mut cursor = std::io::Cursor::new(buf);
+    assert_eq!(Some(values[0].clone()), read_framed(&mut cursor).unwrap());
+    assert_eq!(Some(values[1].clone()), read_framed(&mut cursor).unwrap());
+    assert_eq!(None, read_framed(&mut cursor).unwrap());
+
+    // the frame announces one byte more than the Int consumes
+    let mut cursor = std::io::Cursor::new([0x0, 0x0, 0x0, 0x7, 0x1, 0x0, 0x0, 0x0, 0x0, 0x1, 0x0]);
+    assert!(matches!(
+        read_framed(&mut cursor),
+        Err(DecodeError::TrailingBytes(1))
+    ));
+}
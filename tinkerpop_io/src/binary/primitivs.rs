@@ -1,5 +1,6 @@
 use core::slice;
 use std::io::Read;
+use std::sync::Arc;
 
 use uuid::Uuid;
 
@@ -8,7 +9,7 @@ use crate::{
     specs::CoreType,
 };
 
-use super::{encode_null_object, Decode, Encode};
+use super::{decode_len, decode_shared_string, encode_null_object, read_bytes, Decode, Encode};
 
 impl Encode for String {
     fn type_code() -> u8 {
@@ -29,26 +30,29 @@ impl Decode for String {
     }
 
     fn partial_decode<R: Read>(reader: &mut R) -> Result<String, DecodeError> {
-        let len = i32::partial_decode(reader)?;
+        let len = decode_len(reader)?;
+        Ok(String::from_utf8(read_bytes(reader, len)?)?)
+    }
+}
 
-        if len < 0 {
-            return Err(DecodeError::DecodeError("size negativ".to_string()));
-        }
-        let s = String::from_utf8(
-            reader
-                .bytes()
-                .take(len as usize)
-                .filter_map(Result::ok)
-                .collect(),
-        )?;
-
-        match s.len() {
-            l if l != len as usize => Err(DecodeError::DecodeError(format!(
-                "String {} len not expected lenth of {}",
-                s, len
-            ))),
-            _ => Ok(s),
-        }
+impl Encode for Arc<str> {
+    fn type_code() -> u8 {
+        CoreType::String.into()
+    }
+
+    fn partial_encode<W: std::io::Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        self.as_ref().partial_encode(writer)
+    }
+}
+
+impl Decode for Arc<str> {
+    fn expected_type_code() -> u8 {
+        CoreType::String.into()
+    }
+
+    fn partial_decode<R: Read>(reader: &mut R) -> Result<Arc<str>, DecodeError> {
+        let len = decode_len(reader)?;
+        decode_shared_string(reader, len)
     }
 }
 
@@ -354,6 +358,71 @@ impl<T: Decode> Decode for Option<T> {
     }
 }
 
+impl Encode for char {
+    fn type_code() -> u8 {
+        CoreType::Char.into()
+    }
+
+    fn partial_encode<W: std::io::Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        let mut buf = [0; 4];
+        let slice = self.encode_utf8(&mut buf);
+        writer.write_all(slice.as_bytes())?;
+        Ok(())
+    }
+}
+
+impl Decode for char {
+    fn expected_type_code() -> u8 {
+        CoreType::Char.into()
+    }
+
+    fn partial_decode<R: Read>(reader: &mut R) -> Result<Self, DecodeError>
+    where
+        Self: std::marker::Sized,
+    {
+        let mut first_byte = [0_u8; 1];
+        reader.read_exact(&mut first_byte)?;
+
+        match first_byte[0] {
+            one if one < 0b1000_0000 => Ok(char::from(one)),
+            two if (0b1100_0000..0b1110_0000).contains(&two) => {
+                let mut second_byte = [0_u8; 1];
+                reader.read_exact(&mut second_byte)?;
+                std::str::from_utf8(&[first_byte[0], second_byte[0]])?
+                    .chars()
+                    .next()
+                    .ok_or_else(|| {
+                        DecodeError::DecodeError("error converting u32 to char".to_string())
+                    })
+            }
+            three if (0b1110_0000..0b1111_0000).contains(&three) => {
+                let mut rest = [0_u8; 2];
+                reader.read_exact(&mut rest)?;
+                std::str::from_utf8(&[first_byte[0], rest[0], rest[1]])?
+                    .chars()
+                    .next()
+                    .ok_or_else(|| {
+                        DecodeError::DecodeError("error converting u32 to char".to_string())
+                    })
+            }
+            four if (0b1111_0000..0b1111_1000).contains(&four) => {
+                let mut rest = [0_u8; 3];
+                reader.read_exact(&mut rest)?;
+                std::str::from_utf8(&[first_byte[0], rest[0], rest[1], rest[2]])?
+                    .chars()
+                    .next()
+                    .ok_or_else(|| {
+                        DecodeError::DecodeError("error converting u32 to char".to_string())
+                    })
+            }
+            rest => Err(DecodeError::DecodeError(format!(
+                "not a valid utf-8 first byte: value {:b}",
+                rest
+            ))),
+        }
+    }
+}
+
 #[test]
 fn encode_string() {
     let s = String::from("test");
@@ -489,3 +558,88 @@ fn option_fail_decode() {
 
     assert!(option.is_err())
 }
+
+#[test]
+fn option_consecutive_decode() {
+    let reader: Vec<u8> = vec![
+        0x03, 0x0, 0x0, 0x0, 0x0, 0x04, b'h', b'o', b's', b't', 0xfe, 0x1, 0x01, 0x0, 0x0, 0x0,
+        0x0, 0x2a,
+    ];
+    let mut slice = &reader[..];
+
+    let first: Option<String> = Option::decode(&mut slice).unwrap();
+    let second: Option<String> = Option::decode(&mut slice).unwrap();
+    let third: Option<i32> = Option::decode(&mut slice).unwrap();
+
+    assert_eq!(first, Some(String::from("host")));
+    assert_eq!(second, None);
+    assert_eq!(third, Some(42));
+    assert!(slice.is_empty());
+}
+
+#[test]
+fn nullable_consecutive_decode() {
+    let reader: Vec<u8> = vec![0x0, 0x0, 0x0, 0x0, 0x2a, 0x1, 0x0, 0x0, 0x0, 0x0, 0x07];
+    let mut slice = &reader[..];
+
+    assert_eq!(i32::nullable_decode(&mut slice).unwrap(), Some(42));
+    assert_eq!(i32::nullable_decode(&mut slice).unwrap(), None);
+    assert_eq!(i32::nullable_decode(&mut slice).unwrap(), Some(7));
+    assert!(slice.is_empty());
+}
+
+#[test]
+fn string_negative_len() {
+    let reader = [0x03, 0x0, 0xff, 0xff, 0xff, 0xff, b'a'];
+    let res = String::decode(&mut &reader[..]);
+    assert!(matches!(res, Err(DecodeError::InvalidLength(-1))));
+}
+
+#[test]
+fn string_len_exceeds_input() {
+    let reader = [0x03, 0x0, 0x7f, 0xff, 0xff, 0xff, b'a'];
+    let res = String::decode(&mut &reader[..]);
+    assert!(matches!(res, Err(DecodeError::IoError(_))));
+}
+
+#[test]
+fn char_decode_utf8() {
+    let reader = [0x80_u8, 0x0, 0xe2, 0x99, 0xa5];
+    let c = char::decode(&mut &reader[..]).unwrap();
+
+    assert_eq!('♥', c)
+}
+
+#[test]
+fn char_decode() {
+    let reader = [0x80_u8, 0x0, 65];
+    let c = char::decode(&mut &reader[..]).unwrap();
+
+    assert_eq!('A', c)
+}
+
+#[test]
+fn char_decode_2() {
+    let reader = [0x80_u8, 0x0, 0xc3, 0x9f];
+    let c = char::decode(&mut &reader[..]).unwrap();
+
+    assert_eq!('ß', c)
+}
+
+#[test]
+fn char_decode_3() {
+    let reader = [0x80_u8, 0x0, 0xf0, 0x9f, 0xa6, 0x80];
+    let c = char::decode(&mut &reader[..]).unwrap();
+
+    assert_eq!('🦀', c)
+}
+
+#[test]
+fn char_encode_decode() {
+    let mut buf = Vec::new();
+    '🦀'.encode(&mut buf).unwrap();
+    assert_eq!(buf, [0x80_u8, 0x0, 0xf0, 0x9f, 0xa6, 0x80]);
+
+    let res = crate::GremlinValue::decode(&mut &buf[..]).unwrap();
+    assert_eq!(res, crate::GremlinValue::Char('🦀'));
+}
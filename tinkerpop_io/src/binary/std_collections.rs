@@ -5,7 +5,7 @@ use crate::{
     specs::CoreType,
 };
 
-use super::{Decode, Encode};
+use super::{decode_items_len, sorted_maps, Decode, Encode, MAX_PREALLOCATED_ITEMS};
 
 #[cfg(feature = "graph_binary")]
 impl<T: Encode> Encode for &[T] {
@@ -55,11 +55,8 @@ impl<T: Decode> Decode for Vec<T> {
     where
         Self: std::marker::Sized,
     {
-        let len = i32::partial_decode(reader)?;
-        if len.is_negative() {
-            return Err(DecodeError::DecodeError("vec len negativ".to_string()));
-        }
-        let mut list: Vec<T> = Vec::with_capacity(len as usize);
+        let len = decode_items_len(reader)?;
+        let mut list: Vec<T> = Vec::with_capacity(len.min(MAX_PREALLOCATED_ITEMS));
         for _ in 0..len {
             list.push(T::decode(reader)?);
         }
@@ -67,6 +64,31 @@ impl<T: Decode> Decode for Vec<T> {
     }
 }
 
+#[cfg(feature = "graph_binary")]
+impl<T: Decode, const N: usize> Decode for [T; N] {
+    fn expected_type_code() -> u8 {
+        CoreType::List.into()
+    }
+
+    fn partial_decode<R: std::io::Read>(reader: &mut R) -> Result<Self, DecodeError>
+    where
+        Self: std::marker::Sized,
+    {
+        let len = decode_items_len(reader)?;
+        if len != N {
+            return Err(DecodeError::DecodeError(format!(
+                "array len mismatch, expected {N} found {len}"
+            )));
+        }
+        let mut list: Vec<T> = Vec::with_capacity(N);
+        for _ in 0..N {
+            list.push(T::decode(reader)?);
+        }
+        list.try_into()
+            .map_err(|_| DecodeError::DecodeError(format!("array len mismatch, expected {N}")))
+    }
+}
+
 #[cfg(feature = "graph_binary")]
 impl<K, V, S: BuildHasher> Encode for HashMap<K, V, S>
 where
@@ -81,6 +103,23 @@ where
         let len = self.len() as i32;
         len.partial_encode(writer)?;
 
+        if sorted_maps() {
+            let mut entries = self
+                .iter()
+                .map(|(key, value)| {
+                    let mut key_bytes = Vec::new();
+                    key.encode(&mut key_bytes)?;
+                    Ok((key_bytes, value))
+                })
+                .collect::<Result<Vec<_>, EncodeError>>()?;
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            for (key_bytes, value) in entries {
+                writer.write_all(&key_bytes)?;
+                value.encode(writer)?;
+            }
+            return Ok(());
+        }
+
         for (key, value) in self.iter() {
             key.encode(writer)?;
             value.encode(writer)?;
@@ -104,8 +143,9 @@ where
     where
         Self: std::marker::Sized,
     {
-        let len = i32::partial_decode(reader)? as usize;
-        let mut hash_map = HashMap::with_capacity_and_hasher(len, Default::default());
+        let len = decode_items_len(reader)?;
+        let mut hash_map =
+            HashMap::with_capacity_and_hasher(len.min(MAX_PREALLOCATED_ITEMS), Default::default());
         for _ in 0..len {
             let key = K::decode(reader)?;
             let value = V::decode(reader)?;
@@ -209,3 +249,24 @@ fn testing_decode_hash_map() {
     ];
     assert_eq!(map, HashMap::<i32, String>::decode(&mut &msg[..]).unwrap());
 }
+
+#[test]
+fn array_decode_test() {
+    let reader: Vec<u8> = vec![
+        0x09, 0x0, 0x0, 0x0, 0x0, 0x03, 0x01, 0x0, 0x0, 0x0, 0x0, 0x01, 0x01, 0x0, 0x0, 0x0, 0x0,
+        0x02, 0x01, 0x0, 0x0, 0x0, 0x0, 0x03,
+    ];
+
+    let res = <[i32; 3]>::decode(&mut &reader[..]).unwrap();
+    assert_eq!(res, [1, 2, 3]);
+
+    let res = <[i32; 2]>::decode(&mut &reader[..]);
+    assert!(res.is_err());
+}
+
+#[test]
+fn vec_negative_len() {
+    let reader = [0x09, 0x0, 0x80, 0x0, 0x0, 0x0];
+    let res = Vec::<i32>::decode(&mut &reader[..]);
+    assert!(matches!(res, Err(DecodeError::InvalidLength(i32::MIN))));
+}
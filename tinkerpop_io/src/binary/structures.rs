@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use bigdecimal::BigDecimal;
 use num::BigInt;
@@ -21,13 +22,16 @@ use crate::{
         property::{EitherParent, Property},
         set::Set,
         traverser::{TraversalStrategy, Traverser},
+        tree::Tree,
         vertex::Vertex,
         vertex_property::VertexProperty,
     },
     Binding, GremlinValue,
 };
 
-use super::{Decode, Encode, ValueFlag};
+use super::{
+    decode_items_len, decode_len, read_bytes, Decode, Encode, ValueFlag, MAX_PREALLOCATED_ITEMS,
+};
 
 impl Encode for ElementId {
     fn type_code() -> u8 {
@@ -131,10 +135,8 @@ impl Decode for BigInt {
     where
         Self: std::marker::Sized,
     {
-        let len = i32::partial_decode(reader)?;
-        let mut buf = vec![0; len as usize];
-        reader.read_exact(&mut buf)?;
-        Ok(BigInt::from_signed_bytes_be(&buf))
+        let len = decode_len(reader)?;
+        Ok(BigInt::from_signed_bytes_be(&read_bytes(reader, len)?))
     }
 }
 
@@ -227,9 +229,8 @@ impl Decode for BulkSet {
     where
         Self: std::marker::Sized,
     {
-        let len = i32::partial_decode(reader)?;
-        let len = usize::try_from(len)?;
-        let mut items = Vec::with_capacity(len);
+        let len = decode_items_len(reader)?;
+        let mut items = Vec::with_capacity(len.min(MAX_PREALLOCATED_ITEMS));
         for _ in 0..len {
             let gb = GremlinValue::decode(reader)?;
             let bulk = i64::partial_decode(reader)?;
@@ -239,6 +240,45 @@ impl Decode for BulkSet {
     }
 }
 
+impl Encode for Tree {
+    fn type_code() -> u8 {
+        CoreType::Tree.into()
+    }
+
+    fn partial_encode<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> Result<(), crate::error::EncodeError> {
+        let len = self.0.len() as i32;
+        len.partial_encode(writer)?;
+        for (key, tree) in &self.0 {
+            key.encode(writer)?;
+            tree.partial_encode(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl Decode for Tree {
+    fn expected_type_code() -> u8 {
+        CoreType::Tree.into()
+    }
+
+    fn partial_decode<R: std::io::Read>(reader: &mut R) -> Result<Self, DecodeError>
+    where
+        Self: std::marker::Sized,
+    {
+        let len = decode_items_len(reader)?;
+        let mut branches = Vec::with_capacity(len.min(MAX_PREALLOCATED_ITEMS));
+        for _ in 0..len {
+            let key = GremlinValue::decode(reader)?;
+            let tree = Tree::partial_decode(reader)?;
+            branches.push((key, tree));
+        }
+        Ok(Tree(branches))
+    }
+}
+
 impl Encode for ByteBuffer {
     fn type_code() -> u8 {
         CoreType::ByteBuffer.into()
@@ -264,10 +304,8 @@ impl Decode for ByteBuffer {
     where
         Self: std::marker::Sized,
     {
-        let len = i32::partial_decode(reader)? as usize;
-        let mut buffer = vec![0; len];
-        reader.read_exact(&mut buffer)?;
-        Ok(ByteBuffer(buffer))
+        let len = decode_len(reader)?;
+        Ok(ByteBuffer(read_bytes(reader, len)?))
     }
 }
 
@@ -304,17 +342,17 @@ impl Decode for Bytecode {
     where
         Self: std::marker::Sized,
     {
-        let len = i32::partial_decode(reader)? as usize;
-        let mut steps = Vec::with_capacity(len);
+        let len = decode_items_len(reader)?;
+        let mut steps = Vec::with_capacity(len.min(MAX_PREALLOCATED_ITEMS));
         for _ in 0..len {
             let name = String::partial_decode(reader)?;
             let values = Vec::<GremlinValue>::partial_decode(reader)?;
             steps.push(Step { name, values });
         }
 
-        let len = i32::partial_decode(reader)? as usize;
+        let len = decode_items_len(reader)?;
 
-        let mut sources = Vec::with_capacity(len);
+        let mut sources = Vec::with_capacity(len.min(MAX_PREALLOCATED_ITEMS));
         for _ in 0..len {
             let name = String::partial_decode(reader)?;
             let values = Vec::<GremlinValue>::partial_decode(reader)?;
@@ -355,11 +393,11 @@ impl Decode for Edge {
         Self: std::marker::Sized,
     {
         let id = ElementId::decode(reader)?;
-        let label = String::partial_decode(reader)?;
+        let label = Arc::<str>::partial_decode(reader)?;
         let in_v_id = ElementId::decode(reader)?;
-        let in_v_label = String::partial_decode(reader)?;
+        let in_v_label = Arc::<str>::partial_decode(reader)?;
         let out_v_id = ElementId::decode(reader)?;
-        let out_v_label = String::partial_decode(reader)?;
+        let out_v_label = Arc::<str>::partial_decode(reader)?;
         let parent = Option::<Vertex>::decode(reader)?;
         let properties = Option::<Vec<Property>>::decode(reader)?;
 
@@ -386,11 +424,11 @@ impl Decode for GraphEdge {
         Self: std::marker::Sized,
     {
         let id = ElementId::decode(reader)?;
-        let label = String::partial_decode(reader)?;
+        let label = Arc::<str>::partial_decode(reader)?;
         let in_v_id = ElementId::decode(reader)?;
-        let in_v_label = Option::<String>::decode(reader)?;
+        let in_v_label = Option::<Arc<str>>::decode(reader)?;
         let out_v_id = ElementId::decode(reader)?;
-        let out_v_label = Option::<String>::decode(reader)?;
+        let out_v_label = Option::<Arc<str>>::decode(reader)?;
         let parent = Option::<Vertex>::decode(reader)?;
         let properties = Vec::<Property>::partial_decode(reader)?;
 
@@ -438,7 +476,7 @@ impl Encode for Graph {
                     }
                 }
             } else {
-                None::<i32>.encode(writer)?;
+                0_i32.partial_encode(writer)?;
             }
             // vertex.properties.write_patial_bytes(writer)?;
         }
@@ -467,16 +505,16 @@ impl Decode for Graph {
     where
         Self: std::marker::Sized,
     {
-        let v_len = i32::partial_decode(reader)? as usize;
-        let mut v_vec = Vec::with_capacity(v_len);
+        let v_len = decode_items_len(reader)?;
+        let mut v_vec = Vec::with_capacity(v_len.min(MAX_PREALLOCATED_ITEMS));
         for _ in 0..v_len {
             let v_id = ElementId::decode(reader)?;
-            let v_label = String::partial_decode(reader)?;
-            let p_len = i32::partial_decode(reader)? as usize;
-            let mut p_vec = Vec::with_capacity(p_len);
+            let v_label = Arc::<str>::partial_decode(reader)?;
+            let p_len = decode_items_len(reader)?;
+            let mut p_vec = Vec::with_capacity(p_len.min(MAX_PREALLOCATED_ITEMS));
             for _ in 0..p_len {
                 let p_id = ElementId::decode(reader)?;
-                let p_label = String::partial_decode(reader)?;
+                let p_label = Arc::<str>::partial_decode(reader)?;
                 let p_value = GremlinValue::decode(reader)?;
                 let p_parent = Option::<Vertex>::decode(reader)?;
                 let p_properties = Option::<Vec<Property>>::partial_decode(reader)?;
@@ -494,8 +532,8 @@ impl Decode for Graph {
                 properties: Some(p_vec),
             });
         }
-        let e_len = i32::partial_decode(reader)? as usize;
-        let mut e_vec = Vec::with_capacity(v_len);
+        let e_len = decode_items_len(reader)?;
+        let mut e_vec = Vec::with_capacity(e_len.min(MAX_PREALLOCATED_ITEMS));
         for _ in 0..e_len {
             e_vec.push(GraphEdge::partial_decode(reader)?);
         }
@@ -669,8 +707,8 @@ impl Decode for Path {
         Self: std::marker::Sized,
     {
         reader.read_exact(&mut [0_u8, 0])?;
-        let len = i32::partial_decode(reader)? as usize;
-        let mut labels = Vec::with_capacity(len);
+        let len = decode_items_len(reader)?;
+        let mut labels = Vec::with_capacity(len.min(MAX_PREALLOCATED_ITEMS));
         for _ in 0..len {
             reader.read_exact(&mut [0_u8, 0])?;
             let set = Set::<String>::partial_decode(reader)?;
@@ -707,7 +745,7 @@ impl Decode for Vertex {
         Self: std::marker::Sized,
     {
         let id = ElementId::decode(reader)?;
-        let label = String::partial_decode(reader)?;
+        let label = Arc::<str>::partial_decode(reader)?;
         let properties = Option::<Vec<VertexProperty>>::decode(reader)?;
 
         Ok(Vertex {
@@ -746,7 +784,7 @@ impl Decode for VertexProperty {
         Self: std::marker::Sized,
     {
         let id = ElementId::decode(reader)?;
-        let label = String::partial_decode(reader)?;
+        let label = Arc::<str>::partial_decode(reader)?;
         let value = GremlinValue::decode(reader)?;
         let parent = Option::<Vertex>::decode(reader)?;
         let properties = Option::<Vec<Property>>::decode(reader)?;
@@ -826,7 +864,10 @@ impl Decode for EitherParent {
             (CoreType::VertexProperty, ValueFlag::Set) => Ok(EitherParent::VertexProperty(
                 VertexProperty::partial_decode(reader)?,
             )),
-            (CoreType::UnspecifiedNullObject, ValueFlag::Null) => Ok(EitherParent::None),
+            (
+                CoreType::UnspecifiedNullObject | CoreType::Edge | CoreType::VertexProperty,
+                ValueFlag::Null,
+            ) => Ok(EitherParent::None),
             (c, v) => Err(crate::error::DecodeError::DecodeError(format!(
                 "EitherParent decode with Coretype {c:?} and Valueflag {v:?}"
             ))),
@@ -843,7 +884,7 @@ impl Decode for Property {
     where
         Self: std::marker::Sized,
     {
-        let key = String::partial_decode(reader)?;
+        let key = Arc::<str>::partial_decode(reader)?;
         let value = Box::new(GremlinValue::decode(reader)?);
         let parent = EitherParent::decode(reader)?;
 
@@ -931,6 +972,7 @@ impl Encode for MapKeys {
             MapKeys::Uuid(val) => val.encode(writer),
             MapKeys::T(val) => val.encode(writer),
             MapKeys::Direction(val) => val.encode(writer),
+            MapKeys::Merge(val) => val.encode(writer),
         }
     }
 }
@@ -1057,11 +1099,11 @@ fn edge_none_encode_gb() {
 
     let e = Edge {
         id: 9_i32.into(),
-        label: "created".to_string(),
+        label: "created".into(),
         in_v_id: 3_i64.into(),
-        in_v_label: "software".to_string(),
+        in_v_label: "software".into(),
         out_v_id: 1_i64.into(),
-        out_v_label: "person".to_string(),
+        out_v_label: "person".into(),
         parent: None,
         properties: None,
     };
@@ -1088,11 +1130,11 @@ fn edge_decode_gb() {
     // assert!(p.is_ok());
     let expected = Edge {
         id: 9_i32.into(),
-        label: "created".to_string(),
+        label: "created".into(),
         in_v_id: 3_i64.into(),
-        in_v_label: "software".to_string(),
+        in_v_label: "software".into(),
         out_v_id: 1_i64.into(),
-        out_v_label: "person".to_string(),
+        out_v_label: "person".into(),
         parent: None,
         properties: None,
     };
@@ -1124,18 +1166,18 @@ fn encode_gb() {
     let v_s = vec![
         Vertex {
             id: 1_i64.into(),
-            label: "person".to_string(),
+            label: "person".into(),
             properties: Some(vec![
                 VertexProperty {
                     id: 0i64.into(),
-                    label: "name".to_string(),
+                    label: "name".into(),
                     value: Box::new("marko".into()),
                     parent: None,
                     properties: Some(Vec::new()),
                 },
                 VertexProperty {
                     id: 2i64.into(),
-                    label: "age".to_string(),
+                    label: "age".into(),
                     value: Box::new(29_i32.into()),
                     parent: None,
                     properties: Some(Vec::new()),
@@ -1144,18 +1186,18 @@ fn encode_gb() {
         },
         Vertex {
             id: 2_i64.into(),
-            label: "person".to_string(),
+            label: "person".into(),
             properties: Some(vec![
                 VertexProperty {
                     id: 3i64.into(),
-                    label: "name".to_string(),
+                    label: "name".into(),
                     value: Box::new("vadas".into()),
                     parent: None,
                     properties: Some(Vec::new()),
                 },
                 VertexProperty {
                     id: 4i64.into(),
-                    label: "age".to_string(),
+                    label: "age".into(),
                     value: Box::new(27_i32.into()),
                     parent: None,
                     properties: Some(Vec::new()),
@@ -1166,14 +1208,14 @@ fn encode_gb() {
 
     let edge = vec![GraphEdge {
         id: 13_i64.into(),
-        label: "test".to_string(),
+        label: "test".into(),
         in_v_id: 2_i64.into(),
         in_v_label: None,
         out_v_id: 1_i64.into(),
         out_v_label: None,
         parent: None,
         properties: vec![Property {
-            key: "since".to_string(),
+            key: "since".into(),
             value: Box::new(123_i32.into()),
             parent: EitherParent::None,
         }],
@@ -1215,18 +1257,18 @@ fn decode_gb() {
     let v_s = vec![
         Vertex {
             id: 1_i64.into(),
-            label: "person".to_string(),
+            label: "person".into(),
             properties: Some(vec![
                 VertexProperty {
                     id: 0i64.into(),
-                    label: "name".to_string(),
+                    label: "name".into(),
                     value: Box::new("marko".into()),
                     parent: None,
                     properties: Some(Vec::new()),
                 },
                 VertexProperty {
                     id: 2i64.into(),
-                    label: "age".to_string(),
+                    label: "age".into(),
                     value: Box::new(29_i32.into()),
                     parent: None,
                     properties: Some(Vec::new()),
@@ -1235,18 +1277,18 @@ fn decode_gb() {
         },
         Vertex {
             id: 2_i64.into(),
-            label: "person".to_string(),
+            label: "person".into(),
             properties: Some(vec![
                 VertexProperty {
                     id: 3i64.into(),
-                    label: "name".to_string(),
+                    label: "name".into(),
                     value: Box::new("vadas".into()),
                     parent: None,
                     properties: Some(Vec::new()),
                 },
                 VertexProperty {
                     id: 4i64.into(),
-                    label: "age".to_string(),
+                    label: "age".into(),
                     value: Box::new(27_i32.into()),
                     parent: None,
                     properties: Some(Vec::new()),
@@ -1257,14 +1299,14 @@ fn decode_gb() {
 
     let edge = vec![GraphEdge {
         id: 13_i64.into(),
-        label: "test".to_string(),
+        label: "test".into(),
         in_v_id: 2_i64.into(),
         in_v_label: None,
         out_v_id: 1_i64.into(),
         out_v_label: None,
         parent: None,
         properties: vec![Property {
-            key: "since".to_string(),
+            key: "since".into(),
             value: Box::new(123_i32.into()),
             parent: EitherParent::None,
         }],
@@ -1452,7 +1494,7 @@ fn vertex_none_encode() {
     ];
     let v = Vertex {
         id: 1_i64.into(),
-        label: String::from("person"),
+        label: "person".into(),
         properties: None,
     };
     let mut buf = Vec::new();
@@ -1473,7 +1515,7 @@ fn vertex_decode_none() {
 
     let expected = Vertex {
         id: 1_i64.into(),
-        label: String::from("person"),
+        label: "person".into(),
         properties: None,
     };
 
@@ -1510,3 +1552,208 @@ fn decode_traverser() {
 
     assert_eq!(expected, Traverser::decode(&mut &reader[..]).unwrap())
 }
+
+#[test]
+fn property_edge_parent_roundtrip() {
+    let parent = Edge {
+        id: 9_i32.into(),
+        label: "created".into(),
+        in_v_id: 3_i64.into(),
+        in_v_label: "software".into(),
+        out_v_id: 1_i64.into(),
+        out_v_label: "person".into(),
+        parent: None,
+        properties: None,
+    };
+    let property = Property::new("weight", 0.4_f64, EitherParent::Edge(parent));
+
+    let mut buf = Vec::new();
+    property.encode(&mut buf).unwrap();
+
+    assert_eq!(property, Property::decode(&mut &buf[..]).unwrap())
+}
+
+#[test]
+fn property_none_parent_encode() {
+    let expected = [
+        0x0f, 0x0, 0x0, 0x0, 0x0, 0x4, b'n', b'a', b'm', b'e', 0x03, 0x0, 0x0, 0x0, 0x0, 0x5,
+        b'm', b'a', b'r', b'k', b'o', 0xfe, 0x1,
+    ];
+    let property = Property::new("name", "marko", EitherParent::None);
+
+    let mut buf = Vec::new();
+    property.encode(&mut buf).unwrap();
+
+    assert_eq!(expected, buf[..]);
+    assert_eq!(property, Property::decode(&mut &buf[..]).unwrap())
+}
+
+#[test]
+fn tree_encode() {
+    let expected = [
+        0x2b, 0x0, 0x0, 0x0, 0x0, 0x1, 0x01, 0x0, 0x0, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x2, 0x03,
+        0x0, 0x0, 0x0, 0x0, 0x1, b'a', 0x0, 0x0, 0x0, 0x0, 0x03, 0x0, 0x0, 0x0, 0x0, 0x1, b'b',
+        0x0, 0x0, 0x0, 0x0,
+    ];
+
+    let mut tree = Tree::new();
+    let branch = tree.insert(1);
+    branch.insert("a");
+    branch.insert("b");
+    tree.insert(1);
+
+    let mut buf = Vec::new();
+    tree.encode(&mut buf).unwrap();
+    assert_eq!(expected, buf[..]);
+
+    let decoded = Tree::decode(&mut &buf[..]).unwrap();
+    assert_eq!(tree, decoded);
+}
+
+#[test]
+fn partition_strategy_encode() {
+    use crate::structure::traverser::PARTITION_STRATEGY;
+
+    let mut expected = vec![0x29, 0x0];
+    expected.extend((PARTITION_STRATEGY.len() as i32).to_be_bytes());
+    expected.extend(PARTITION_STRATEGY.as_bytes());
+    expected.extend([0x0, 0x0, 0x0, 0x1]);
+    expected.extend([0x03, 0x0, 0x0, 0x0, 0x0, 0x0c]);
+    expected.extend(b"partitionKey");
+    expected.extend([0x03, 0x0, 0x0, 0x0, 0x0, 0x0a]);
+    expected.extend(b"_partition");
+
+    let strategy = TraversalStrategy::partition("_partition", None, vec![], false);
+    let mut buf = Vec::new();
+    strategy.encode(&mut buf).unwrap();
+    assert_eq!(expected, buf);
+
+    let strategy = TraversalStrategy::partition("_partition", Some("a"), vec!["a", "b"], true);
+    let mut buf = Vec::new();
+    strategy.encode(&mut buf).unwrap();
+    assert_eq!(strategy, TraversalStrategy::decode(&mut &buf[..]).unwrap());
+}
+
+#[test]
+fn bytecode_with_source_roundtrip() {
+    let mut bytecode = Bytecode::default();
+    bytecode.push_new_source("inject", vec![29.into()]);
+    bytecode.push_new_step("V", vec![]);
+
+    let mut buf = Vec::new();
+    bytecode.encode(&mut buf).unwrap();
+    assert_eq!(bytecode, Bytecode::decode(&mut &buf[..]).unwrap());
+}
+
+#[test]
+fn lambda_groovy_roundtrip() {
+    let lambda = Lambda::groovy("{ a, b -> a + b }");
+    let expected_head = [0x1d, 0x0, 0x0, 0x0, 0x0, 0xe];
+
+    let mut buf = Vec::new();
+    lambda.encode(&mut buf).unwrap();
+    assert_eq!(expected_head, buf[..6]);
+    assert_eq!(b"gremlin-groovy", &buf[6..20]);
+    assert_eq!([0x0, 0x0, 0x0, 0x2], buf[buf.len() - 4..]);
+    assert_eq!(lambda, Lambda::decode(&mut &buf[..]).unwrap());
+}
+
+#[test]
+fn bytecode_step_with_t_roundtrip() {
+    use crate::structure::enums::T;
+
+    let mut bytecode = Bytecode::default();
+    bytecode.push_new_step("V", vec![]);
+    bytecode.push_new_step("has", vec![T::Id.into(), 1.into()]);
+
+    let mut buf = Vec::new();
+    bytecode.encode(&mut buf).unwrap();
+    let t_id = [
+        CoreType::T.into(),
+        0x0,
+        0x03,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x02,
+        b'i',
+        b'd',
+    ];
+    assert!(buf.windows(t_id.len()).any(|window| window == t_id));
+
+    let decoded = Bytecode::decode(&mut &buf[..]).unwrap();
+    assert_eq!(bytecode, decoded);
+    assert_eq!(
+        Some(&GremlinValue::T(T::Id)),
+        decoded.steps.last().and_then(|step| step.values.first())
+    );
+}
+
+#[test]
+fn tree_invalid_len() {
+    let reader = [0x2b, 0x0, 0x80, 0x0, 0x0, 0x0];
+    let res = Tree::decode(&mut &reader[..]);
+    assert!(matches!(res, Err(DecodeError::InvalidLength(i32::MIN))));
+
+    let reader = [0x2b, 0x0, 0x7f, 0xff, 0xff, 0xff];
+    let res = Tree::decode(&mut &reader[..]);
+    assert!(res.is_err());
+}
+
+#[test]
+fn bytecode_invalid_len() {
+    let reader = [0x15, 0x0, 0x80, 0x0, 0x0, 0x0];
+    let res = Bytecode::decode(&mut &reader[..]);
+    assert!(matches!(res, Err(DecodeError::InvalidLength(i32::MIN))));
+
+    let reader = [0x15, 0x0, 0x7f, 0xff, 0xff, 0xff];
+    let res = Bytecode::decode(&mut &reader[..]);
+    assert!(res.is_err());
+
+    let reader = [0x15, 0x0, 0x0, 0x0, 0x0, 0x0, 0xff, 0xff, 0xff, 0xff];
+    let res = Bytecode::decode(&mut &reader[..]);
+    assert!(matches!(res, Err(DecodeError::InvalidLength(-1))));
+
+    let reader = [0x15, 0x0, 0x0, 0x0, 0x0, 0x0, 0x7f, 0xff, 0xff, 0xff];
+    let res = Bytecode::decode(&mut &reader[..]);
+    assert!(res.is_err());
+}
+
+#[test]
+fn graph_invalid_len() {
+    let reader = [0x10, 0x0, 0x80, 0x0, 0x0, 0x0];
+    let res = Graph::decode(&mut &reader[..]);
+    assert!(matches!(res, Err(DecodeError::InvalidLength(i32::MIN))));
+
+    let reader = [0x10, 0x0, 0x7f, 0xff, 0xff, 0xff];
+    let res = Graph::decode(&mut &reader[..]);
+    assert!(res.is_err());
+
+    let reader = [0x10, 0x0, 0x0, 0x0, 0x0, 0x0, 0xff, 0xff, 0xff, 0xff];
+    let res = Graph::decode(&mut &reader[..]);
+    assert!(matches!(res, Err(DecodeError::InvalidLength(-1))));
+
+    let reader = [0x10, 0x0, 0x0, 0x0, 0x0, 0x0, 0x7f, 0xff, 0xff, 0xff];
+    let res = Graph::decode(&mut &reader[..]);
+    assert!(res.is_err());
+
+    // one vertex with an oversized property count
+    let reader = [
+        0x10, 0x0, 0x0, 0x0, 0x0, 0x01, 0x01, 0x0, 0x0, 0x0, 0x0, 0x01, 0x0, 0x0, 0x0, 0x0, 0x7f,
+        0xff, 0xff, 0xff,
+    ];
+    let res = Graph::decode(&mut &reader[..]);
+    assert!(res.is_err());
+}
+
+#[test]
+fn path_invalid_len() {
+    let reader = [0x0e, 0x0, 0x09, 0x0, 0x80, 0x0, 0x0, 0x0];
+    let res = Path::decode(&mut &reader[..]);
+    assert!(matches!(res, Err(DecodeError::InvalidLength(i32::MIN))));
+
+    let reader = [0x0e, 0x0, 0x09, 0x0, 0x7f, 0xff, 0xff, 0xff];
+    let res = Path::decode(&mut &reader[..]);
+    assert!(res.is_err());
+}
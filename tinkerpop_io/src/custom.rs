@@ -3,6 +3,10 @@ use std::fmt::Display;
 use crate::{error::CustomError, specs::CoreType, structure::bytebuffer::ByteBuffer};
 
 use crate::binary::{Decode, Encode};
+#[cfg(feature = "graph_son")]
+use crate::graphson::EncodeGraphSON;
+#[cfg(feature = "graph_son")]
+use serde_json::json;
 
 pub trait CustomTypes {
     fn partial_encode(&self) -> (String, ByteBuffer, ByteBuffer);
@@ -24,6 +28,7 @@ pub trait CustomType {
 }
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub struct Custom {
     name: String,
     type_info: ByteBuffer,
@@ -57,6 +62,38 @@ impl Encode for Custom {
     }
 }
 
+/// GraphSON has no generic custom type, the value is written under its own name with the
+/// GraphBinary type info and blob it was decoded from.
+#[cfg(feature = "graph_son")]
+impl EncodeGraphSON for Custom {
+    fn encode_v3(&self) -> serde_json::Value {
+        json!({
+            "@type" : self.name,
+            "@value" : {
+                "typeInfo" : self.type_info.encode_v3(),
+                "value" : self.blob.encode_v3()
+            }
+        })
+    }
+
+    fn encode_v2(&self) -> serde_json::Value {
+        json!({
+            "@type" : self.name,
+            "@value" : {
+                "typeInfo" : self.type_info.encode_v2(),
+                "value" : self.blob.encode_v2()
+            }
+        })
+    }
+
+    fn encode_v1(&self) -> serde_json::Value {
+        json!({
+            "typeInfo" : self.type_info.encode_v1(),
+            "value" : self.blob.encode_v1()
+        })
+    }
+}
+
 impl Decode for Custom {
     fn expected_type_code() -> u8 {
         CoreType::Custom.into()
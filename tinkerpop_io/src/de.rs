@@ -13,6 +13,14 @@ pub fn from_gremlin<'de, T: Deserialize<'de>>(g: GremlinValue) -> Result<T, Deco
     T::deserialize(de)
 }
 
+/// Deserializes `T` from GraphBinary bytes. Errors while decoding the bytes are reported as
+/// [`DecodeError::AtOffset`] with the position of the cursor at which decoding failed.
+#[cfg(feature = "graph_binary")]
+pub fn from_graph_binary<'de, T: Deserialize<'de>>(bytes: &[u8]) -> Result<T, DecodeError> {
+    let g = crate::binary::from_slice_debug(bytes)?;
+    from_gremlin(g)
+}
+
 struct Deserializer(GremlinValue);
 
 impl<'de> serde::de::Deserializer<'de> for Deserializer {
@@ -42,14 +50,31 @@ impl<'de> serde::de::Deserializer<'de> for Deserializer {
             GremlinValue::Byte(v) => visitor.visit_u8(v),
             GremlinValue::Short(v) => visitor.visit_i16(v),
             GremlinValue::Boolean(v) => visitor.visit_bool(v),
-            #[cfg(feature = "extended")]
+            GremlinValue::Uuid(v) => visitor.visit_bytes(v.as_bytes()),
+            GremlinValue::BigInteger(v) => match i128::try_from(&v) {
+                Ok(v) => visitor.visit_i128(v),
+                Err(_) => visitor.visit_u128(u128::try_from(&v).map_err(|_| {
+                    DecodeError::DecodeError(format!("BigInteger {v} out of range of u128"))
+                })?),
+            },
             GremlinValue::Char(v) => visitor.visit_char(v),
+            GremlinValue::UnspecifiedNullObject => visitor.visit_unit(),
             _ => Err(DecodeError::DecodeError(
                 "Graphbinary not supported in deserialize_any".to_string(),
             )),
         }
     }
 
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.0 {
+            GremlinValue::UnspecifiedNullObject => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
     fn deserialize_newtype_struct<V>(
         self,
         _name: &'static str,
@@ -84,8 +109,8 @@ impl<'de> serde::de::Deserializer<'de> for Deserializer {
     }
 
     serde::forward_to_deserialize_any! {
-        bool u8 u16 u32 u64 i8 i16 i32 i64 f32 f64 char str string seq
-        bytes byte_buf map option unit
+        bool u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 f32 f64 char str string seq
+        bytes byte_buf map unit
         ignored_any unit_struct tuple_struct tuple enum identifier
     }
 }
@@ -216,3 +241,70 @@ fn new_type_struct_gremlin_v() {
     let test_struct = from_gremlin(gb).unwrap();
     assert_eq!(expected, test_struct)
 }
+
+#[cfg(feature = "graph_binary")]
+#[test]
+fn uuid_struct_from_graph_binary() {
+    use crate::binary::{from_slice, to_writer};
+    use std::collections::HashMap;
+    use uuid::Uuid;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct TestStruct {
+        id: Uuid,
+        name: String,
+    }
+
+    let id = Uuid::from_u128(0x0011_2233_4455_6677_8899_aabb_ccdd_eeff);
+    let gb = GremlinValue::Map(HashMap::from([
+        ("id".into(), id.into()),
+        ("name".into(), "marko".into()),
+    ]));
+
+    let mut buf = Vec::new();
+    to_writer(gb, &mut buf).unwrap();
+    let gb: GremlinValue = from_slice(&mut &buf[..]).unwrap();
+
+    let expected = TestStruct {
+        id,
+        name: "marko".to_string(),
+    };
+    let test_struct = from_gremlin(gb).unwrap();
+    assert_eq!(expected, test_struct)
+}
+
+#[cfg(feature = "graph_binary")]
+#[test]
+fn truncated_list_from_graph_binary_offset() {
+    let bytes = [
+        0x09, 0x00, 0x00, 0x00, 0x00, 0x02, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01, 0x00, 0x00,
+    ];
+
+    let err = from_graph_binary::<Vec<i32>>(&bytes).unwrap_err();
+    assert_eq!(Some(bytes.len()), err.offset());
+    assert!(matches!(
+        err,
+        DecodeError::AtOffset { source, .. } if matches!(*source, DecodeError::IoError(_))
+    ));
+}
+
+#[cfg(feature = "graph_binary")]
+#[test]
+fn unspecified_null_object_from_graph_binary() {
+    let res = from_graph_binary::<Option<i32>>(&[0xfe, 0x00]);
+    assert!(res.is_err());
+    assert_eq!(
+        from_graph_binary::<Option<i32>>(&[0xfe, 0x01]).unwrap(),
+        None
+    );
+
+    // a list holding 0xfe with the value flag set
+    let bytes = [0x09, 0x0, 0x0, 0x0, 0x0, 0x01, 0xfe, 0x00];
+    assert!(from_graph_binary::<Vec<Option<i32>>>(&bytes).is_err());
+
+    let bytes = [0x09, 0x0, 0x0, 0x0, 0x0, 0x01, 0xfe, 0x01];
+    assert_eq!(
+        from_graph_binary::<Vec<Option<i32>>>(&bytes).unwrap(),
+        vec![None]
+    );
+}
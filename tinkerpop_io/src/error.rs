@@ -1,7 +1,9 @@
 use std::{io, num::TryFromIntError, str::Utf8Error, string::FromUtf8Error};
 use thiserror::Error;
 
+use crate::specs::CoreType;
 use crate::structure::bytebuffer::ByteBuffer;
+use crate::structure::enums::Operator;
 
 #[derive(Error, Debug)]
 pub enum EncodeError {
@@ -51,6 +53,109 @@ pub enum DecodeError {
 
     #[error("try from int error")]
     TryError(#[from] TryFromIntError),
+
+    #[error("`{0}` trailing bytes after decoding")]
+    TrailingBytes(usize),
+
+    #[error("type code `{0:#04x}` is not allowed")]
+    DisallowedType(u8),
+
+    #[error("invalid length `{0}`")]
+    InvalidLength(i32),
+
+    #[error("unsupported GraphBinary protocol version `{0:#04x}`")]
+    UnsupportedVersion(u8),
+
+    #[error("decoding exceeds the budget of `{0}` values")]
+    BudgetExceeded(usize),
+
+    #[error("at byte offset `{offset}`")]
+    AtOffset {
+        offset: usize,
+        /// Hexdump of the bytes around `offset`, if the input was available.
+        snippet: Option<String>,
+        #[source]
+        source: Box<DecodeError>,
+    },
+}
+
+impl DecodeError {
+    /// Wraps the error with the byte offset of the input at which decoding failed.
+    #[must_use]
+    pub fn at_offset(self, offset: usize) -> DecodeError {
+        DecodeError::AtOffset {
+            offset,
+            snippet: None,
+            source: Box::new(self),
+        }
+    }
+
+    /// Returns the byte offset at which decoding failed, if the error carries one.
+    #[must_use]
+    pub fn offset(&self) -> Option<usize> {
+        match self {
+            DecodeError::AtOffset { offset, .. } => Some(*offset),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum TraverserError {
+    #[error("bulk of a Traverser can not be negative, found: `{0}`")]
+    NegativeBulk(i64),
+    #[error("bulk of a Traverser overflows")]
+    Overflow,
+    #[error("only Traverser with equal values can be merged")]
+    ValueMismatch,
+}
+
+#[derive(Error, Debug)]
+pub enum MergeError {
+    #[error("only two GremlinValue::Map can be merged")]
+    NotAMap,
+    #[error("values of key `{0}` are not numeric")]
+    NotNumeric(String),
+    #[error("sum of values of key `{0}` overflows")]
+    Overflow(String),
+}
+
+#[derive(Error, Debug)]
+pub enum OperatorError {
+    #[error("operator `{0}` can not be applied client side")]
+    Unsupported(Operator),
+    #[error("operator `{0}` expects numeric operands")]
+    NotNumeric(Operator),
+    #[error("operator `{0}` expects boolean operands")]
+    NotBoolean(Operator),
+    #[error("operator `{0}` overflows")]
+    Overflow(Operator),
+    #[error("division by zero")]
+    DivisionByZero,
+}
+
+#[derive(Error, Debug)]
+pub enum TypeError {
+    #[error("`{0}` is not a List and can not be promoted to one")]
+    NotAList(String),
+}
+
+#[derive(Error, Debug)]
+pub enum ValidationError {
+    #[error("`{0}` is not a fully-qualified class name")]
+    ClassName(String),
+    #[error("`{0}` is not a known Lambda language")]
+    LambdaLanguage(String),
+}
+
+#[derive(Error, Debug)]
+pub enum CoerceError {
+    #[error("cannot coerce `{value}` to {target:?}")]
+    Unsupported { value: String, target: CoreType },
+    #[error("`{value}` is out of range of {target:?}")]
+    OutOfRange { value: String, target: CoreType },
+    #[error("cannot parse `{value}` as {target:?}")]
+    Parse { value: String, target: CoreType },
 }
 
 #[cfg(feature = "graph_son")]
@@ -77,6 +182,17 @@ pub enum GraphSonError {
     WrongFixedValue(String),
 }
 
+#[cfg(all(feature = "graph_binary", feature = "graph_son"))]
+#[derive(Error, Debug)]
+pub enum TranscodeError {
+    #[error("decoding GraphBinary")]
+    Decode(#[from] DecodeError),
+    #[error("encoding GraphBinary")]
+    Encode(#[from] EncodeError),
+    #[error("GraphSON")]
+    GraphSon(#[from] GraphSonError),
+}
+
 #[cfg(feature = "serde")]
 impl serde::ser::Error for EncodeError {
     fn custom<T>(msg: T) -> Self
@@ -7,6 +7,7 @@ use crate::conversion;
 use crate::error::GraphSonError;
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub struct Instant {
     pub secs: i64,
     pub nanos: i32,
@@ -30,6 +31,7 @@ impl Display for Instant {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub struct MonthDay {
     pub month: u8,
     pub day: u8,
@@ -40,6 +42,7 @@ impl Display for MonthDay {
     }
 }
 #[derive(Debug, Clone, PartialEq, Copy, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub struct Year(pub i32);
 
 impl Display for Year {
@@ -49,6 +52,7 @@ impl Display for Year {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub struct YearMonth {
     pub year: i32,
     pub month: u8,
@@ -61,6 +65,7 @@ impl Display for YearMonth {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub struct Period {
     pub years: i32,
     pub months: i32,
@@ -127,6 +132,7 @@ impl Period {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub struct ZonedDateTime(pub DateTime<FixedOffset>);
 
 impl Display for ZonedDateTime {
@@ -136,11 +142,35 @@ impl Display for ZonedDateTime {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub struct OffsetTime {
     pub time: NaiveTime,
+    #[cfg_attr(feature = "serde-derive", serde(with = "fixed_offset_seconds"))]
     pub offset: FixedOffset,
 }
 
+/// `FixedOffset` has no serde support in chrono, it is represented by its total seconds east of UTC.
+#[cfg(feature = "serde-derive")]
+pub(crate) mod fixed_offset_seconds {
+    use chrono::FixedOffset;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(
+        offset: &FixedOffset,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(offset.local_minus_utc())
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<FixedOffset, D::Error> {
+        let secs = i32::deserialize(deserializer)?;
+        FixedOffset::east_opt(secs)
+            .ok_or_else(|| D::Error::custom(format!("offset out of range: {secs}")))
+    }
+}
+
 impl Display for OffsetTime {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}{}", self.time, self.offset)
@@ -2,5 +2,4 @@ use std::net::IpAddr;
 
 use crate::conversion;
 
-conversion!(char, Char);
 conversion!(IpAddr, InetAddress);
@@ -4,6 +4,7 @@ use serde_json::json;
 
 use crate::{
     error::GraphSonError,
+    specs::CoreType,
     structure::enums::{
         Barrier, Cardinality, Column, Direction, Merge, Operator, Order, Pick, Pop, Scope, TextP,
         P, T,
@@ -11,27 +12,30 @@ use crate::{
     GremlinValue,
 };
 
-use super::{get_val_by_key_v2, get_val_by_key_v3, validate_type, DecodeGraphSON, EncodeGraphSON};
+use super::{
+    get_val_by_key_v2, get_val_by_key_v3, type_identifier, validate_type, DecodeGraphSON,
+    EncodeGraphSON,
+};
 
 impl<T> EncodeGraphSON for P<T> {
     fn encode_v3(&self) -> serde_json::Value {
         match self.predicate.as_str() {
             "eq" | "neq" | "lt" | "lte" | "gt" | "gte" => json!({
-                "@type" : "g:P",
+                "@type" : type_identifier(CoreType::P),
                 "@value" : {
                     "predicate" : self.predicate,
                     "value": self.value[0].encode_v3()
                 }
             }),
             "between" | "inside" | "outside" | "within" | "without" => json!({
-                "@type" : "g:P",
+                "@type" : type_identifier(CoreType::P),
                 "@value" :{
                     "predicate" : self.predicate,
                     "value":  self.value.encode_v3()
                 }
             }),
             "and" | "or" => json!({
-                "@type" : "g:P",
+                "@type" : type_identifier(CoreType::P),
                 "@value" : {
                     "predicate" : self.predicate,
                     "value":  self.value.iter().map(EncodeGraphSON::encode_v3).collect::<Vec<serde_json::Value>>()
@@ -45,21 +49,21 @@ impl<T> EncodeGraphSON for P<T> {
     fn encode_v2(&self) -> serde_json::Value {
         match self.predicate.as_str() {
             "eq" | "neq" | "lt" | "lte" | "gt" | "gte" => json!({
-                "@type" : "g:P",
+                "@type" : type_identifier(CoreType::P),
                 "@value" : {
                     "predicate" : self.predicate,
                     "value": self.value[0].encode_v2()
                 }
             }),
             "between" | "inside" | "outside" | "within" | "without" => json!({
-                "@type" : "g:P",
+                "@type" : type_identifier(CoreType::P),
                 "@value" :{
                     "predicate" : self.predicate,
                     "value":  self.value.encode_v2()
                 }
             }),
             "and" | "or" => json!({
-                "@type" : "g:P",
+                "@type" : type_identifier(CoreType::P),
                 "@value" : {
                     "predicate" : self.predicate,
                     "value":  self.value.iter().map(EncodeGraphSON::encode_v2).collect::<Vec<serde_json::Value>>()
@@ -80,7 +84,7 @@ impl<T> DecodeGraphSON for P<T> {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:P")?;
+        let value_object = validate_type(j_val, CoreType::P)?;
 
         let predicate = get_val_by_key_v3::<String>(value_object, "predicate", "P")?;
 
@@ -126,7 +130,7 @@ impl<T> DecodeGraphSON for P<T> {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:P")?;
+        let value_object = validate_type(j_val, CoreType::P)?;
 
         let predicate = get_val_by_key_v2::<String>(value_object, "predicate", "P")?;
         match predicate.as_ref() {
@@ -179,7 +183,7 @@ impl EncodeGraphSON for TextP {
     //FIXME need testing if values can be more than one
     fn encode_v3(&self) -> serde_json::Value {
         json!({
-          "@type" : "g:TextP",
+          "@type" : type_identifier(CoreType::TextP),
           "@value" : {
             "predicate" : self.predicate,
             "value" : self.value[0].encode_v3()
@@ -189,7 +193,7 @@ impl EncodeGraphSON for TextP {
 
     fn encode_v2(&self) -> serde_json::Value {
         json!({
-          "@type" : "g:TextP",
+          "@type" : type_identifier(CoreType::TextP),
           "@value" : {
             "predicate" : self.predicate,
             "value" : self.value[0].encode_v2()
@@ -207,7 +211,7 @@ impl DecodeGraphSON for TextP {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:TextP")?;
+        let value_object = validate_type(j_val, CoreType::TextP)?;
 
         let predicate = get_val_by_key_v3(value_object, "predicate", "TextP")?;
         let value = get_val_by_key_v3(value_object, "value", "TextP")?;
@@ -221,7 +225,7 @@ impl DecodeGraphSON for TextP {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:TextP")?;
+        let value_object = validate_type(j_val, CoreType::TextP)?;
 
         let predicate = get_val_by_key_v2(value_object, "predicate", "TextP")?;
         let value = get_val_by_key_v2(value_object, "value", "TextP")?;
@@ -249,7 +253,7 @@ macro_rules! graph_son_impls {
                 fn encode_v3(&self) -> serde_json::Value {
                     json!({
 
-                        "@type" : concat!("g:",stringify!($t)),
+                        "@type" : type_identifier(CoreType::$t),
                         "@value" : self.as_str(),
                     })
                 }
@@ -268,7 +272,7 @@ macro_rules! graph_son_impls {
                 where
                     Self: std::marker::Sized,
                 {
-                    let value_object = validate_type(j_val, concat!("g:",stringify!($t)))?;
+                    let value_object = validate_type(j_val, CoreType::$t)?;
                     let s = value_object.as_str().ok_or_else(|| GraphSonError::WrongJsonType("str".to_string()))?;
                     <$t>::try_from(s).map_err(|err| GraphSonError::TryFrom(err.to_string()))
                 }
@@ -418,3 +422,83 @@ fn p_and_decode_v2() {
     let res = P::decode_v2(&v).unwrap();
     assert_eq!(res, expected);
 }
+
+#[test]
+fn t_roundtrip_v3() {
+    use crate::structure::map::MapKeys;
+    use std::collections::HashMap;
+    use std::str::FromStr;
+
+    for (t, s) in [
+        (T::Id, "id"),
+        (T::Key, "key"),
+        (T::Label, "label"),
+        (T::Value, "value"),
+    ] {
+        assert_eq!(t.to_string(), s);
+        assert_eq!(T::from_str(s).unwrap(), t);
+
+        let jval = t.encode_v3();
+        assert_eq!(jval, json!({"@type": "g:T", "@value": s}));
+        assert_eq!(T::decode_v3(&jval).unwrap(), t);
+
+        let map = HashMap::from([(MapKeys::T(t), GremlinValue::Int(1))]);
+        let jval = map.encode_v3();
+        assert_eq!(
+            HashMap::<MapKeys, GremlinValue>::decode_v3(&jval).unwrap(),
+            map
+        );
+    }
+    assert!(T::from_str("ID").is_err());
+}
+
+#[test]
+fn typed_key_map_decode_v3() {
+    use crate::structure::map::MapKeys;
+    use std::collections::HashMap;
+
+    let jval = json!({
+        "@type" : "g:Map",
+        "@value" : [
+            {"@type" : "g:T", "@value" : "label"},
+            {"@type" : "g:Int64", "@value" : 6},
+            {"@type" : "g:Direction", "@value" : "OUT"},
+            {"@type" : "g:Int64", "@value" : 2}
+        ]
+    });
+
+    let expected = HashMap::from([
+        (MapKeys::T(T::Label), GremlinValue::Long(6)),
+        (MapKeys::Direction(Direction::Out), GremlinValue::Long(2)),
+    ]);
+    assert_eq!(
+        HashMap::<MapKeys, GremlinValue>::decode_v3(&jval).unwrap(),
+        expected
+    );
+    assert_eq!(
+        GremlinValue::decode_v3(&jval).unwrap(),
+        GremlinValue::Map(expected)
+    );
+}
+
+#[test]
+fn merge_key_map_decode_v3() {
+    use crate::structure::map::MapKeys;
+    use std::collections::HashMap;
+
+    let jval = json!({
+        "@type" : "g:Map",
+        "@value" : [
+            {"@type" : "g:Merge", "@value" : "onCreate"},
+            {"@type" : "g:Map", "@value" : ["name", "marko"]}
+        ]
+    });
+
+    let expected = HashMap::from([(
+        MapKeys::from(Merge::OnCreate),
+        GremlinValue::Map(HashMap::from([("name".into(), "marko".into())])),
+    )]);
+    let res = HashMap::<MapKeys, GremlinValue>::decode_v3(&jval).unwrap();
+    assert_eq!(res, expected);
+    assert_eq!(res.encode_v3(), jval);
+}
@@ -12,9 +12,10 @@ use serde_json::json;
 use crate::{
     error::GraphSonError,
     extended::chrono::{Instant, MonthDay, OffsetTime, Period, Year, YearMonth, ZonedDateTime},
+    specs::CoreType,
 };
 
-use super::{validate_type, validate_type_entry, DecodeGraphSON, EncodeGraphSON};
+use super::{type_identifier, validate_type, DecodeGraphSON, EncodeGraphSON};
 
 fn parse_java_duration(s: &str) -> Result<Duration, GraphSonError> {
     let mut iter = s.chars();
@@ -78,10 +79,18 @@ fn parse_java_duration(s: &str) -> Result<Duration, GraphSonError> {
     Ok(duration)
 }
 
+/// Some servers emit `gx:Duration` and `gx:Instant` as a number of milliseconds and `gx:Period`
+/// as a number of days instead of an ISO-8601 string.
+fn numeric_fallback(value: &serde_json::Value) -> Result<i64, GraphSonError> {
+    value
+        .as_i64()
+        .ok_or_else(|| GraphSonError::WrongJsonType("str or number".to_string()))
+}
+
 impl EncodeGraphSON for Period {
     fn encode_v3(&self) -> serde_json::Value {
         json!({
-          "@type" : "gx:Period",
+          "@type" : type_identifier(CoreType::Period),
           "@value" : self.to_string()
         })
     }
@@ -100,11 +109,14 @@ impl DecodeGraphSON for Period {
     where
         Self: std::marker::Sized,
     {
-        let s = validate_type(j_val, "gx:Period")?
-            .as_str()
-            .ok_or_else(|| GraphSonError::WrongJsonType("str".to_string()))?;
-
-        Period::parse(s)
+        match validate_type(j_val, CoreType::Period)? {
+            serde_json::Value::String(s) => Period::parse(s),
+            number => {
+                let days = i32::try_from(numeric_fallback(number)?)
+                    .map_err(|err| GraphSonError::Parse(err.to_string()))?;
+                Ok(Period::new(0, 0, days))
+            }
+        }
     }
 
     fn decode_v2(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
@@ -125,7 +137,7 @@ impl DecodeGraphSON for Period {
 impl EncodeGraphSON for Instant {
     fn encode_v3(&self) -> serde_json::Value {
         json!({
-          "@type" : "gx:Instant",
+          "@type" : type_identifier(CoreType::Instant),
           "@value" : NaiveDateTime::from_timestamp_opt(self.secs, self.nanos as u32)
             .expect("NaiveDateTime out-of-range number of seconds and/or invalid nanosecond")
             .format("%Y-%m-%dT%H:%M:%S%.fZ").to_string()
@@ -146,9 +158,16 @@ impl DecodeGraphSON for Instant {
     where
         Self: std::marker::Sized,
     {
-        let s = validate_type(j_val, "gx:Instant")?
-            .as_str()
-            .ok_or_else(|| GraphSonError::WrongJsonType("str".to_string()))?;
+        let s = match validate_type(j_val, CoreType::Instant)? {
+            serde_json::Value::String(s) => s,
+            number => {
+                let millis = numeric_fallback(number)?;
+                return Ok(Instant {
+                    secs: millis.div_euclid(1000),
+                    nanos: (millis.rem_euclid(1000) * 1000 * 1000) as i32,
+                });
+            }
+        };
 
         let naive = NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.fZ")
             .map_err(|e| GraphSonError::Parse(format!("cannot parse Instant: {e}")))?;
@@ -176,7 +195,7 @@ impl DecodeGraphSON for Instant {
 impl EncodeGraphSON for Duration {
     fn encode_v3(&self) -> serde_json::Value {
         json!({
-          "@type" : "gx:Duration",
+          "@type" : type_identifier(CoreType::Duration),
           "@value" : self.to_string()
         })
     }
@@ -195,11 +214,10 @@ impl DecodeGraphSON for Duration {
     where
         Self: std::marker::Sized,
     {
-        let s = validate_type(j_val, "gx:Duration")?
-            .as_str()
-            .ok_or_else(|| GraphSonError::WrongJsonType("str".to_string()))?;
-
-        parse_java_duration(s)
+        match validate_type(j_val, CoreType::Duration)? {
+            serde_json::Value::String(s) => parse_java_duration(s),
+            number => Ok(Duration::milliseconds(numeric_fallback(number)?)),
+        }
     }
 
     fn decode_v2(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
@@ -220,7 +238,7 @@ impl DecodeGraphSON for Duration {
 impl EncodeGraphSON for MonthDay {
     fn encode_v3(&self) -> serde_json::Value {
         json!({
-          "@type" : "gx:MonthDay",
+          "@type" : type_identifier(CoreType::MonthDay),
           "@value" : format!("--{:02}-{:02}",self.month,self.day)
         })
     }
@@ -239,7 +257,7 @@ impl DecodeGraphSON for MonthDay {
     where
         Self: std::marker::Sized,
     {
-        let s = validate_type(j_val, "gx:MonthDay")?
+        let s = validate_type(j_val, CoreType::MonthDay)?
             .as_str()
             .ok_or_else(|| GraphSonError::WrongJsonType("str".to_string()))?;
         let month = s[2..4]
@@ -270,7 +288,7 @@ impl DecodeGraphSON for MonthDay {
 impl EncodeGraphSON for Year {
     fn encode_v3(&self) -> serde_json::Value {
         json!({
-          "@type" : "gx:Year",
+          "@type" : type_identifier(CoreType::Year),
           "@value" : self.0
         })
     }
@@ -289,7 +307,7 @@ impl DecodeGraphSON for Year {
     where
         Self: std::marker::Sized,
     {
-        let s = validate_type(j_val, "gx:Year")?
+        let s = validate_type(j_val, CoreType::Year)?
             .as_str()
             .ok_or_else(|| GraphSonError::WrongJsonType("str".to_string()))?;
         let year = s
@@ -317,7 +335,7 @@ impl DecodeGraphSON for Year {
 impl EncodeGraphSON for YearMonth {
     fn encode_v3(&self) -> serde_json::Value {
         json!({
-          "@type" : "gx:YearMonth",
+          "@type" : type_identifier(CoreType::YearMonth),
           "@value" : format!("{:04}-{:02}",self.year,self.month)
         })
     }
@@ -336,7 +354,7 @@ impl DecodeGraphSON for YearMonth {
     where
         Self: std::marker::Sized,
     {
-        let s = validate_type(j_val, "gx:YearMonth")?
+        let s = validate_type(j_val, CoreType::YearMonth)?
             .as_str()
             .ok_or_else(|| GraphSonError::WrongJsonType("str".to_string()))?;
 
@@ -373,7 +391,7 @@ impl DecodeGraphSON for YearMonth {
 impl EncodeGraphSON for NaiveTime {
     fn encode_v3(&self) -> serde_json::Value {
         json!({
-          "@type" : "gx:LocalTime",
+          "@type" : type_identifier(CoreType::LocalTime),
           "@value" : self.format("%H:%M:%S").to_string()
         })
     }
@@ -392,7 +410,7 @@ impl DecodeGraphSON for NaiveTime {
     where
         Self: std::marker::Sized,
     {
-        let s = validate_type(j_val, "gx:LocalTime")?
+        let s = validate_type(j_val, CoreType::LocalTime)?
             .as_str()
             .ok_or_else(|| GraphSonError::WrongJsonType("str".to_string()))?;
 
@@ -420,7 +438,7 @@ impl DecodeGraphSON for NaiveTime {
 impl EncodeGraphSON for NaiveDate {
     fn encode_v3(&self) -> serde_json::Value {
         json!({
-          "@type" : "gx:LocalDate",
+          "@type" : type_identifier(CoreType::LocalDate),
           "@value" : self.format("%Y-%m-%d").to_string()
         })
     }
@@ -439,7 +457,7 @@ impl DecodeGraphSON for NaiveDate {
     where
         Self: std::marker::Sized,
     {
-        let s = validate_type(j_val, "gx:LocalDate")?
+        let s = validate_type(j_val, CoreType::LocalDate)?
             .as_str()
             .ok_or_else(|| GraphSonError::WrongJsonType("str".to_string()))?;
 
@@ -465,7 +483,7 @@ impl DecodeGraphSON for NaiveDate {
 impl EncodeGraphSON for NaiveDateTime {
     fn encode_v3(&self) -> serde_json::Value {
         json!({
-            "@type" : "gx:LocalDateTime",
+            "@type" : type_identifier(CoreType::LocalDateTime),
             "@value" : self.format("%Y-%m-%dT%H:%M").to_string()
         })
     }
@@ -484,7 +502,7 @@ impl DecodeGraphSON for NaiveDateTime {
     where
         Self: std::marker::Sized,
     {
-        let s = validate_type(j_val, "gx:LocalDateTime")?
+        let s = validate_type(j_val, CoreType::LocalDateTime)?
             .as_str()
             .ok_or_else(|| GraphSonError::WrongJsonType("str".to_string()))?;
 
@@ -513,7 +531,7 @@ impl EncodeGraphSON for FixedOffset {
     fn encode_v3(&self) -> serde_json::Value {
         self.to_string();
         json!({
-          "@type" : "gx:ZoneOffset",
+          "@type" : type_identifier(CoreType::ZoneOffset),
           "@value" : self.to_string()
         })
     }
@@ -532,7 +550,7 @@ impl DecodeGraphSON for FixedOffset {
     where
         Self: std::marker::Sized,
     {
-        let s = validate_type(j_val, "gx:ZoneOffset")?
+        let s = validate_type(j_val, CoreType::ZoneOffset)?
             .as_str()
             .ok_or_else(|| GraphSonError::WrongJsonType("str".to_string()))?;
 
@@ -581,7 +599,7 @@ impl DecodeGraphSON for FixedOffset {
 impl EncodeGraphSON for OffsetTime {
     fn encode_v3(&self) -> serde_json::Value {
         json!({
-          "@type" : "gx:OffsetTime",
+          "@type" : type_identifier(CoreType::OffsetTime),
           "@value" : format!("{}{}",self.time.format("%H:%M:%S"),self.offset)
         })
     }
@@ -600,7 +618,7 @@ impl DecodeGraphSON for OffsetTime {
     where
         Self: std::marker::Sized,
     {
-        let s = validate_type(j_val, "gx:OffsetTime")?
+        let s = validate_type(j_val, CoreType::OffsetTime)?
             .as_str()
             .ok_or_else(|| GraphSonError::WrongJsonType("str".to_string()))?;
 
@@ -639,7 +657,7 @@ impl DecodeGraphSON for OffsetTime {
 impl EncodeGraphSON for DateTime<FixedOffset> {
     fn encode_v3(&self) -> serde_json::Value {
         json!({
-          "@type" : "gx:OffsetDateTime",
+          "@type" : type_identifier(CoreType::OffsetDateTime),
           "@value" : self.format("%Y-%m-%dT%H:%M:%S%:z").to_string()
         })
     }
@@ -658,7 +676,7 @@ impl DecodeGraphSON for DateTime<FixedOffset> {
     where
         Self: std::marker::Sized,
     {
-        let s = validate_type(j_val, "gx:OffsetDateTime")?
+        let s = validate_type(j_val, CoreType::OffsetDateTime)?
             .as_str()
             .ok_or_else(|| GraphSonError::WrongJsonType("str".to_string()))?;
 
@@ -686,7 +704,7 @@ impl DecodeGraphSON for DateTime<FixedOffset> {
 impl EncodeGraphSON for ZonedDateTime {
     fn encode_v3(&self) -> serde_json::Value {
         json!({
-          "@type" : "gx:ZonedDateTime",
+          "@type" : type_identifier(CoreType::ZonedDateTime),
           "@value" : self.0.format("%Y-%m-%dT%H:%M:%S%.f%:z[GMT%:z]").to_string()
         })
     }
@@ -705,7 +723,7 @@ impl DecodeGraphSON for ZonedDateTime {
     where
         Self: std::marker::Sized,
     {
-        let mut s = validate_type(j_val, "gx:ZonedDateTime")?
+        let mut s = validate_type(j_val, CoreType::ZonedDateTime)?
             .as_str()
             .ok_or_else(|| GraphSonError::WrongJsonType("str".to_string()))?;
         if let Some(len) = s.find('[') {
@@ -741,69 +759,17 @@ impl DecodeGraphSON for ZonedDateTime {
     }
 }
 
-impl DecodeGraphSON for char {
-    fn decode_v3(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
-    where
-        Self: std::marker::Sized,
-    {
-        j_val
-            .as_object()
-            .filter(|map| validate_type_entry(*map, "gx:Char"))
-            .and_then(|map| map.get("@value"))
-            .and_then(|value| value.as_str())
-            .and_then(|s| s.chars().next()) //FIXME more than 1 char is not evaluated
-            .ok_or_else(|| GraphSonError::WrongJsonType("str".to_string()))
-    }
-
-    fn decode_v2(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
-    where
-        Self: std::marker::Sized,
-    {
-        Self::decode_v3(j_val)
-    }
-
-    fn decode_v1(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
-    where
-        Self: std::marker::Sized,
-    {
-        j_val
-            .as_str()
-            .and_then(|s| s.chars().next())
-            .ok_or_else(|| GraphSonError::WrongJsonType("str".to_string()))
-    }
-}
-
-impl EncodeGraphSON for char {
-    fn encode_v3(&self) -> serde_json::Value {
-        json!({
-            "@type": "gx:Char",
-            "@value": self
-        })
-    }
-
-    fn encode_v2(&self) -> serde_json::Value {
-        json!({
-            "@type": "gx:Char",
-            "@value": self
-        })
-    }
-
-    fn encode_v1(&self) -> serde_json::Value {
-        todo!()
-    }
-}
-
 impl EncodeGraphSON for IpAddr {
     fn encode_v3(&self) -> serde_json::Value {
         json!({
-            "@type" : "gx:InetAddress",
+            "@type" : type_identifier(CoreType::InetAddress),
             "@value" : self.to_string()
         })
     }
 
     fn encode_v2(&self) -> serde_json::Value {
         json!({
-            "@type" : "gx:InetAddress",
+            "@type" : type_identifier(CoreType::InetAddress),
             "@value" : self.to_string()
         })
     }
@@ -818,7 +784,7 @@ impl DecodeGraphSON for IpAddr {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "gx:InetAddress")?;
+        let value_object = validate_type(j_val, CoreType::InetAddress)?;
 
         match value_object
             .as_str()
@@ -1098,6 +1064,19 @@ fn duration_decode_v3() {
     assert_eq!(res, expected);
 }
 
+#[test]
+fn duration_millis_decode_v3() {
+    let iso = r#"{"@type":"gx:Duration","@value":"PT1.5S"}"#;
+    let millis = r#"{"@type":"gx:Duration","@value":1500}"#;
+    let expected = Duration::milliseconds(1500);
+
+    let v = serde_json::from_str(iso).unwrap();
+    assert_eq!(expected, Duration::decode_v3(&v).unwrap());
+    let v = serde_json::from_str(millis).unwrap();
+    assert_eq!(expected, Duration::decode_v3(&v).unwrap());
+    assert_eq!(expected, Duration::decode_v2(&v).unwrap());
+}
+
 #[test]
 fn period_encode_v3() {
     let expected = r#"{"@type":"gx:Period","@value":"P2Y5M-1D"}"#;
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, net::IpAddr};
+use std::{cell::Cell, collections::HashMap, net::IpAddr};
 
 use bigdecimal::BigDecimal;
 #[cfg(feature = "extended")]
@@ -11,9 +11,11 @@ use uuid::Uuid;
 use crate::extended::chrono::{
     Instant, MonthDay, OffsetTime, Period, Year, YearMonth, ZonedDateTime,
 };
+use crate::specs::{core_type_from_graphson, CoreType};
 use crate::{
     error::GraphSonError,
     structure::{
+        bulkset::BulkSet,
         bytebuffer::ByteBuffer,
         bytecode::Bytecode,
         edge::Edge,
@@ -26,7 +28,9 @@ use crate::{
         metrics::{Metrics, TraversalMetrics},
         path::Path,
         property::Property,
-        traverser::Traverser,
+        set::Set,
+        traverser::{TraversalStrategy, Traverser},
+        tree::Tree,
         vertex::Vertex,
         vertex_property::VertexProperty,
     },
@@ -39,6 +43,233 @@ mod extended;
 mod primitivs;
 mod std_collections;
 mod structures;
+mod validate;
+
+pub use crate::specs::graphson_identifier;
+pub use validate::validate;
+
+/// The versions of the GraphSON format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphSonVersion {
+    V1,
+    V2,
+    V3,
+}
+
+/// Options for encoding a `GremlinValue` with [`encode_v3_with_config`] and
+/// [`encode_v2_with_config`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct EncodeConfig {
+    /// Replaces `null` elements of `g:List`, `g:Set` and `g:Map` with this value. A bare `null`
+    /// is valid GraphSON 3.0, but some servers only accept a typed placeholder.
+    pub null_placeholder: Option<serde_json::Value>,
+    /// Renders a `g:Double` without fractional part as `100.0`, the TinkerPop format. When
+    /// disabled it is rendered as `100`, which some strict JSON consumers expect.
+    pub integral_double_suffix: bool,
+}
+
+impl Default for EncodeConfig {
+    fn default() -> Self {
+        EncodeConfig {
+            null_placeholder: None,
+            integral_double_suffix: true,
+        }
+    }
+}
+
+impl EncodeConfig {
+    #[must_use]
+    pub fn new() -> Self {
+        EncodeConfig::default()
+    }
+
+    #[must_use]
+    pub fn null_placeholder(mut self, placeholder: serde_json::Value) -> Self {
+        self.null_placeholder = Some(placeholder);
+        self
+    }
+
+    #[must_use]
+    pub fn integral_double_suffix(mut self, suffix: bool) -> Self {
+        self.integral_double_suffix = suffix;
+        self
+    }
+}
+
+/// Encodes the value into GraphSON 3.0 like [`EncodeGraphSON::encode_v3`], applying the options
+/// of `config`.
+#[must_use]
+pub fn encode_v3_with_config(value: &GremlinValue, config: &EncodeConfig) -> serde_json::Value {
+    let mut j_val = {
+        let _guard = DoubleSuffixGuard(
+            INTEGRAL_DOUBLE_SUFFIX.with(|suffix| suffix.replace(config.integral_double_suffix)),
+        );
+        value.encode_v3()
+    };
+    if let Some(placeholder) = &config.null_placeholder {
+        replace_container_nulls(&mut j_val, placeholder);
+    }
+    j_val
+}
+
+/// Encodes the value into GraphSON 2.0 like [`EncodeGraphSON::encode_v2`], applying the options
+/// of `config`.
+#[must_use]
+pub fn encode_v2_with_config(value: &GremlinValue, config: &EncodeConfig) -> serde_json::Value {
+    let mut j_val = {
+        let _guard = DoubleSuffixGuard(
+            INTEGRAL_DOUBLE_SUFFIX.with(|suffix| suffix.replace(config.integral_double_suffix)),
+        );
+        value.encode_v2()
+    };
+    if let Some(placeholder) = &config.null_placeholder {
+        replace_container_nulls(&mut j_val, placeholder);
+    }
+    j_val
+}
+
+thread_local! {
+    static INTEGRAL_DOUBLE_SUFFIX: Cell<bool> = const { Cell::new(true) };
+}
+
+/// Restores the enclosing setting when encoding with a config returns, also if encoding panics.
+struct DoubleSuffixGuard(bool);
+
+impl Drop for DoubleSuffixGuard {
+    fn drop(&mut self) {
+        INTEGRAL_DOUBLE_SUFFIX.with(|suffix| suffix.set(self.0));
+    }
+}
+
+/// Formats a double `@value`, integral values lose the `.0` suffix if the config of the current
+/// encoding disables it.
+pub(crate) fn encode_double(value: f64) -> serde_json::Value {
+    if !INTEGRAL_DOUBLE_SUFFIX.with(Cell::get)
+        && value.fract() == 0.0
+        && value.abs() < 9_007_199_254_740_992.0
+    {
+        return json!(value as i64);
+    }
+    json!(value)
+}
+
+fn replace_container_nulls(j_val: &mut serde_json::Value, placeholder: &serde_json::Value) {
+    match j_val {
+        serde_json::Value::Array(array) => array
+            .iter_mut()
+            .for_each(|val| replace_container_nulls(val, placeholder)),
+        serde_json::Value::Object(object) => {
+            let is_container = matches!(
+                object
+                    .get("@type")
+                    .and_then(serde_json::Value::as_str)
+                    .and_then(core_type_from_graphson),
+                Some(CoreType::List | CoreType::Set | CoreType::Map)
+            );
+            for (key, val) in object.iter_mut() {
+                match val {
+                    serde_json::Value::Array(array) if is_container && key == "@value" => {
+                        for element in array {
+                            if element.is_null() {
+                                *element = placeholder.clone();
+                            } else {
+                                replace_container_nulls(element, placeholder);
+                            }
+                        }
+                    }
+                    val => replace_container_nulls(val, placeholder),
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Encodes the value into GraphSON 3.0 like [`EncodeGraphSON::encode_v3`], with the entries of
+/// every `g:Map` ordered by their encoded key, also maps nested in graph elements and their
+/// properties, traversers, paths and bulk sets. Equal values always produce the same output,
+/// which makes it suitable for string comparisons and caching.
+#[must_use]
+pub fn encode_v3_sorted(value: &GremlinValue) -> serde_json::Value {
+    let _guard = SortedMapsGuard(SORTED_MAPS.with(|sorted| sorted.replace(true)));
+    value.encode_v3()
+}
+
+thread_local! {
+    static SORTED_MAPS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Restores the enclosing setting when [`encode_v3_sorted`] returns, also if encoding panics.
+struct SortedMapsGuard(bool);
+
+impl Drop for SortedMapsGuard {
+    fn drop(&mut self) {
+        SORTED_MAPS.with(|sorted| sorted.set(self.0));
+    }
+}
+
+/// Whether `g:Map` entries are encoded ordered by their key on the current thread.
+pub(crate) fn sorted_maps() -> bool {
+    SORTED_MAPS.with(Cell::get)
+}
+
+const UNKNOWN_TYPE: &str = "a GremlinValue identifier";
+
+/// Decodes GraphSON 3.0 like [`DecodeGraphSON::decode_v3`], but keeps values with an `@type`
+/// this crate does not know instead of failing. Such a value is decoded into a `Map` with the
+/// entries `"@type"`, holding the type identifier, and `"@value"`, holding the raw value
+/// read as plain JSON with [`GremlinValue::from_plain_json`].
+pub fn decode_v3_lenient(j_val: &serde_json::Value) -> Result<GremlinValue, GraphSonError> {
+    let mut j_val = std::borrow::Cow::Borrowed(j_val);
+    loop {
+        match GremlinValue::decode_v3(&j_val) {
+            Err(err) => {
+                let Some(type_id) = unknown_type(&err).map(ToString::to_string) else {
+                    return Err(err);
+                };
+                if !wrap_unknown_type(j_val.to_mut(), &type_id) {
+                    return Err(err);
+                }
+            }
+            ok => return ok,
+        }
+    }
+}
+
+fn unknown_type(err: &GraphSonError) -> Option<&str> {
+    match err {
+        GraphSonError::WrongTypeIdentifier { expected, found } if expected == UNKNOWN_TYPE => {
+            Some(found)
+        }
+        GraphSonError::FieldError { source, .. } => unknown_type(source),
+        _ => None,
+    }
+}
+
+fn wrap_unknown_type(j_val: &mut serde_json::Value, type_id: &str) -> bool {
+    match j_val {
+        serde_json::Value::Array(array) => array.iter_mut().fold(false, |wrapped, val| {
+            wrap_unknown_type(val, type_id) | wrapped
+        }),
+        serde_json::Value::Object(object)
+            if object.get("@type").and_then(serde_json::Value::as_str) == Some(type_id) =>
+        {
+            let raw = object
+                .get("@value")
+                .map(GremlinValue::from_plain_json)
+                .unwrap_or(GremlinValue::UnspecifiedNullObject);
+            *j_val = json!({
+                "@type" : type_identifier(CoreType::Map),
+                "@value" : ["@type", type_id, "@value", raw.encode_v3()]
+            });
+            true
+        }
+        serde_json::Value::Object(object) => object.values_mut().fold(false, |wrapped, val| {
+            wrap_unknown_type(val, type_id) | wrapped
+        }),
+        _ => false,
+    }
+}
 
 pub trait EncodeGraphSON {
     fn encode_v3(&self) -> serde_json::Value;
@@ -69,11 +300,11 @@ impl EncodeGraphSON for GremlinValue {
             GremlinValue::Long(val) => val.encode_v3(),
             GremlinValue::String(val) => val.encode_v3(),
             GremlinValue::Date(val) => json!({
-              "@type" : "g:Date",
+              "@type" : type_identifier(CoreType::Date),
               "@value" : val
             }),
             GremlinValue::Timestamp(val) => json!({
-              "@type" : "g:Timestamp",
+              "@type" : type_identifier(CoreType::Timestamp),
               "@value" : val
             }),
             GremlinValue::Class(val) => val.encode_v3(),
@@ -112,12 +343,12 @@ impl EncodeGraphSON for GremlinValue {
             GremlinValue::Boolean(val) => val.encode_v3(),
             GremlinValue::TextP(val) => val.encode_v3(),
             GremlinValue::BulkSet(val) => val.encode_v3(),
-            // GremlinTypes::Tree(val) => val.encode_v3(),
+            GremlinValue::Tree(val) => val.encode_v3(),
             GremlinValue::Metrics(val) => val.encode_v3(),
             GremlinValue::TraversalMetrics(val) => val.encode_v3(),
+            GremlinValue::TraversalStrategy(val) => val.encode_v3(),
             GremlinValue::Merge(val) => val.encode_v3(),
             GremlinValue::UnspecifiedNullObject => serde_json::Value::Null,
-            #[cfg(feature = "extended")]
             GremlinValue::Char(val) => val.encode_v3(),
             #[cfg(feature = "extended")]
             GremlinValue::Duration(val) => val.encode_v3(),
@@ -147,7 +378,8 @@ impl EncodeGraphSON for GremlinValue {
             GremlinValue::ZonedDateTime(val) => val.encode_v3(),
             #[cfg(feature = "extended")]
             GremlinValue::ZoneOffset(val) => val.encode_v3(),
-            _ => unimplemented!("not supported with GraphSON V3 encode"),
+            #[cfg(feature = "custom")]
+            GremlinValue::Custom(val) => val.encode_v3(),
         }
     }
     fn encode_v2(&self) -> serde_json::Value {
@@ -156,11 +388,11 @@ impl EncodeGraphSON for GremlinValue {
             GremlinValue::Long(val) => val.encode_v2(),
             GremlinValue::String(val) => val.encode_v2(),
             GremlinValue::Date(val) => json!({
-              "@type" : "g:Date",
+              "@type" : type_identifier(CoreType::Date),
               "@value" : val
             }),
             GremlinValue::Timestamp(val) => json!({
-              "@type" : "g:Timestamp",
+              "@type" : type_identifier(CoreType::Timestamp),
               "@value" : val
             }),
             GremlinValue::Class(val) => val.encode_v2(),
@@ -199,12 +431,12 @@ impl EncodeGraphSON for GremlinValue {
             GremlinValue::Boolean(val) => val.encode_v2(),
             GremlinValue::TextP(val) => val.encode_v2(),
             GremlinValue::BulkSet(val) => val.encode_v2(),
-            // GremlinTypes::Tree(val) => val.encode_v2(),
+            GremlinValue::Tree(val) => val.encode_v2(),
             GremlinValue::Metrics(val) => val.encode_v2(),
             GremlinValue::TraversalMetrics(val) => val.encode_v2(),
+            GremlinValue::TraversalStrategy(val) => val.encode_v2(),
             GremlinValue::Merge(val) => val.encode_v2(),
             GremlinValue::UnspecifiedNullObject => serde_json::Value::Null,
-            #[cfg(feature = "extended")]
             GremlinValue::Char(val) => val.encode_v2(),
             #[cfg(feature = "extended")]
             GremlinValue::Duration(val) => val.encode_v2(),
@@ -234,7 +466,8 @@ impl EncodeGraphSON for GremlinValue {
             GremlinValue::ZonedDateTime(val) => val.encode_v2(),
             #[cfg(feature = "extended")]
             GremlinValue::ZoneOffset(val) => val.encode_v2(),
-            _ => unimplemented!("not supported with GraphSON V2 encode"),
+            #[cfg(feature = "custom")]
+            GremlinValue::Custom(val) => val.encode_v2(),
         }
     }
 
@@ -255,111 +488,152 @@ impl DecodeGraphSON for GremlinValue {
             serde_json::Value::Bool(b) => Ok(GremlinValue::Boolean(*b)),
             serde_json::Value::String(s) => Ok(GremlinValue::String(s.clone())),
             serde_json::Value::Object(o) => {
-                match o
+                let type_identifier = o
                     .get("@type")
                     .and_then(|s| s.as_str())
-                    .ok_or_else(|| GraphSonError::KeyNotFound("@type".to_string()))?
-                {
-                    "g:Int32" => Ok(GremlinValue::Int(i32::decode_v3(j_val)?)),
-                    "g:Int64" => Ok(GremlinValue::Long(i64::decode_v3(j_val)?)),
-                    "g:Class" => Ok(GremlinValue::Class(
+                    .ok_or_else(|| GraphSonError::KeyNotFound("@type".to_string()))?;
+                match core_type_from_graphson(type_identifier) {
+                    Some(CoreType::Int32) => Ok(GremlinValue::Int(i32::decode_v3(j_val)?)),
+                    Some(CoreType::Long) => Ok(GremlinValue::Long(i64::decode_v3(j_val)?)),
+                    Some(CoreType::Class) => Ok(GremlinValue::Class(
                         o.get("@value")
                             .and_then(|c| c.as_str())
                             .ok_or_else(|| GraphSonError::WrongJsonType("str".to_string()))
                             .map(|class| class.to_string())?,
                     )),
-                    "g:Date" => Ok(GremlinValue::Date(
-                        o.get("@value")
-                            .and_then(|c| c.as_i64())
-                            .ok_or_else(|| GraphSonError::WrongJsonType("i64".to_string()))?,
-                    )),
-                    "g:Timestamp" => Ok(GremlinValue::Timestamp(
-                        o.get("@value")
-                            .and_then(|c| c.as_i64())
-                            .ok_or_else(|| GraphSonError::WrongJsonType("i64".to_string()))?,
-                    )),
-                    "g:Double" => Ok(GremlinValue::Double(f64::decode_v3(j_val)?)),
-                    "g:Float" => Ok(GremlinValue::Float(f32::decode_v3(j_val)?)),
-                    "g:List" => Ok(GremlinValue::List(Vec::<GremlinValue>::decode_v3(j_val)?)),
-                    "g:Map" => Ok(GremlinValue::Map(
+                    Some(CoreType::Date) => Ok(GremlinValue::Date(date_millis(o)?)),
+                    Some(CoreType::Timestamp) => Ok(GremlinValue::Timestamp(date_millis(o)?)),
+                    Some(CoreType::Double) => Ok(GremlinValue::Double(f64::decode_v3(j_val)?)),
+                    Some(CoreType::Float) => Ok(GremlinValue::Float(f32::decode_v3(j_val)?)),
+                    Some(CoreType::List) => {
+                        Ok(GremlinValue::List(Vec::<GremlinValue>::decode_v3(j_val)?))
+                    }
+                    Some(CoreType::Set) => Ok(GremlinValue::Set(Set::decode_v3(j_val)?)),
+                    Some(CoreType::Map) => Ok(GremlinValue::Map(
                         HashMap::<MapKeys, GremlinValue>::decode_v3(j_val)?,
                     )),
-                    "g:UUID" => Ok(GremlinValue::Uuid(Uuid::decode_v3(j_val)?)),
-                    "g:Edge" => Ok(GremlinValue::Edge(Edge::decode_v3(j_val)?)),
-                    "g:Path" => Ok(GremlinValue::Path(Path::decode_v3(j_val)?)),
-                    "g:Property" => Ok(GremlinValue::Property(Property::decode_v3(j_val)?)),
-                    "g:tinker:graph" => Ok(GremlinValue::Graph(Graph::decode_v3(j_val)?)),
-                    "g:Vertex" => Ok(GremlinValue::Vertex(Vertex::decode_v3(j_val)?)),
-                    "g:VertexProperty" => Ok(GremlinValue::VertexProperty(
+                    Some(CoreType::Uuid) => Ok(GremlinValue::Uuid(Uuid::decode_v3(j_val)?)),
+                    Some(CoreType::Edge) => Ok(GremlinValue::Edge(Edge::decode_v3(j_val)?)),
+                    Some(CoreType::Path) => Ok(GremlinValue::Path(Path::decode_v3(j_val)?)),
+                    Some(CoreType::Property) => {
+                        Ok(GremlinValue::Property(Property::decode_v3(j_val)?))
+                    }
+                    Some(CoreType::Graph) => Ok(GremlinValue::Graph(Graph::decode_v3(j_val)?)),
+                    Some(CoreType::Vertex) => Ok(GremlinValue::Vertex(Vertex::decode_v3(j_val)?)),
+                    Some(CoreType::VertexProperty) => Ok(GremlinValue::VertexProperty(
                         VertexProperty::decode_v3(j_val)?,
                     )),
-                    "g:Barrier" => Ok(GremlinValue::Barrier(Barrier::decode_v3(j_val)?)),
-                    "g:Binding" => Ok(GremlinValue::Binding(Binding::decode_v3(j_val)?)),
-                    "g:Bytecode" => Ok(GremlinValue::Bytecode(Bytecode::decode_v3(j_val)?)),
-                    "g:Cardinality" => {
+                    Some(CoreType::Barrier) => {
+                        Ok(GremlinValue::Barrier(Barrier::decode_v3(j_val)?))
+                    }
+                    Some(CoreType::Binding) => {
+                        Ok(GremlinValue::Binding(Binding::decode_v3(j_val)?))
+                    }
+                    Some(CoreType::ByteCode) => {
+                        Ok(GremlinValue::Bytecode(Bytecode::decode_v3(j_val)?))
+                    }
+                    Some(CoreType::Cardinality) => {
                         Ok(GremlinValue::Cardinality(Cardinality::decode_v3(j_val)?))
                     }
-                    "g:Column" => Ok(GremlinValue::Column(Column::decode_v3(j_val)?)),
-                    "g:Direction" => Ok(GremlinValue::Direction(Direction::decode_v3(j_val)?)),
-                    "g:Lambda" => Ok(GremlinValue::Lambda(Lambda::decode_v3(j_val)?)),
-                    "g:Merge" => Ok(GremlinValue::Merge(Merge::decode_v3(j_val)?)),
-                    "g:Metrics" => Ok(GremlinValue::Metrics(Metrics::decode_v3(j_val)?)),
-                    "g:Operator" => Ok(GremlinValue::Operator(Operator::decode_v3(j_val)?)),
-                    "g:Order" => Ok(GremlinValue::Order(Order::decode_v3(j_val)?)),
-                    "g:P" => Ok(GremlinValue::P(P::decode_v3(j_val)?)),
-                    "g:Pick" => Ok(GremlinValue::Pick(Pick::decode_v3(j_val)?)),
-                    "g:Pop" => Ok(GremlinValue::Pop(Pop::decode_v3(j_val)?)),
-                    "g:Scope" => Ok(GremlinValue::Scope(Scope::decode_v3(j_val)?)),
-                    "g:T" => Ok(GremlinValue::T(T::decode_v3(j_val)?)),
-                    "g:TextP" => Ok(GremlinValue::TextP(TextP::decode_v3(j_val)?)),
-                    "g:TraversalMetrics" => Ok(GremlinValue::TraversalMetrics(
+                    Some(CoreType::Column) => Ok(GremlinValue::Column(Column::decode_v3(j_val)?)),
+                    Some(CoreType::Direction) => {
+                        Ok(GremlinValue::Direction(Direction::decode_v3(j_val)?))
+                    }
+                    Some(CoreType::Lambda) => Ok(GremlinValue::Lambda(Lambda::decode_v3(j_val)?)),
+                    Some(CoreType::Merge) => Ok(GremlinValue::Merge(Merge::decode_v3(j_val)?)),
+                    Some(CoreType::Metrics) => {
+                        Ok(GremlinValue::Metrics(Metrics::decode_v3(j_val)?))
+                    }
+                    Some(CoreType::Operator) => {
+                        Ok(GremlinValue::Operator(Operator::decode_v3(j_val)?))
+                    }
+                    Some(CoreType::Order) => Ok(GremlinValue::Order(Order::decode_v3(j_val)?)),
+                    Some(CoreType::P) => Ok(GremlinValue::P(P::decode_v3(j_val)?)),
+                    Some(CoreType::Pick) => Ok(GremlinValue::Pick(Pick::decode_v3(j_val)?)),
+                    Some(CoreType::Pop) => Ok(GremlinValue::Pop(Pop::decode_v3(j_val)?)),
+                    Some(CoreType::Scope) => Ok(GremlinValue::Scope(Scope::decode_v3(j_val)?)),
+                    Some(CoreType::T) => Ok(GremlinValue::T(T::decode_v3(j_val)?)),
+                    Some(CoreType::TextP) => Ok(GremlinValue::TextP(TextP::decode_v3(j_val)?)),
+                    Some(CoreType::TraversalMetrics) => Ok(GremlinValue::TraversalMetrics(
                         TraversalMetrics::decode_v3(j_val)?,
                     )),
-                    "g:Traverser" => Ok(GremlinValue::Traverser(Traverser::decode_v3(j_val)?)),
-                    "gx:BigDecimal" => Ok(GremlinValue::BigDecimal(BigDecimal::decode_v3(j_val)?)),
-                    "gx:BigInteger" => Ok(GremlinValue::BigInteger(BigInt::decode_v3(j_val)?)),
-                    "gx:Byte" => Ok(GremlinValue::Byte(u8::decode_v3(j_val)?)),
-                    "gx:ByteBuffer" => Ok(GremlinValue::ByteBuffer(ByteBuffer::decode_v3(j_val)?)),
-                    "gx:Int16" => Ok(GremlinValue::Short(i16::decode_v3(j_val)?)),
-                    #[cfg(feature = "extended")]
-                    "gx:Char" => Ok(GremlinValue::Char(char::decode_v3(j_val)?)),
+                    Some(CoreType::TraversalStrategy) => Ok(GremlinValue::TraversalStrategy(
+                        TraversalStrategy::decode_v3(j_val)?,
+                    )),
+                    Some(CoreType::Traverser) => {
+                        Ok(GremlinValue::Traverser(Traverser::decode_v3(j_val)?))
+                    }
+                    Some(CoreType::BulkSet) => {
+                        Ok(GremlinValue::BulkSet(BulkSet::decode_v3(j_val)?))
+                    }
+                    Some(CoreType::Tree) => Ok(GremlinValue::Tree(Tree::decode_v3(j_val)?)),
+                    Some(CoreType::BigDecimal) => {
+                        Ok(GremlinValue::BigDecimal(BigDecimal::decode_v3(j_val)?))
+                    }
+                    Some(CoreType::BigInteger) => {
+                        Ok(GremlinValue::BigInteger(BigInt::decode_v3(j_val)?))
+                    }
+                    Some(CoreType::Byte) => Ok(GremlinValue::Byte(u8::decode_v3(j_val)?)),
+                    Some(CoreType::ByteBuffer) => {
+                        Ok(GremlinValue::ByteBuffer(ByteBuffer::decode_v3(j_val)?))
+                    }
+                    Some(CoreType::Short) => Ok(GremlinValue::Short(i16::decode_v3(j_val)?)),
+                    Some(CoreType::Char) => Ok(GremlinValue::Char(char::decode_v3(j_val)?)),
                     #[cfg(feature = "extended")]
-                    "gx:Duration" => Ok(GremlinValue::Duration(Duration::decode_v3(j_val)?)),
+                    Some(CoreType::Duration) => {
+                        Ok(GremlinValue::Duration(Duration::decode_v3(j_val)?))
+                    }
                     #[cfg(feature = "extended")]
-                    "gx:InetAddress" => Ok(GremlinValue::InetAddress(IpAddr::decode_v3(j_val)?)),
+                    Some(CoreType::InetAddress) => {
+                        Ok(GremlinValue::InetAddress(IpAddr::decode_v3(j_val)?))
+                    }
                     #[cfg(feature = "extended")]
-                    "gx:Instant" => Ok(GremlinValue::Instant(Instant::decode_v3(j_val)?)),
+                    Some(CoreType::Instant) => {
+                        Ok(GremlinValue::Instant(Instant::decode_v3(j_val)?))
+                    }
                     #[cfg(feature = "extended")]
-                    "gx:LocalDate" => Ok(GremlinValue::LocalDate(NaiveDate::decode_v3(j_val)?)),
+                    Some(CoreType::LocalDate) => {
+                        Ok(GremlinValue::LocalDate(NaiveDate::decode_v3(j_val)?))
+                    }
                     #[cfg(feature = "extended")]
-                    "gx:LocalDateTime" => Ok(GremlinValue::LocalDateTime(
+                    Some(CoreType::LocalDateTime) => Ok(GremlinValue::LocalDateTime(
                         NaiveDateTime::decode_v3(j_val)?,
                     )),
                     #[cfg(feature = "extended")]
-                    "gx:LocalTime" => Ok(GremlinValue::LocalTime(NaiveTime::decode_v3(j_val)?)),
+                    Some(CoreType::LocalTime) => {
+                        Ok(GremlinValue::LocalTime(NaiveTime::decode_v3(j_val)?))
+                    }
                     #[cfg(feature = "extended")]
-                    "gx:MonthDay" => Ok(GremlinValue::MonthDay(MonthDay::decode_v3(j_val)?)),
+                    Some(CoreType::MonthDay) => {
+                        Ok(GremlinValue::MonthDay(MonthDay::decode_v3(j_val)?))
+                    }
                     #[cfg(feature = "extended")]
-                    "gx:OffsetDateTime" => {
+                    Some(CoreType::OffsetDateTime) => {
                         Ok(GremlinValue::OffsetDateTime(DateTime::decode_v3(j_val)?))
                     }
                     #[cfg(feature = "extended")]
-                    "gx:OffsetTime" => Ok(GremlinValue::OffsetTime(OffsetTime::decode_v3(j_val)?)),
+                    Some(CoreType::OffsetTime) => {
+                        Ok(GremlinValue::OffsetTime(OffsetTime::decode_v3(j_val)?))
+                    }
                     #[cfg(feature = "extended")]
-                    "gx:Period" => Ok(GremlinValue::Period(Period::decode_v3(j_val)?)),
+                    Some(CoreType::Period) => Ok(GremlinValue::Period(Period::decode_v3(j_val)?)),
                     #[cfg(feature = "extended")]
-                    "gx:Year" => Ok(GremlinValue::Year(Year::decode_v3(j_val)?)),
+                    Some(CoreType::Year) => Ok(GremlinValue::Year(Year::decode_v3(j_val)?)),
                     #[cfg(feature = "extended")]
-                    "gx:YearMonth" => Ok(GremlinValue::YearMonth(YearMonth::decode_v3(j_val)?)),
+                    Some(CoreType::YearMonth) => {
+                        Ok(GremlinValue::YearMonth(YearMonth::decode_v3(j_val)?))
+                    }
                     #[cfg(feature = "extended")]
-                    "gx:ZonedDateTime" => Ok(GremlinValue::ZonedDateTime(
+                    Some(CoreType::ZonedDateTime) => Ok(GremlinValue::ZonedDateTime(
                         ZonedDateTime::decode_v3(j_val)?,
                     )),
                     #[cfg(feature = "extended")]
-                    "gx:ZoneOffset" => Ok(GremlinValue::ZoneOffset(FixedOffset::decode_v3(j_val)?)),
-                    rest => Err(GraphSonError::WrongTypeIdentifier {
-                        expected: "a GremlinValue identifier".to_string(),
-                        found: rest.to_string(),
+                    Some(CoreType::ZoneOffset) => {
+                        Ok(GremlinValue::ZoneOffset(FixedOffset::decode_v3(j_val)?))
+                    }
+                    _ => Err(GraphSonError::WrongTypeIdentifier {
+                        expected: UNKNOWN_TYPE.to_string(),
+                        found: type_identifier.to_string(),
                     }),
                 }
             }
@@ -384,119 +658,164 @@ impl DecodeGraphSON for GremlinValue {
                 }
                 Ok(GremlinValue::List(vec))
             }
+            // an empty object carries no type information and is always an empty map
+            serde_json::Value::Object(o) if o.is_empty() => Ok(GremlinValue::Map(HashMap::new())),
+            // an object with a type identifier is a typed value and must carry its value
+            serde_json::Value::Object(o)
+                if o.contains_key("@type") && !o.contains_key("@value") =>
+            {
+                Err(GraphSonError::KeyNotFound("@value".to_string()))
+            }
             serde_json::Value::Object(o) => {
                 if let Some(type_identifier) = o.get("@type").and_then(|s| s.as_str()) {
-                    match type_identifier {
-                        "g:Int32" => Ok(GremlinValue::Int(i32::decode_v2(j_val)?)),
-                        "g:Int64" => Ok(GremlinValue::Long(i64::decode_v2(j_val)?)),
-                        "g:Class" => Ok(GremlinValue::Class(
+                    match core_type_from_graphson(type_identifier) {
+                        Some(CoreType::Int32) => Ok(GremlinValue::Int(i32::decode_v2(j_val)?)),
+                        Some(CoreType::Long) => Ok(GremlinValue::Long(i64::decode_v2(j_val)?)),
+                        Some(CoreType::Class) => Ok(GremlinValue::Class(
                             o.get("@value")
                                 .and_then(|c| c.as_str())
                                 .ok_or_else(|| GraphSonError::WrongJsonType("str".to_string()))
                                 .map(ToString::to_string)?,
                         )),
-                        "g:Date" => Ok(GremlinValue::Date(
-                            o.get("@value")
-                                .and_then(|c| c.as_i64())
-                                .ok_or_else(|| GraphSonError::WrongJsonType("i64".to_string()))?,
-                        )),
-                        "g:Timestamp" => Ok(GremlinValue::Timestamp(
-                            o.get("@value")
-                                .and_then(|c| c.as_i64())
-                                .ok_or_else(|| GraphSonError::WrongJsonType("i64".to_string()))?,
-                        )),
-                        "g:Double" => Ok(GremlinValue::Double(f64::decode_v2(j_val)?)),
-                        "g:Float" => Ok(GremlinValue::Float(f32::decode_v2(j_val)?)),
-                        "g:List" => Ok(GremlinValue::List(Vec::<GremlinValue>::decode_v2(j_val)?)),
-                        "g:Map" => Ok(GremlinValue::Map(
-                            HashMap::<MapKeys, GremlinValue>::decode_v2(j_val)?,
-                        )),
-                        "g:UUID" => Ok(GremlinValue::Uuid(Uuid::decode_v2(j_val)?)),
-                        "g:Edge" => Ok(GremlinValue::Edge(Edge::decode_v2(j_val)?)),
-                        "g:Path" => Ok(GremlinValue::Path(Path::decode_v2(j_val)?)),
-                        "g:Property" => Ok(GremlinValue::Property(Property::decode_v2(j_val)?)),
-                        "g:tinker:graph" => Ok(GremlinValue::Graph(Graph::decode_v2(j_val)?)),
-                        "g:Vertex" => Ok(GremlinValue::Vertex(Vertex::decode_v2(j_val)?)),
-                        "g:VertexProperty" => Ok(GremlinValue::VertexProperty(
+                        Some(CoreType::Date) => Ok(GremlinValue::Date(date_millis(o)?)),
+                        Some(CoreType::Timestamp) => Ok(GremlinValue::Timestamp(date_millis(o)?)),
+                        Some(CoreType::Double) => Ok(GremlinValue::Double(f64::decode_v2(j_val)?)),
+                        Some(CoreType::Float) => Ok(GremlinValue::Float(f32::decode_v2(j_val)?)),
+                        Some(CoreType::List) => {
+                            Ok(GremlinValue::List(Vec::<GremlinValue>::decode_v2(j_val)?))
+                        }
+                        Some(CoreType::Map) => {
+                            Ok(GremlinValue::Map(
+                                HashMap::<MapKeys, GremlinValue>::decode_v2(j_val)?,
+                            ))
+                        }
+                        Some(CoreType::Uuid) => Ok(GremlinValue::Uuid(Uuid::decode_v2(j_val)?)),
+                        Some(CoreType::Edge) => Ok(GremlinValue::Edge(Edge::decode_v2(j_val)?)),
+                        Some(CoreType::Path) => Ok(GremlinValue::Path(Path::decode_v2(j_val)?)),
+                        Some(CoreType::Property) => {
+                            Ok(GremlinValue::Property(Property::decode_v2(j_val)?))
+                        }
+                        Some(CoreType::Graph) => Ok(GremlinValue::Graph(Graph::decode_v2(j_val)?)),
+                        Some(CoreType::Vertex) => {
+                            Ok(GremlinValue::Vertex(Vertex::decode_v2(j_val)?))
+                        }
+                        Some(CoreType::VertexProperty) => Ok(GremlinValue::VertexProperty(
                             VertexProperty::decode_v2(j_val)?,
                         )),
-                        "g:Barrier" => Ok(GremlinValue::Barrier(Barrier::decode_v2(j_val)?)),
-                        "g:Binding" => Ok(GremlinValue::Binding(Binding::decode_v2(j_val)?)),
-                        "g:Bytecode" => Ok(GremlinValue::Bytecode(Bytecode::decode_v2(j_val)?)),
-                        "g:Cardinality" => {
+                        Some(CoreType::Barrier) => {
+                            Ok(GremlinValue::Barrier(Barrier::decode_v2(j_val)?))
+                        }
+                        Some(CoreType::Binding) => {
+                            Ok(GremlinValue::Binding(Binding::decode_v2(j_val)?))
+                        }
+                        Some(CoreType::ByteCode) => {
+                            Ok(GremlinValue::Bytecode(Bytecode::decode_v2(j_val)?))
+                        }
+                        Some(CoreType::Cardinality) => {
                             Ok(GremlinValue::Cardinality(Cardinality::decode_v2(j_val)?))
                         }
-                        "g:Column" => Ok(GremlinValue::Column(Column::decode_v2(j_val)?)),
-                        "g:Direction" => Ok(GremlinValue::Direction(Direction::decode_v2(j_val)?)),
-                        "g:Lambda" => Ok(GremlinValue::Lambda(Lambda::decode_v2(j_val)?)),
-                        "g:Merge" => Ok(GremlinValue::Merge(Merge::decode_v2(j_val)?)),
-                        "g:Metrics" => Ok(GremlinValue::Metrics(Metrics::decode_v2(j_val)?)),
-                        "g:Operator" => Ok(GremlinValue::Operator(Operator::decode_v2(j_val)?)),
-                        "g:Order" => Ok(GremlinValue::Order(Order::decode_v2(j_val)?)),
-                        "g:P" => Ok(GremlinValue::P(P::decode_v2(j_val)?)),
-                        "g:Pick" => Ok(GremlinValue::Pick(Pick::decode_v2(j_val)?)),
-                        "g:Pop" => Ok(GremlinValue::Pop(Pop::decode_v2(j_val)?)),
-                        "g:Scope" => Ok(GremlinValue::Scope(Scope::decode_v2(j_val)?)),
-                        "g:T" => Ok(GremlinValue::T(T::decode_v2(j_val)?)),
-                        "g:TextP" => Ok(GremlinValue::TextP(TextP::decode_v2(j_val)?)),
-                        "g:TraversalMetrics" => Ok(GremlinValue::TraversalMetrics(
+                        Some(CoreType::Column) => {
+                            Ok(GremlinValue::Column(Column::decode_v2(j_val)?))
+                        }
+                        Some(CoreType::Direction) => {
+                            Ok(GremlinValue::Direction(Direction::decode_v2(j_val)?))
+                        }
+                        Some(CoreType::Lambda) => {
+                            Ok(GremlinValue::Lambda(Lambda::decode_v2(j_val)?))
+                        }
+                        Some(CoreType::Merge) => Ok(GremlinValue::Merge(Merge::decode_v2(j_val)?)),
+                        Some(CoreType::Metrics) => {
+                            Ok(GremlinValue::Metrics(Metrics::decode_v2(j_val)?))
+                        }
+                        Some(CoreType::Operator) => {
+                            Ok(GremlinValue::Operator(Operator::decode_v2(j_val)?))
+                        }
+                        Some(CoreType::Order) => Ok(GremlinValue::Order(Order::decode_v2(j_val)?)),
+                        Some(CoreType::P) => Ok(GremlinValue::P(P::decode_v2(j_val)?)),
+                        Some(CoreType::Pick) => Ok(GremlinValue::Pick(Pick::decode_v2(j_val)?)),
+                        Some(CoreType::Pop) => Ok(GremlinValue::Pop(Pop::decode_v2(j_val)?)),
+                        Some(CoreType::Scope) => Ok(GremlinValue::Scope(Scope::decode_v2(j_val)?)),
+                        Some(CoreType::T) => Ok(GremlinValue::T(T::decode_v2(j_val)?)),
+                        Some(CoreType::TextP) => Ok(GremlinValue::TextP(TextP::decode_v2(j_val)?)),
+                        Some(CoreType::TraversalMetrics) => Ok(GremlinValue::TraversalMetrics(
                             TraversalMetrics::decode_v2(j_val)?,
                         )),
-                        "g:Traverser" => Ok(GremlinValue::Traverser(Traverser::decode_v2(j_val)?)),
-                        "gx:BigDecimal" => {
+                        Some(CoreType::TraversalStrategy) => Ok(GremlinValue::TraversalStrategy(
+                            TraversalStrategy::decode_v2(j_val)?,
+                        )),
+                        Some(CoreType::Traverser) => {
+                            Ok(GremlinValue::Traverser(Traverser::decode_v2(j_val)?))
+                        }
+                        Some(CoreType::Tree) => Ok(GremlinValue::Tree(Tree::decode_v2(j_val)?)),
+                        Some(CoreType::BigDecimal) => {
                             Ok(GremlinValue::BigDecimal(BigDecimal::decode_v2(j_val)?))
                         }
-                        "gx:BigInteger" => Ok(GremlinValue::BigInteger(BigInt::decode_v2(j_val)?)),
-                        "gx:Byte" => Ok(GremlinValue::Byte(u8::decode_v2(j_val)?)),
-                        "gx:ByteBuffer" => {
+                        Some(CoreType::BigInteger) => {
+                            Ok(GremlinValue::BigInteger(BigInt::decode_v2(j_val)?))
+                        }
+                        Some(CoreType::Byte) => Ok(GremlinValue::Byte(u8::decode_v2(j_val)?)),
+                        Some(CoreType::ByteBuffer) => {
                             Ok(GremlinValue::ByteBuffer(ByteBuffer::decode_v2(j_val)?))
                         }
-                        "gx:Int16" => Ok(GremlinValue::Short(i16::decode_v2(j_val)?)),
-                        #[cfg(feature = "extended")]
-                        "gx:Char" => Ok(GremlinValue::Char(char::decode_v2(j_val)?)),
+                        Some(CoreType::Short) => Ok(GremlinValue::Short(i16::decode_v2(j_val)?)),
+                        Some(CoreType::Char) => Ok(GremlinValue::Char(char::decode_v2(j_val)?)),
                         #[cfg(feature = "extended")]
-                        "gx:Duration" => Ok(GremlinValue::Duration(Duration::decode_v2(j_val)?)),
+                        Some(CoreType::Duration) => {
+                            Ok(GremlinValue::Duration(Duration::decode_v2(j_val)?))
+                        }
                         #[cfg(feature = "extended")]
-                        "gx:InetAddress" => {
+                        Some(CoreType::InetAddress) => {
                             Ok(GremlinValue::InetAddress(IpAddr::decode_v2(j_val)?))
                         }
                         #[cfg(feature = "extended")]
-                        "gx:Instant" => Ok(GremlinValue::Instant(Instant::decode_v2(j_val)?)),
+                        Some(CoreType::Instant) => {
+                            Ok(GremlinValue::Instant(Instant::decode_v2(j_val)?))
+                        }
                         #[cfg(feature = "extended")]
-                        "gx:LocalDate" => Ok(GremlinValue::LocalDate(NaiveDate::decode_v2(j_val)?)),
+                        Some(CoreType::LocalDate) => {
+                            Ok(GremlinValue::LocalDate(NaiveDate::decode_v2(j_val)?))
+                        }
                         #[cfg(feature = "extended")]
-                        "gx:LocalDateTime" => Ok(GremlinValue::LocalDateTime(
+                        Some(CoreType::LocalDateTime) => Ok(GremlinValue::LocalDateTime(
                             NaiveDateTime::decode_v2(j_val)?,
                         )),
                         #[cfg(feature = "extended")]
-                        "gx:LocalTime" => Ok(GremlinValue::LocalTime(NaiveTime::decode_v2(j_val)?)),
+                        Some(CoreType::LocalTime) => {
+                            Ok(GremlinValue::LocalTime(NaiveTime::decode_v2(j_val)?))
+                        }
                         #[cfg(feature = "extended")]
-                        "gx:MonthDay" => Ok(GremlinValue::MonthDay(MonthDay::decode_v2(j_val)?)),
+                        Some(CoreType::MonthDay) => {
+                            Ok(GremlinValue::MonthDay(MonthDay::decode_v2(j_val)?))
+                        }
                         #[cfg(feature = "extended")]
-                        "gx:OffsetDateTime" => {
+                        Some(CoreType::OffsetDateTime) => {
                             Ok(GremlinValue::OffsetDateTime(DateTime::decode_v2(j_val)?))
                         }
                         #[cfg(feature = "extended")]
-                        "gx:OffsetTime" => {
+                        Some(CoreType::OffsetTime) => {
                             Ok(GremlinValue::OffsetTime(OffsetTime::decode_v2(j_val)?))
                         }
                         #[cfg(feature = "extended")]
-                        "gx:Period" => Ok(GremlinValue::Period(Period::decode_v2(j_val)?)),
+                        Some(CoreType::Period) => {
+                            Ok(GremlinValue::Period(Period::decode_v2(j_val)?))
+                        }
                         #[cfg(feature = "extended")]
-                        "gx:Year" => Ok(GremlinValue::Year(Year::decode_v2(j_val)?)),
+                        Some(CoreType::Year) => Ok(GremlinValue::Year(Year::decode_v2(j_val)?)),
                         #[cfg(feature = "extended")]
-                        "gx:YearMonth" => Ok(GremlinValue::YearMonth(YearMonth::decode_v2(j_val)?)),
+                        Some(CoreType::YearMonth) => {
+                            Ok(GremlinValue::YearMonth(YearMonth::decode_v2(j_val)?))
+                        }
                         #[cfg(feature = "extended")]
-                        "gx:ZonedDateTime" => Ok(GremlinValue::ZonedDateTime(
+                        Some(CoreType::ZonedDateTime) => Ok(GremlinValue::ZonedDateTime(
                             ZonedDateTime::decode_v2(j_val)?,
                         )),
                         #[cfg(feature = "extended")]
-                        "gx:ZoneOffset" => {
+                        Some(CoreType::ZoneOffset) => {
                             Ok(GremlinValue::ZoneOffset(FixedOffset::decode_v2(j_val)?))
                         }
-                        rest => Err(GraphSonError::WrongTypeIdentifier {
-                            expected: "a GremlinValue identifier".to_string(),
-                            found: rest.to_string(),
+                        _ => Err(GraphSonError::WrongTypeIdentifier {
+                            expected: UNKNOWN_TYPE.to_string(),
+                            found: type_identifier.to_string(),
                         }),
                     }
                 } else {
@@ -590,6 +909,23 @@ macro_rules! val_by_key_v3_new {
     };
 }
 
+/// Reads the epoch millis of a g:Date or g:Timestamp, which some producers write as ISO-8601 string
+fn date_millis(o: &serde_json::Map<String, serde_json::Value>) -> Result<i64, GraphSonError> {
+    let value = o
+        .get("@value")
+        .ok_or_else(|| GraphSonError::KeyNotFound("@value".to_string()))?;
+    if let Some(millis) = value.as_i64() {
+        return Ok(millis);
+    }
+    #[cfg(feature = "extended")]
+    if let Some(iso) = value.as_str() {
+        return chrono::DateTime::parse_from_rfc3339(iso)
+            .map(|date_time| date_time.timestamp_millis())
+            .map_err(|err| GraphSonError::Parse(format!("{iso} is not an ISO-8601 date: {err}")));
+    }
+    Err(GraphSonError::WrongJsonType("i64".to_string()))
+}
+
 pub(crate) fn get_val_by_key_v3<T: DecodeGraphSON>(
     jval: &serde_json::Value,
     key: &str,
@@ -623,20 +959,27 @@ pub(crate) fn get_val_by_key_v1<T: DecodeGraphSON>(
     T::decode_v1(val)
 }
 
+/// The `@type` identifier of a type, only called with types that are written with one.
+pub(crate) fn type_identifier(core_type: CoreType) -> &'static str {
+    graphson_identifier(core_type)
+        .unwrap_or_else(|| unreachable!("{core_type:?} is written without a GraphSON identifier"))
+}
+
 pub(crate) fn validate_type_entry(
     map: &serde_json::Map<String, serde_json::Value>,
-    type_value: &str,
+    core_type: CoreType,
 ) -> bool {
     map.get("@type")
         .and_then(|val| val.as_str())
-        .filter(|s| s.eq(&type_value))
+        .filter(|s| *s == type_identifier(core_type))
         .is_some()
 }
 
-pub(crate) fn validate_type<'a>(
-    jval: &'a serde_json::Value,
-    identifier: &str,
-) -> Result<&'a serde_json::Value, GraphSonError> {
+pub(crate) fn validate_type(
+    jval: &serde_json::Value,
+    core_type: CoreType,
+) -> Result<&serde_json::Value, GraphSonError> {
+    let identifier = type_identifier(core_type);
     let a = jval
         .get("@type")
         .ok_or_else(|| GraphSonError::KeyNotFound("@type".to_string()))?
@@ -652,3 +995,153 @@ pub(crate) fn validate_type<'a>(
     jval.get("@value")
         .ok_or_else(|| GraphSonError::KeyNotFound("@value".to_string()))
 }
+
+#[test]
+fn empty_object_decode_v2() {
+    let jval = serde_json::json!({});
+    let res = GremlinValue::decode_v2(&jval).unwrap();
+    assert_eq!(res, GremlinValue::Map(HashMap::new()))
+}
+
+#[test]
+fn typed_object_without_value_decode_v2() {
+    let jval = serde_json::json!({"@type": "g:Int32"});
+    let res = GremlinValue::decode_v2(&jval);
+    assert!(matches!(res, Err(GraphSonError::KeyNotFound(key)) if key == "@value"))
+}
+
+#[test]
+fn list_with_null_encode_v3() {
+    let list = GremlinValue::List(vec![
+        1.into(),
+        GremlinValue::UnspecifiedNullObject,
+        GremlinValue::List(vec![GremlinValue::UnspecifiedNullObject]),
+    ]);
+
+    assert_eq!(
+        json!({"@type": "g:List", "@value": [
+            {"@type": "g:Int32", "@value": 1},
+            null,
+            {"@type": "g:List", "@value": [null]}
+        ]}),
+        list.encode_v3()
+    );
+
+    let placeholder = json!({"@type": "g:Null", "@value": null});
+    let config = EncodeConfig::new().null_placeholder(placeholder.clone());
+    assert_eq!(
+        json!({"@type": "g:List", "@value": [
+            {"@type": "g:Int32", "@value": 1},
+            placeholder,
+            {"@type": "g:List", "@value": [placeholder]}
+        ]}),
+        encode_v3_with_config(&list, &config)
+    );
+}
+
+#[test]
+fn integral_double_encode_v3() {
+    let list = GremlinValue::List(vec![100.0_f64.into(), 0.5_f64.into(), 100.0_f32.into()]);
+
+    let with_suffix = encode_v3_with_config(&list, &EncodeConfig::new());
+    assert_eq!(list.encode_v3(), with_suffix);
+    assert_eq!(
+        r#"{"@type":"g:List","@value":[{"@type":"g:Double","@value":100.0},{"@type":"g:Double","@value":0.5},{"@type":"g:Float","@value":100.0}]}"#,
+        with_suffix.to_string()
+    );
+
+    let config = EncodeConfig::new().integral_double_suffix(false);
+    let without_suffix = encode_v3_with_config(&list, &config);
+    assert_eq!(
+        r#"{"@type":"g:List","@value":[{"@type":"g:Double","@value":100},{"@type":"g:Double","@value":0.5},{"@type":"g:Float","@value":100.0}]}"#,
+        without_suffix.to_string()
+    );
+    assert_eq!(list, GremlinValue::decode_v3(&without_suffix).unwrap());
+    assert_eq!(
+        r#"{"@type":"g:Double","@value":100}"#,
+        encode_v2_with_config(&GremlinValue::Double(100.0), &config).to_string()
+    );
+    assert_eq!(
+        r#"{"@type":"g:Double","@value":100.0}"#,
+        GremlinValue::Double(100.0).encode_v3().to_string()
+    );
+}
+
+#[test]
+fn date_decode_v3() {
+    let millis = json!({"@type" : "g:Date", "@value" : 1_481_750_076_295_i64});
+    let expected = GremlinValue::Date(1_481_750_076_295);
+    assert_eq!(expected, GremlinValue::decode_v3(&millis).unwrap());
+
+    #[cfg(feature = "extended")]
+    {
+        let iso = json!({"@type" : "g:Date", "@value" : "2016-12-14T21:14:36.295Z"});
+        assert_eq!(expected, GremlinValue::decode_v3(&iso).unwrap());
+        assert_eq!(expected, GremlinValue::decode_v2(&iso).unwrap());
+
+        let iso = json!({"@type" : "g:Timestamp", "@value" : "2016-12-14T21:14:36.295+00:00"});
+        assert_eq!(
+            GremlinValue::Timestamp(1_481_750_076_295),
+            GremlinValue::decode_v3(&iso).unwrap()
+        );
+    }
+}
+
+#[test]
+fn unknown_type_decode_v3_lenient() {
+    let jval = json!({
+        "@type" : "g:List",
+        "@value" : [
+            {"@type" : "g:Int32", "@value" : 1},
+            {"@type" : "g:Future", "@value" : {"state" : "pending", "id" : 7}}
+        ]
+    });
+    assert!(GremlinValue::decode_v3(&jval).is_err());
+
+    let expected = GremlinValue::List(vec![
+        GremlinValue::Int(1),
+        GremlinValue::Map(HashMap::from([
+            ("@type".into(), "g:Future".into()),
+            (
+                "@value".into(),
+                GremlinValue::Map(HashMap::from([
+                    ("state".into(), "pending".into()),
+                    ("id".into(), GremlinValue::Int(7)),
+                ])),
+            ),
+        ])),
+    ]);
+    assert_eq!(expected, decode_v3_lenient(&jval).unwrap());
+
+    let wrong_json_type = json!({"@type" : "g:Int32", "@value" : []});
+    assert!(decode_v3_lenient(&wrong_json_type).is_err());
+}
+
+#[test]
+fn set_bulk_set_strategy_decode_v3() {
+    let values = [
+        GremlinValue::Set(Set::new(vec![1_i32.into(), "a".into()])),
+        GremlinValue::BulkSet(BulkSet::new(vec![(1_i64.into(), 3)])),
+        GremlinValue::TraversalStrategy(TraversalStrategy::partition(
+            "_partition",
+            Some("a"),
+            vec!["a"],
+            false,
+        )),
+        GremlinValue::Short(7),
+    ];
+    for value in values {
+        let j_val = value.encode_v3();
+        assert_eq!(
+            Some(graphson_identifier(value.core_type()).unwrap()),
+            j_val["@type"].as_str()
+        );
+        assert_eq!(value, GremlinValue::decode_v3(&j_val).unwrap());
+    }
+
+    let strategy = GremlinValue::TraversalStrategy(TraversalStrategy::read_only());
+    assert_eq!(
+        strategy,
+        GremlinValue::decode_v2(&strategy.encode_v2()).unwrap()
+    );
+}
@@ -1,11 +1,16 @@
 use std::str::FromStr;
+use std::sync::Arc;
 
 use serde_json::json;
 use uuid::Uuid;
 
 use crate::error::GraphSonError;
+use crate::specs::CoreType;
 
-use super::{validate_type, DecodeGraphSON, EncodeGraphSON};
+use super::{
+    encode_double, type_identifier, validate_type, validate_type_entry, DecodeGraphSON,
+    EncodeGraphSON,
+};
 
 #[cfg(feature = "graph_son")]
 impl EncodeGraphSON for String {
@@ -48,6 +53,48 @@ impl DecodeGraphSON for String {
     }
 }
 
+#[cfg(feature = "graph_son")]
+impl EncodeGraphSON for Arc<str> {
+    fn encode_v3(&self) -> serde_json::Value {
+        json!(self.as_ref())
+    }
+
+    fn encode_v2(&self) -> serde_json::Value {
+        self.encode_v3()
+    }
+
+    fn encode_v1(&self) -> serde_json::Value {
+        self.encode_v3()
+    }
+}
+
+#[cfg(feature = "graph_son")]
+impl DecodeGraphSON for Arc<str> {
+    fn decode_v3(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
+    where
+        Self: std::marker::Sized,
+    {
+        j_val
+            .as_str()
+            .ok_or_else(|| GraphSonError::WrongJsonType("str".to_string()))
+            .map(Into::into)
+    }
+
+    fn decode_v2(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
+    where
+        Self: std::marker::Sized,
+    {
+        Self::decode_v3(j_val)
+    }
+
+    fn decode_v1(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
+    where
+        Self: std::marker::Sized,
+    {
+        Self::decode_v3(j_val)
+    }
+}
+
 #[cfg(feature = "graph_son")]
 impl EncodeGraphSON for &str {
     fn encode_v3(&self) -> serde_json::Value {
@@ -69,11 +116,8 @@ impl DecodeGraphSON for u8 {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "gx:Byte")?;
-        value_object
-            .as_u64()
-            .ok_or_else(|| GraphSonError::WrongJsonType("u64".to_string()))
-            .map(|val| val as u8)
+        let value_object = validate_type(j_val, CoreType::Byte)?;
+        narrow_integer_value(value_object)
     }
 
     fn decode_v2(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
@@ -87,10 +131,7 @@ impl DecodeGraphSON for u8 {
     where
         Self: std::marker::Sized,
     {
-        j_val
-            .as_u64()
-            .ok_or_else(|| GraphSonError::WrongJsonType("u64".to_string()))
-            .map(|val| val as u8)
+        narrow_integer_value(j_val)
     }
 }
 
@@ -100,11 +141,8 @@ impl DecodeGraphSON for i16 {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "gx:Short")?;
-        value_object
-            .as_i64()
-            .ok_or_else(|| GraphSonError::WrongJsonType("i64".to_string()))
-            .map(|val| val as i16)
+        let value_object = validate_type(j_val, CoreType::Short)?;
+        narrow_integer_value(value_object)
     }
 
     fn decode_v2(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
@@ -118,10 +156,7 @@ impl DecodeGraphSON for i16 {
     where
         Self: std::marker::Sized,
     {
-        j_val
-            .as_i64()
-            .ok_or_else(|| GraphSonError::WrongJsonType("i64".to_string()))
-            .map(|val| val as i16)
+        narrow_integer_value(j_val)
     }
 }
 
@@ -131,11 +166,8 @@ impl DecodeGraphSON for i32 {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:Int32")?;
-        value_object
-            .as_i64()
-            .ok_or_else(|| GraphSonError::WrongJsonType("i64".to_string()))
-            .map(|val| val as i32)
+        let value_object = validate_type(j_val, CoreType::Int32)?;
+        narrow_integer_value(value_object)
     }
 
     fn decode_v2(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
@@ -149,10 +181,7 @@ impl DecodeGraphSON for i32 {
     where
         Self: std::marker::Sized,
     {
-        j_val
-            .as_i64()
-            .ok_or_else(|| GraphSonError::WrongJsonType("i64".to_string()))
-            .map(|t| t as i32)
+        narrow_integer_value(j_val)
     }
 }
 
@@ -162,10 +191,8 @@ impl DecodeGraphSON for i64 {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:Int64")?;
-        value_object
-            .as_i64()
-            .ok_or_else(|| GraphSonError::WrongJsonType("i64".to_string()))
+        let value_object = validate_type(j_val, CoreType::Long)?;
+        integer_value(value_object)
     }
 
     fn decode_v2(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
@@ -179,19 +206,41 @@ impl DecodeGraphSON for i64 {
     where
         Self: std::marker::Sized,
     {
-        j_val
+        integer_value(j_val)
+    }
+}
+
+/// Reads an integer `@value`, which is either a JSON number or a JSON string
+/// as emitted for values beyond the safe integer range of JavaScript.
+fn integer_value(j_val: &serde_json::Value) -> Result<i64, GraphSonError> {
+    match j_val {
+        serde_json::Value::String(s) => s
+            .parse()
+            .map_err(|_| GraphSonError::Parse(format!("integer from: `{s}`"))),
+        _ => j_val
             .as_i64()
-            .ok_or_else(|| GraphSonError::WrongJsonType("i64".to_string()))
+            .ok_or_else(|| GraphSonError::WrongJsonType("i64".to_string())),
     }
 }
 
+#[cfg(feature = "graph_son")]
+fn narrow_integer_value<T: TryFrom<i64>>(j_val: &serde_json::Value) -> Result<T, GraphSonError> {
+    let val = integer_value(j_val)?;
+    T::try_from(val).map_err(|_| {
+        GraphSonError::Parse(format!(
+            "integer `{val}` out of range of {}",
+            std::any::type_name::<T>()
+        ))
+    })
+}
+
 #[cfg(feature = "graph_son")]
 impl DecodeGraphSON for f32 {
     fn decode_v3(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:Float")?;
+        let value_object = validate_type(j_val, CoreType::Float)?;
 
         if let Some(res) = value_object.as_f64().map(|f| f as f32) {
             return Ok(res);
@@ -232,7 +281,7 @@ impl DecodeGraphSON for f64 {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:Double")?;
+        let value_object = validate_type(j_val, CoreType::Double)?;
 
         if let Some(res) = value_object.as_f64() {
             return Ok(res);
@@ -270,7 +319,7 @@ impl DecodeGraphSON for f64 {
 impl EncodeGraphSON for Uuid {
     fn encode_v3(&self) -> serde_json::Value {
         json!({
-          "@type" : "g:UUID",
+          "@type" : type_identifier(CoreType::Uuid),
           "@value" : self.to_string()
         })
     }
@@ -290,7 +339,7 @@ impl DecodeGraphSON for Uuid {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:UUID")?;
+        let value_object = validate_type(j_val, CoreType::Uuid)?;
         let s = value_object
             .as_str()
             .ok_or_else(|| GraphSonError::WrongJsonType("str".to_string()))?;
@@ -414,21 +463,21 @@ impl<T: DecodeGraphSON> DecodeGraphSON for Option<T> {
 }
 
 macro_rules! graphson_impl {
-    ($(($t:ty,$type_sig:literal)),*$(,)?) => {
+    ($(($t:ty, $core_type:ident)),*$(,)?) => {
         $(
         #[cfg(feature = "graph_son")]
         impl EncodeGraphSON for $t {
 
             fn encode_v3(&self) -> serde_json::Value {
                 json!({
-                    "@type" : $type_sig,
+                    "@type" : type_identifier(CoreType::$core_type),
                     "@value" : self
                 })
             }
 
             fn encode_v2(&self) -> serde_json::Value {
                 json!({
-                    "@type" : $type_sig,
+                    "@type" : type_identifier(CoreType::$core_type),
                     "@value" : self
                 })
             }
@@ -442,14 +491,83 @@ macro_rules! graphson_impl {
 }
 
 graphson_impl!(
-    (u8, "gx:Byte"),
-    (i16, "gx:Int16"),
-    (i32, "g:Int32"),
-    (i64, "g:Int64"),
-    (f32, "g:Float"),
-    (f64, "g:Double"),
+    (u8, Byte),
+    (i16, Short),
+    (i32, Int32),
+    (i64, Long),
+    (f32, Float),
 );
 
+#[cfg(feature = "graph_son")]
+impl EncodeGraphSON for f64 {
+    fn encode_v3(&self) -> serde_json::Value {
+        json!({
+            "@type" : type_identifier(CoreType::Double),
+            "@value" : encode_double(*self)
+        })
+    }
+
+    fn encode_v2(&self) -> serde_json::Value {
+        self.encode_v3()
+    }
+
+    fn encode_v1(&self) -> serde_json::Value {
+        encode_double(*self)
+    }
+}
+
+impl DecodeGraphSON for char {
+    fn decode_v3(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
+    where
+        Self: std::marker::Sized,
+    {
+        j_val
+            .as_object()
+            .filter(|map| validate_type_entry(*map, CoreType::Char))
+            .and_then(|map| map.get("@value"))
+            .and_then(|value| value.as_str())
+            .and_then(|s| s.chars().next()) //FIXME more than 1 char is not evaluated
+            .ok_or_else(|| GraphSonError::WrongJsonType("str".to_string()))
+    }
+
+    fn decode_v2(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
+    where
+        Self: std::marker::Sized,
+    {
+        Self::decode_v3(j_val)
+    }
+
+    fn decode_v1(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
+    where
+        Self: std::marker::Sized,
+    {
+        j_val
+            .as_str()
+            .and_then(|s| s.chars().next())
+            .ok_or_else(|| GraphSonError::WrongJsonType("str".to_string()))
+    }
+}
+
+impl EncodeGraphSON for char {
+    fn encode_v3(&self) -> serde_json::Value {
+        json!({
+            "@type": type_identifier(CoreType::Char),
+            "@value": self
+        })
+    }
+
+    fn encode_v2(&self) -> serde_json::Value {
+        json!({
+            "@type": type_identifier(CoreType::Char),
+            "@value": self
+        })
+    }
+
+    fn encode_v1(&self) -> serde_json::Value {
+        todo!()
+    }
+}
+
 #[test]
 fn int32_decode_v3() {
     let obj = r#"{"@type" : "g:Int32","@value" : 100}"#;
@@ -488,3 +606,44 @@ fn uuid_encode_v3() {
     let expected = r#"{"@type":"g:UUID","@value":"41d2e28a-20a4-4ab0-b379-d810dede3786"}"#;
     assert_eq!(res, expected)
 }
+
+#[test]
+fn int64_string_decode_v3() {
+    let jval = json!({"@type":"g:Int64","@value":"9007199254740993"});
+    assert_eq!(i64::decode_v3(&jval).unwrap(), 9007199254740993);
+
+    let jval = json!({"@type":"g:Int32","@value":"123"});
+    assert_eq!(i32::decode_v3(&jval).unwrap(), 123);
+
+    let jval = json!({"@type":"g:Int64","@value":"12a"});
+    assert!(i64::decode_v3(&jval).is_err());
+}
+
+#[test]
+fn integer_out_of_range_decode_v3() {
+    let jval = json!({"@type":"gx:Byte","@value":-1});
+    assert!(matches!(u8::decode_v3(&jval), Err(GraphSonError::Parse(_))));
+
+    let jval = json!({"@type":"gx:Byte","@value":256});
+    assert!(matches!(u8::decode_v3(&jval), Err(GraphSonError::Parse(_))));
+
+    let jval = json!({"@type":"gx:Int16","@value":40000});
+    assert!(matches!(
+        i16::decode_v3(&jval),
+        Err(GraphSonError::Parse(_))
+    ));
+
+    let jval = json!({"@type":"g:Int32","@value":"2147483648"});
+    assert!(matches!(
+        i32::decode_v3(&jval),
+        Err(GraphSonError::Parse(_))
+    ));
+
+    assert!(matches!(
+        i32::decode_v1(&json!(2147483648_i64)),
+        Err(GraphSonError::Parse(_))
+    ));
+
+    let jval = json!({"@type":"gx:Byte","@value":255});
+    assert_eq!(u8::decode_v3(&jval).unwrap(), 255);
+}
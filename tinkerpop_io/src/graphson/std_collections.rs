@@ -3,13 +3,14 @@ use std::collections::HashMap;
 use serde_json::json;
 
 use crate::error::GraphSonError;
+use crate::specs::CoreType;
 
-use super::{validate_type, DecodeGraphSON, EncodeGraphSON};
+use super::{sorted_maps, type_identifier, validate_type, DecodeGraphSON, EncodeGraphSON};
 
 impl<T: EncodeGraphSON> EncodeGraphSON for Vec<T> {
     fn encode_v3(&self) -> serde_json::Value {
         json!({
-            "@type" : "g:List",
+            "@type" : type_identifier(CoreType::List),
             "@value" : self.iter().map(|t| t.encode_v3()).collect::<Vec<serde_json::Value>>(),
         })
     }
@@ -34,13 +35,14 @@ impl<T: DecodeGraphSON> DecodeGraphSON for Vec<T> {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:List")?;
+        let value_object = validate_type(j_val, CoreType::List)?;
 
         value_object
             .as_array()
             .ok_or_else(|| GraphSonError::WrongJsonType("array".to_string()))?
             .iter()
-            .map(|v| T::decode_v3(v))
+            .enumerate()
+            .map(|(index, v)| T::decode_v3(v).map_err(|err| element_error(index, err)))
             .collect::<Result<Vec<_>, _>>()
     }
 
@@ -52,7 +54,8 @@ impl<T: DecodeGraphSON> DecodeGraphSON for Vec<T> {
             .as_array()
             .ok_or_else(|| GraphSonError::WrongJsonType("array".to_string()))?
             .iter()
-            .map(|v| T::decode_v2(v))
+            .enumerate()
+            .map(|(index, v)| T::decode_v2(v).map_err(|err| element_error(index, err)))
             .collect::<Result<Vec<_>, _>>()
     }
 
@@ -64,15 +67,34 @@ impl<T: DecodeGraphSON> DecodeGraphSON for Vec<T> {
             .as_array()
             .ok_or_else(|| GraphSonError::WrongJsonType("array".to_string()))?
             .iter()
-            .map(|v| T::decode_v1(v))
+            .enumerate()
+            .map(|(index, v)| T::decode_v1(v).map_err(|err| element_error(index, err)))
             .collect::<Result<Vec<_>, _>>()
     }
 }
 
+/// Returns the flattened key/value array of a `g:Map` `@value`, which must hold pairs.
+fn map_entries(value_object: &serde_json::Value) -> Result<&Vec<serde_json::Value>, GraphSonError> {
+    let k_v_pairs = value_object
+        .as_array()
+        .ok_or_else(|| GraphSonError::WrongJsonType("array".to_string()))?;
+    if k_v_pairs.len() % 2 != 0 {
+        return Err(GraphSonError::Parse("odd-length g:Map".to_string()));
+    }
+    Ok(k_v_pairs)
+}
+
+fn element_error(index: usize, err: GraphSonError) -> GraphSonError {
+    GraphSonError::FieldError {
+        context: format!("element at index {index}"),
+        source: Box::new(err),
+    }
+}
+
 impl<T: EncodeGraphSON> EncodeGraphSON for &[T] {
     fn encode_v3(&self) -> serde_json::Value {
         json!({
-            "@type" : "g:List",
+            "@type" : type_identifier(CoreType::List),
             "@value" : self.iter().map(|t| t.encode_v3()).collect::<Vec<serde_json::Value>>(),
         })
     }
@@ -95,7 +117,7 @@ impl<T: EncodeGraphSON> EncodeGraphSON for &[T] {
 impl<T: EncodeGraphSON, const N: usize> EncodeGraphSON for [T; N] {
     fn encode_v3(&self) -> serde_json::Value {
         json!({
-            "@type" : "g:List",
+            "@type" : type_identifier(CoreType::List),
             "@value" : self.iter().map(|t| t.encode_v3()).collect::<Vec<serde_json::Value>>(),
         })
     }
@@ -121,23 +143,44 @@ where
     V: EncodeGraphSON,
 {
     fn encode_v3(&self) -> serde_json::Value {
+        let mut entries: Vec<_> = self.iter().map(|(k, v)| (k.encode_v3(), v)).collect();
+        if sorted_maps() {
+            entries.sort_by_cached_key(|(k, _)| k.to_string());
+        }
         let mut vec = Vec::with_capacity(self.len() * 2);
-        for (k, v) in self {
-            vec.push(k.encode_v3());
+        for (k, v) in entries {
+            vec.push(k);
             vec.push(v.encode_v3());
         }
         json!({
-            "@type" : "g:Map",
+            "@type" : type_identifier(CoreType::Map),
             "@value" : vec
         })
     }
 
+    /// Encodes the map as a plain JSON object if all keys encode to JSON strings, otherwise
+    /// falls back to the flattened `g:Map` list representation of GraphSON v3.
     fn encode_v2(&self) -> serde_json::Value {
-        let mut map = HashMap::with_capacity(self.len());
-        for (k, v) in self {
-            map.insert(k.to_string(), v.encode_v2());
+        let entries: Vec<_> = self.iter().map(|(k, v)| (k.encode_v2(), v)).collect();
+        if entries.iter().all(|(k, _)| k.is_string()) {
+            let mut map = serde_json::Map::with_capacity(entries.len());
+            for (k, v) in entries {
+                if let serde_json::Value::String(k) = k {
+                    map.insert(k, v.encode_v2());
+                }
+            }
+            return serde_json::Value::Object(map);
         }
-        json!(map)
+
+        let mut vec = Vec::with_capacity(entries.len() * 2);
+        for (k, v) in entries {
+            vec.push(k);
+            vec.push(v.encode_v2());
+        }
+        json!({
+            "@type" : type_identifier(CoreType::Map),
+            "@value" : vec
+        })
     }
 
     fn encode_v1(&self) -> serde_json::Value {
@@ -158,18 +201,11 @@ where
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:Map")?;
+        let value_object = validate_type(j_val, CoreType::Map)?;
 
-        let mut map_len = 0;
-        let k_v_pairs = value_object
-            .as_array()
-            .map(|array| {
-                map_len = array.len() / 2;
-                array
-            })
-            .ok_or_else(|| GraphSonError::WrongJsonType("array".to_string()))?;
+        let k_v_pairs = map_entries(value_object)?;
 
-        let mut map = HashMap::with_capacity(map_len);
+        let mut map = HashMap::with_capacity(k_v_pairs.len() / 2);
         for chunk in k_v_pairs.chunks_exact(2) {
             let key = K::decode_v3(&chunk[0])?;
             let val = V::decode_v3(&chunk[1])?;
@@ -183,6 +219,18 @@ where
     where
         Self: std::marker::Sized,
     {
+        if j_val.get("@type").and_then(|t| t.as_str()) == Some(type_identifier(CoreType::Map)) {
+            let k_v_pairs = map_entries(validate_type(j_val, CoreType::Map)?)?;
+
+            let mut map = HashMap::with_capacity(k_v_pairs.len() / 2);
+            for chunk in k_v_pairs.chunks_exact(2) {
+                let key = K::decode_v2(&chunk[0])?;
+                let val = V::decode_v2(&chunk[1])?;
+                map.insert(key, val);
+            }
+            return Ok(map);
+        }
+
         let mut map_len = 0;
         let iter = j_val
             .as_object()
@@ -444,6 +492,19 @@ fn map_empty_encode_graphson_v3() {
     assert_eq!(str, val);
 }
 
+#[test]
+fn map_odd_length_decode_v3() {
+    let jval = json!({
+        "@type" : "g:Map",
+        "@value" : ["a", {"@type" : "g:Int32", "@value" : 1}, "b"]
+    });
+
+    let err = HashMap::<String, i32>::decode_v3(&jval).unwrap_err();
+    assert!(matches!(err, GraphSonError::Parse(msg) if msg == "odd-length g:Map"));
+    assert!(HashMap::<String, i32>::decode_v2(&jval).is_err());
+    assert!(crate::GremlinValue::decode_v3(&jval).is_err());
+}
+
 #[test]
 fn map_decode_graphson_v3_error() {
     let str = r#"{
@@ -551,6 +612,24 @@ fn empty_map_encode_graphson_v2() {
     assert_eq!(str, val);
 }
 
+#[test]
+fn int_key_map_encode_graphson_v2() {
+    use crate::{structure::map::MapKeys, GremlinValue};
+
+    let map = HashMap::from([(MapKeys::Int(1), GremlinValue::from("marko"))]);
+    let expected = json!({
+        "@type" : "g:Map",
+        "@value" : [{"@type":"g:Int32","@value":1}, "marko"]
+    });
+
+    let val = map.encode_v2();
+    assert_eq!(val, expected);
+    assert_eq!(
+        HashMap::<MapKeys, GremlinValue>::decode_v2(&val).unwrap(),
+        map
+    );
+}
+
 #[test]
 fn map_decode_graphson_v1() {
     let str = r#"{"dur":1,"test":2}"#;
@@ -596,3 +675,122 @@ fn empty_map_encode_graphson_v1() {
 
     assert_eq!(str, val);
 }
+
+#[test]
+fn heterogeneous_list_decode_v3() {
+    use crate::structure::vertex::Vertex;
+    use crate::GremlinValue;
+
+    let s = r#"{
+        "@type" : "g:List",
+        "@value" : [
+            {"@type" : "g:Int32", "@value" : 1},
+            "marko",
+            {"@type" : "g:Vertex", "@value" : {"id" : {"@type" : "g:Int32", "@value" : 1}, "label" : "person"}}
+        ]
+    }"#;
+
+    let v: serde_json::Value = serde_json::from_str(s).unwrap();
+    let res = Vec::<GremlinValue>::decode_v3(&v).unwrap();
+    assert_eq!(
+        vec![
+            GremlinValue::Int(1),
+            GremlinValue::from("marko"),
+            GremlinValue::Vertex(Vertex::new(1, "person", None))
+        ],
+        res
+    );
+
+    let s = r#"{
+        "@type" : "g:List",
+        "@value" : [
+            {"@type" : "g:Int32", "@value" : 1},
+            {"@type" : "g:Int32", "@value" : "one"}
+        ]
+    }"#;
+
+    let v: serde_json::Value = serde_json::from_str(s).unwrap();
+    let err = Vec::<GremlinValue>::decode_v3(&v).unwrap_err();
+    assert!(err.to_string().contains("index 1"));
+}
+
+#[test]
+fn map_encode_v3_sorted() {
+    use crate::structure::map::MapKeys;
+    use crate::GremlinValue;
+
+    let keys = ["a", "b", "c", "d", "e", "f", "g", "h"];
+    let forward: HashMap<MapKeys, GremlinValue> = keys
+        .iter()
+        .map(|k| ((*k).into(), GremlinValue::from(*k)))
+        .collect();
+    let backward: HashMap<MapKeys, GremlinValue> = keys
+        .iter()
+        .rev()
+        .map(|k| ((*k).into(), GremlinValue::from(*k)))
+        .collect();
+
+    let forward = super::encode_v3_sorted(&GremlinValue::List(vec![GremlinValue::Map(forward)]));
+    let backward = super::encode_v3_sorted(&GremlinValue::List(vec![GremlinValue::Map(backward)]));
+    assert_eq!(forward.to_string(), backward.to_string());
+    assert_eq!(
+        json!(["a", "a", "b", "b"]),
+        json!(forward["@value"][0]["@value"].as_array().unwrap()[..4])
+    );
+}
+
+#[test]
+fn encode_v3_sorted_in_structures() {
+    use crate::structure::bulkset::BulkSet;
+    use crate::structure::map::MapKeys;
+    use crate::structure::path::Path;
+    use crate::structure::set::Set;
+    use crate::structure::traverser::Traverser;
+    use crate::structure::vertex::Vertex;
+    use crate::structure::vertex_property::VertexProperty;
+    use crate::GremlinValue;
+
+    let nested = |keys: &mut dyn Iterator<Item = &str>| {
+        let map: HashMap<MapKeys, GremlinValue> = keys.map(|k| (k.into(), k.into())).collect();
+        let map = GremlinValue::Map(map);
+        let vertex = Vertex::new(
+            1,
+            "person",
+            Some(vec![VertexProperty::new(
+                0_i64,
+                "name",
+                map.clone(),
+                None,
+                None,
+            )]),
+        );
+        GremlinValue::List(vec![
+            vertex.into(),
+            Traverser::new(map.clone()).into(),
+            GremlinValue::Path(Path {
+                labels: vec![Set::new(vec!["a".to_string()])],
+                objects: vec![map.clone()],
+            }),
+            GremlinValue::BulkSet(BulkSet::new(vec![(map, 2)])),
+        ])
+    };
+    let keys = ["a", "b", "c", "d", "e", "f", "g", "h"];
+
+    let forward = nested(&mut keys.iter().copied());
+    let backward = nested(&mut keys.iter().rev().copied());
+
+    let sorted = super::encode_v3_sorted(&forward);
+    assert_eq!(
+        sorted.to_string(),
+        super::encode_v3_sorted(&backward).to_string()
+    );
+    assert_eq!(
+        json!(["a", "a", "b", "b"]),
+        json!(
+            sorted["@value"][1]["@value"]["value"]["@value"]
+                .as_array()
+                .unwrap()[..4]
+        )
+    );
+    assert_eq!(forward, GremlinValue::decode_v3(&sorted).unwrap());
+}
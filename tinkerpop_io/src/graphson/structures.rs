@@ -1,12 +1,14 @@
 use std::collections::HashMap;
 use std::str::FromStr;
+use std::sync::Arc;
 
 use bigdecimal::BigDecimal;
 use num::BigInt;
 use uuid::Uuid;
 
 use crate::error::GraphSonError;
-use crate::graphson::{validate_type, DecodeGraphSON, EncodeGraphSON};
+use crate::graphson::{type_identifier, validate_type, DecodeGraphSON, EncodeGraphSON};
+use crate::specs::{core_type_from_graphson, CoreType};
 use crate::structure::bulkset::BulkSet;
 
 use crate::structure::bytebuffer::ByteBuffer;
@@ -20,7 +22,8 @@ use crate::structure::metrics::{Metrics, TraversalMetrics};
 use crate::structure::path::Path;
 use crate::structure::property::{self, EitherParent, Property};
 use crate::structure::set::Set;
-use crate::structure::traverser::Traverser;
+use crate::structure::traverser::{TraversalStrategy, Traverser};
+use crate::structure::tree::Tree;
 use crate::structure::vertex::Vertex;
 use crate::structure::vertex_property::VertexProperty;
 use crate::{Binding, GremlinValue};
@@ -66,17 +69,17 @@ impl DecodeGraphSON for ElementId {
         match j_val {
             serde_json::Value::String(s) => Ok(ElementId::String(s.clone())),
             serde_json::Value::Object(o) => {
-                match o
+                let identifier = o
                     .get("@type")
                     .and_then(|s| s.as_str())
-                    .ok_or_else(|| GraphSonError::KeyNotFound("@type".to_string()))?
-                {
-                    "g:Int32" => Ok(ElementId::Int(i32::decode_v3(j_val)?)),
-                    "g:Int64" => Ok(ElementId::Long(i64::decode_v3(j_val)?)),
-                    "g:UUID" => Ok(ElementId::Uuid(Uuid::decode_v3(j_val)?)),
-                    rest => Err(GraphSonError::WrongTypeIdentifier {
+                    .ok_or_else(|| GraphSonError::KeyNotFound("@type".to_string()))?;
+                match core_type_from_graphson(identifier) {
+                    Some(CoreType::Int32) => Ok(ElementId::Int(i32::decode_v3(j_val)?)),
+                    Some(CoreType::Long) => Ok(ElementId::Long(i64::decode_v3(j_val)?)),
+                    Some(CoreType::Uuid) => Ok(ElementId::Uuid(Uuid::decode_v3(j_val)?)),
+                    _ => Err(GraphSonError::WrongTypeIdentifier {
                         expected: String::from("ElementID expected String, int, long, uuid"),
-                        found: rest.to_string(),
+                        found: identifier.to_string(),
                     }),
                 }
             }
@@ -94,17 +97,17 @@ impl DecodeGraphSON for ElementId {
         match j_val {
             serde_json::Value::String(s) => Ok(ElementId::String(s.clone())),
             serde_json::Value::Object(o) => {
-                match o
+                let identifier = o
                     .get("@type")
                     .and_then(|s| s.as_str())
-                    .ok_or_else(|| GraphSonError::KeyNotFound("@type".to_string()))?
-                {
-                    "g:Int32" => Ok(ElementId::Int(i32::decode_v2(j_val)?)),
-                    "g:Int64" => Ok(ElementId::Long(i64::decode_v2(j_val)?)),
-                    "g:UUID" => Ok(ElementId::Uuid(Uuid::decode_v2(j_val)?)),
-                    rest => Err(GraphSonError::WrongTypeIdentifier {
+                    .ok_or_else(|| GraphSonError::KeyNotFound("@type".to_string()))?;
+                match core_type_from_graphson(identifier) {
+                    Some(CoreType::Int32) => Ok(ElementId::Int(i32::decode_v2(j_val)?)),
+                    Some(CoreType::Long) => Ok(ElementId::Long(i64::decode_v2(j_val)?)),
+                    Some(CoreType::Uuid) => Ok(ElementId::Uuid(Uuid::decode_v2(j_val)?)),
+                    _ => Err(GraphSonError::WrongTypeIdentifier {
                         expected: String::from("ElementID expected String, int, long, uuid"),
-                        found: rest.to_string(),
+                        found: identifier.to_string(),
                     }),
                 }
             }
@@ -115,20 +118,41 @@ impl DecodeGraphSON for ElementId {
         }
     }
 
-    fn decode_v1(_j_val: &serde_json::Value) -> Result<Self, GraphSonError>
+    /// GraphSON 1.0 ids are untyped, integral ids decode to `Int` if they fit into an `i32`,
+    /// otherwise to `Long`, and UUIDs decode to `String`.
+    fn decode_v1(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
     where
         Self: std::marker::Sized,
     {
-        todo!()
+        match j_val {
+            serde_json::Value::String(s) => Ok(ElementId::String(s.clone())),
+            serde_json::Value::Number(n) => n
+                .as_i64()
+                .map(|id| i32::try_from(id).map_or(ElementId::Long(id), ElementId::Int))
+                .ok_or_else(|| {
+                    GraphSonError::WrongJsonType(format!("ElementID expected integer, got: {n}"))
+                }),
+            rest => Err(GraphSonError::WrongJsonType(format!(
+                "ElementID expected String or Number, got: {}",
+                rest
+            ))),
+        }
+    }
+}
+
+/// Writes the digits as JSON number, or as JSON string if they can not be represented as one.
+fn number_or_string(digits: String) -> serde_json::Value {
+    match serde_json::Number::from_str(&digits) {
+        Ok(num) => serde_json::Value::Number(num),
+        Err(_) => serde_json::Value::String(digits),
     }
 }
 
 impl EncodeGraphSON for BigInt {
     fn encode_v3(&self) -> serde_json::Value {
-        let num =
-            serde_json::Value::Number(serde_json::Number::from_str(&self.to_string()).unwrap());
+        let num = number_or_string(self.to_string());
         json!({
-          "@type" : "gx:BigInteger",
+          "@type" : type_identifier(CoreType::BigInteger),
           "@value" : num
         })
     }
@@ -147,12 +171,14 @@ impl DecodeGraphSON for BigInt {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "gx:BigInteger")?;
-        match value_object {
-            serde_json::Value::Number(val) => BigInt::from_str(&val.to_string())
-                .map_err(|err| GraphSonError::Parse(format!("cannot parse BigInt: {err}"))),
-            _ => Err(GraphSonError::WrongJsonType("number".to_string())),
-        }
+        let value_object = validate_type(j_val, CoreType::BigInteger)?;
+        let s = match value_object {
+            serde_json::Value::Number(val) => val.to_string(),
+            serde_json::Value::String(s) => s.clone(),
+            _ => return Err(GraphSonError::WrongJsonType("number or str".to_string())),
+        };
+        BigInt::from_str(&s)
+            .map_err(|err| GraphSonError::Parse(format!("cannot parse BigInt: {err}")))
     }
 
     fn decode_v2(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
@@ -172,10 +198,9 @@ impl DecodeGraphSON for BigInt {
 
 impl EncodeGraphSON for BigDecimal {
     fn encode_v3(&self) -> serde_json::Value {
-        let num =
-            serde_json::Value::Number(serde_json::Number::from_str(&self.to_string()).unwrap());
+        let num = number_or_string(self.to_string());
         json!({
-          "@type" : "gx:BigDecimal",
+          "@type" : type_identifier(CoreType::BigDecimal),
           "@value" : num
         })
     }
@@ -194,12 +219,14 @@ impl DecodeGraphSON for BigDecimal {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "gx:BigDecimal")?;
-        match value_object {
-            serde_json::Value::Number(val) => BigDecimal::from_str(&val.to_string())
-                .map_err(|err| GraphSonError::Parse(format!("cannot parse BigDecimal: {err}"))),
-            _ => Err(GraphSonError::WrongJsonType("number".to_string())),
-        }
+        let value_object = validate_type(j_val, CoreType::BigDecimal)?;
+        let s = match value_object {
+            serde_json::Value::Number(val) => val.to_string(),
+            serde_json::Value::String(s) => s.clone(),
+            _ => return Err(GraphSonError::WrongJsonType("number or str".to_string())),
+        };
+        BigDecimal::from_str(&s)
+            .map_err(|err| GraphSonError::Parse(format!("cannot parse BigDecimal: {err}")))
     }
 
     fn decode_v2(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
@@ -220,7 +247,7 @@ impl DecodeGraphSON for BigDecimal {
 impl EncodeGraphSON for Binding {
     fn encode_v3(&self) -> serde_json::Value {
         json!({
-          "@type" : "g:Binding",
+          "@type" : type_identifier(CoreType::Binding),
           "@value" : {
             "key" : self.key,
             "value" : self.value.encode_v3()
@@ -230,7 +257,7 @@ impl EncodeGraphSON for Binding {
 
     fn encode_v2(&self) -> serde_json::Value {
         json!({
-          "@type" : "g:Binding",
+          "@type" : type_identifier(CoreType::Binding),
           "@value" : {
             "key" : self.key,
             "value" : self.value.encode_v2()
@@ -248,7 +275,7 @@ impl DecodeGraphSON for Binding {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:Binding")?;
+        let value_object = validate_type(j_val, CoreType::Binding)?;
 
         let key = get_val_by_key_v3(value_object, "key", "Binding")?;
         let value = get_val_by_key_v3(value_object, "value", "Binding")?;
@@ -284,7 +311,7 @@ impl EncodeGraphSON for BulkSet {
 
         json!(
             {
-                "@type" : "g:BulkSet",
+                "@type" : type_identifier(CoreType::BulkSet),
                 "@value" : j_vec
             }
         )
@@ -304,7 +331,7 @@ impl DecodeGraphSON for BulkSet {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:BulkSet")?
+        let value_object = validate_type(j_val, CoreType::BulkSet)?
             .as_array()
             .ok_or_else(|| GraphSonError::WrongJsonType("array".to_string()))?;
 
@@ -336,23 +363,105 @@ impl DecodeGraphSON for BulkSet {
     }
 }
 
-//TODO impl sources in encoding Bytecode
+impl EncodeGraphSON for Tree {
+    fn encode_v3(&self) -> serde_json::Value {
+        let branches = self
+            .0
+            .iter()
+            .map(|(key, tree)| json!({"key": key.encode_v3(), "value": tree.encode_v3()}))
+            .collect::<Vec<_>>();
+
+        json!({
+            "@type" : type_identifier(CoreType::Tree),
+            "@value" : branches
+        })
+    }
+
+    fn encode_v2(&self) -> serde_json::Value {
+        let branches = self
+            .0
+            .iter()
+            .map(|(key, tree)| json!({"key": key.encode_v2(), "value": tree.encode_v2()}))
+            .collect::<Vec<_>>();
+
+        json!({
+            "@type" : type_identifier(CoreType::Tree),
+            "@value" : branches
+        })
+    }
+
+    fn encode_v1(&self) -> serde_json::Value {
+        unimplemented!("not supported in GraphSON V1")
+    }
+}
+
+impl DecodeGraphSON for Tree {
+    fn decode_v3(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
+    where
+        Self: std::marker::Sized,
+    {
+        let value_object = validate_type(j_val, CoreType::Tree)?
+            .as_array()
+            .ok_or_else(|| GraphSonError::WrongJsonType("array".to_string()))?;
+
+        let mut branches = Vec::with_capacity(value_object.len());
+        for branch in value_object {
+            let key = get_val_by_key_v3::<GremlinValue>(branch, "key", "Tree")?;
+            let tree = get_val_by_key_v3::<Tree>(branch, "value", "Tree")?;
+            branches.push((key, tree));
+        }
+        Ok(Tree(branches))
+    }
+
+    fn decode_v2(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
+    where
+        Self: std::marker::Sized,
+    {
+        let value_object = validate_type(j_val, CoreType::Tree)?
+            .as_array()
+            .ok_or_else(|| GraphSonError::WrongJsonType("array".to_string()))?;
+
+        let mut branches = Vec::with_capacity(value_object.len());
+        for branch in value_object {
+            let key = get_val_by_key_v2::<GremlinValue>(branch, "key", "Tree")?;
+            let tree = get_val_by_key_v2::<Tree>(branch, "value", "Tree")?;
+            branches.push((key, tree));
+        }
+        Ok(Tree(branches))
+    }
+
+    fn decode_v1(_j_val: &serde_json::Value) -> Result<Self, GraphSonError>
+    where
+        Self: std::marker::Sized,
+    {
+        unimplemented!("not supported in GraphSON V1")
+    }
+}
+
 impl EncodeGraphSON for Bytecode {
     fn encode_v3(&self) -> serde_json::Value {
-        let v: Vec<Vec<serde_json::Value>> = self
+        let instructions = |name: &String, values: &Vec<GremlinValue>| {
+            let mut inner = vec![name.encode_v3()];
+            inner.extend(values.iter().map(EncodeGraphSON::encode_v3));
+            inner
+        };
+        let steps: Vec<Vec<serde_json::Value>> = self
             .steps
             .iter()
-            .map(|s| {
-                let mut inner = vec![s.name.encode_v3()];
-                inner.extend(s.values.iter().map(EncodeGraphSON::encode_v3));
-                inner
-            })
+            .map(|s| instructions(&s.name, &s.values))
             .collect();
+        let mut value = json!({ "step": steps });
+        if !self.sources.is_empty() {
+            let sources: Vec<Vec<serde_json::Value>> = self
+                .sources
+                .iter()
+                .map(|s| instructions(&s.name, &s.values))
+                .collect();
+            value["source"] = json!(sources);
+        }
         json!({
-          "@type" : "g:Bytecode",
-          "@value" : {
-            "step" : v
-          }
+          "@type" : type_identifier(CoreType::ByteCode),
+          "@value" : value
         })
     }
 
@@ -373,7 +482,7 @@ impl DecodeGraphSON for Bytecode {
         let mut steps = Vec::<Step>::new();
         let mut sources = Vec::<Source>::new();
 
-        let value_object = validate_type(j_val, "g:Bytecode")?;
+        let value_object = validate_type(j_val, CoreType::ByteCode)?;
 
         let steps_iter = value_object.get("step").and_then(|v| v.as_array());
 
@@ -432,20 +541,24 @@ impl DecodeGraphSON for Bytecode {
 
 impl EncodeGraphSON for Edge {
     fn encode_v3(&self) -> serde_json::Value {
-        let properties_map = self.properties.as_ref().map(|vec| {
-            vec.iter()
-                .map(|prop| (prop.key.clone(), prop.encode_v3()))
-                .collect::<Map<String, serde_json::Value>>()
-        });
+        let properties_map = self
+            .properties
+            .as_ref()
+            .filter(|vec| !vec.is_empty())
+            .map(|vec| {
+                vec.iter()
+                    .map(|prop| (prop.key.to_string(), prop.encode_v3()))
+                    .collect::<Map<String, serde_json::Value>>()
+            });
         // needs testing
 
         let mut json_value = json!({
-          "@type" : "g:Edge",
+          "@type" : type_identifier(CoreType::Edge),
           "@value" : {
             "id" : self.id.encode_v3(),
-            "label" : self.label,
-            "inVLabel" : self.in_v_label,
-            "outVLabel" : self.out_v_label,
+            "label" : &*self.label,
+            "inVLabel" : &*self.in_v_label,
+            "outVLabel" : &*self.out_v_label,
             "inV" : self.in_v_id.encode_v3(),
             "outV" : self.out_v_id.encode_v3(),
           }
@@ -460,19 +573,23 @@ impl EncodeGraphSON for Edge {
     }
 
     fn encode_v2(&self) -> serde_json::Value {
-        let properties_map = self.properties.as_ref().map(|vec| {
-            vec.iter()
-                .map(|prop| (prop.key.clone(), prop.value.encode_v2()))
-                .collect::<Map<String, serde_json::Value>>()
-        });
+        let properties_map = self
+            .properties
+            .as_ref()
+            .filter(|vec| !vec.is_empty())
+            .map(|vec| {
+                vec.iter()
+                    .map(|prop| (prop.key.to_string(), prop.value.encode_v2()))
+                    .collect::<Map<String, serde_json::Value>>()
+            });
 
         let mut json_value = json!({
-          "@type" : "g:Edge",
+          "@type" : type_identifier(CoreType::Edge),
           "@value" : {
             "id" : self.id.encode_v2(),
-            "label" : self.label,
-            "inVLabel" : self.in_v_label,
-            "outVLabel" : self.out_v_label,
+            "label" : &*self.label,
+            "inVLabel" : &*self.in_v_label,
+            "outVLabel" : &*self.out_v_label,
             "inV" : self.in_v_id.encode_v2(),
             "outV" : self.out_v_id.encode_v2(),
           }
@@ -487,7 +604,19 @@ impl EncodeGraphSON for Edge {
     }
 
     fn encode_v1(&self) -> serde_json::Value {
-        todo!()
+        let mut edge = json!({
+            "id" : self.id.encode_v1(),
+            "label" : &*self.label,
+            "type" : "edge",
+            "inVLabel" : &*self.in_v_label,
+            "outVLabel" : &*self.out_v_label,
+            "inV" : self.in_v_id.encode_v1(),
+            "outV" : self.out_v_id.encode_v1(),
+        });
+        if let Some(properties) = self.properties.as_deref().filter(|vec| !vec.is_empty()) {
+            edge["properties"] = properties_encode_v1(properties);
+        }
+        edge
     }
 }
 
@@ -496,7 +625,7 @@ impl DecodeGraphSON for Edge {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:Edge")?;
+        let value_object = validate_type(j_val, CoreType::Edge)?;
 
         let id = get_val_by_key_v3(value_object, "id", "Edge")?;
         let label = get_val_by_key_v3(value_object, "label", "Edge")?;
@@ -531,7 +660,7 @@ impl DecodeGraphSON for Edge {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:Edge")?;
+        let value_object = validate_type(j_val, CoreType::Edge)?;
 
         let id = get_val_by_key_v2(value_object, "id", "Edge")?;
         let label = get_val_by_key_v2(value_object, "label", "Edge")?;
@@ -565,18 +694,38 @@ impl DecodeGraphSON for Edge {
         })
     }
 
-    fn decode_v1(_j_val: &serde_json::Value) -> Result<Self, GraphSonError>
+    fn decode_v1(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
     where
         Self: std::marker::Sized,
     {
-        todo!()
+        let id = get_val_by_key_v1(j_val, "id", "Edge")?;
+        let label = get_val_by_key_v1(j_val, "label", "Edge")?;
+        let in_v_id = get_val_by_key_v1(j_val, "inV", "Edge")?;
+        let in_v_label = get_val_by_key_v1(j_val, "inVLabel", "Edge")?;
+        let out_v_id = get_val_by_key_v1(j_val, "outV", "Edge")?;
+        let out_v_label = get_val_by_key_v1(j_val, "outVLabel", "Edge")?;
+        let properties = j_val
+            .get("properties")
+            .map(properties_decode_v1)
+            .transpose()?;
+
+        Ok(Edge {
+            id,
+            label,
+            in_v_id,
+            in_v_label,
+            out_v_id,
+            out_v_label,
+            parent: None,
+            properties,
+        })
     }
 }
 
 impl EncodeGraphSON for ByteBuffer {
     fn encode_v3(&self) -> serde_json::Value {
         json!({
-          "@type" : "gx:ByteBuffer",
+          "@type" : type_identifier(CoreType::ByteBuffer),
           "@value" : self.0.iter().map(|byte| *byte as char).collect::<String>()
         })
     }
@@ -586,7 +735,7 @@ impl EncodeGraphSON for ByteBuffer {
     }
 
     fn encode_v1(&self) -> serde_json::Value {
-        todo!()
+        json!(self.0.iter().map(|byte| *byte as char).collect::<String>())
     }
 }
 
@@ -595,7 +744,7 @@ impl DecodeGraphSON for ByteBuffer {
     where
         Self: std::marker::Sized,
     {
-        let inner = validate_type(j_val, "gx:ByteBuffer")?
+        let inner = validate_type(j_val, CoreType::ByteBuffer)?
             .as_str()
             .map(|s| s.chars().map(|c| c as u8).collect::<Vec<u8>>())
             .ok_or_else(|| GraphSonError::WrongJsonType("str".to_string()))?;
@@ -622,11 +771,11 @@ impl EncodeGraphSON for GraphEdge {
         let properties_map = self
             .properties
             .iter()
-            .map(|prop| (prop.key.clone(), prop.encode_v3()))
+            .map(|prop| (prop.key.to_string(), prop.encode_v3()))
             .collect::<Map<String, serde_json::Value>>();
 
         let mut json_value = json!({
-          "@type" : "g:Edge",
+          "@type" : type_identifier(CoreType::Edge),
           "@value" : {
             "id" : self.id.encode_v3(),
             "label" : self.label.encode_v3(),
@@ -649,11 +798,11 @@ impl EncodeGraphSON for GraphEdge {
         let properties_map = self
             .properties
             .iter()
-            .map(|prop| (prop.key.clone(), prop.encode_v2()))
+            .map(|prop| (prop.key.to_string(), prop.encode_v2()))
             .collect::<Map<String, serde_json::Value>>();
 
         let mut json_value = json!({
-          "@type" : "g:Edge",
+          "@type" : type_identifier(CoreType::Edge),
           "@value" : {
             "id" : self.id.encode_v2(),
             "label" : self.label.encode_v2(),
@@ -703,7 +852,7 @@ impl DecodeGraphSON for GraphEdge {
 impl EncodeGraphSON for Graph {
     fn encode_v3(&self) -> serde_json::Value {
         json!({
-            "@type" : "tinker:graph",
+            "@type" : type_identifier(CoreType::Graph),
             "@value" : {
                 "vertices": self.vertices.iter().map(EncodeGraphSON::encode_v3).collect::<Vec<serde_json::Value>>(),
                 "edges": self.edges.iter().map(EncodeGraphSON::encode_v3).collect::<Vec<serde_json::Value>>()
@@ -713,7 +862,7 @@ impl EncodeGraphSON for Graph {
 
     fn encode_v2(&self) -> serde_json::Value {
         json!({
-            "@type" : "tinker:graph",
+            "@type" : type_identifier(CoreType::Graph),
             "@value" : {
                 "vertices": self.vertices.iter().map(EncodeGraphSON::encode_v2).collect::<Vec<serde_json::Value>>(),
                 "edges": self.edges.iter().map(EncodeGraphSON::encode_v2).collect::<Vec<serde_json::Value>>()
@@ -726,15 +875,34 @@ impl EncodeGraphSON for Graph {
     }
 }
 
+/// tinker:graph carries its vertices and edges as plain arrays rather than g:List
+fn graph_elements_v3<T: DecodeGraphSON>(
+    value_object: &serde_json::Value,
+    key: &str,
+) -> Result<Vec<T>, GraphSonError> {
+    value_object
+        .get(key)
+        .ok_or_else(|| {
+            GraphSonError::KeyNotFound(format!(
+                "{key} not found during graphson decode TinkerGraph"
+            ))
+        })?
+        .as_array()
+        .ok_or_else(|| GraphSonError::WrongJsonType("array".to_string()))?
+        .iter()
+        .map(T::decode_v3)
+        .collect()
+}
+
 impl DecodeGraphSON for Graph {
     fn decode_v3(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:TinkerGraph")?;
+        let value_object = validate_type(j_val, CoreType::Graph)?;
 
-        let vertices = get_val_by_key_v3(value_object, "vertices", "TinkerGraph")?;
-        let edges = get_val_by_key_v3(value_object, "edges", "TinkerGraph")?;
+        let vertices = graph_elements_v3(value_object, "vertices")?;
+        let edges = graph_elements_v3(value_object, "edges")?;
 
         Ok(Graph { vertices, edges })
     }
@@ -743,7 +911,7 @@ impl DecodeGraphSON for Graph {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:TinkerGraph")?;
+        let value_object = validate_type(j_val, CoreType::Graph)?;
 
         let vertices = get_val_by_key_v2(value_object, "vertices", "TinkerGraph")?;
         let edges = get_val_by_key_v2(value_object, "edges", "TinkerGraph")?;
@@ -762,7 +930,7 @@ impl DecodeGraphSON for Graph {
 impl EncodeGraphSON for Lambda {
     fn encode_v3(&self) -> serde_json::Value {
         json!({
-          "@type" : "g:Lambda",
+          "@type" : type_identifier(CoreType::Lambda),
           "@value" : {
             "script" : self.script,
             "language" : self.language,
@@ -785,7 +953,7 @@ impl DecodeGraphSON for Lambda {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:Lambda")?;
+        let value_object = validate_type(j_val, CoreType::Lambda)?;
 
         let script = get_val_by_key_v3(value_object, "script", "Lambda")?;
         let language = get_val_by_key_v3(value_object, "language", "Lambda")?;
@@ -821,7 +989,7 @@ impl DecodeGraphSON for Lambda {
 impl<T: EncodeGraphSON> EncodeGraphSON for Set<T> {
     fn encode_v3(&self) -> serde_json::Value {
         json!({
-          "@type" : "g:Set",
+          "@type" : type_identifier(CoreType::Set),
           "@value" : self.set().iter().map(|t| t.encode_v3()).collect::<Vec<serde_json::Value>>()
         })
     }
@@ -848,7 +1016,7 @@ impl<T: DecodeGraphSON> DecodeGraphSON for Set<T> {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:Set")?;
+        let value_object = validate_type(j_val, CoreType::Set)?;
 
         let result_vec = value_object
             .as_array()
@@ -864,14 +1032,20 @@ impl<T: DecodeGraphSON> DecodeGraphSON for Set<T> {
     where
         Self: std::marker::Sized,
     {
-        Ok(Set::new(Vec::<T>::decode_v2(j_val)?))
+        // depending on the server config a v2 set is either a bare array or wrapped in g:List
+        let array = if j_val.is_object() {
+            validate_type(j_val, CoreType::List)?
+        } else {
+            j_val
+        };
+        Ok(Set::new(Vec::<T>::decode_v2(array)?))
     }
 
-    fn decode_v1(_j_val: &serde_json::Value) -> Result<Self, GraphSonError>
+    fn decode_v1(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
     where
         Self: std::marker::Sized,
     {
-        todo!()
+        Ok(Set::new(Vec::<T>::decode_v1(j_val)?))
     }
 }
 
@@ -880,9 +1054,9 @@ impl EncodeGraphSON for Metrics {
         let dur = self.duration as f64 / 1000. / 1000.;
         if self.nested_metrics.is_empty() {
             json!({
-                "@type" : "g:Metrics",
+                "@type" : type_identifier(CoreType::Metrics),
                 "@value" : {
-                    "@type" : "g:Map",
+                    "@type" : type_identifier(CoreType::Map),
                     "@value" : [
                         "dur",dur.encode_v3(),
                         "counts",self.counts.encode_v3(),
@@ -894,9 +1068,9 @@ impl EncodeGraphSON for Metrics {
             })
         } else {
             json!({
-                "@type" : "g:Metrics",
+                "@type" : type_identifier(CoreType::Metrics),
                 "@value" : {
-                    "@type" : "g:Map",
+                    "@type" : type_identifier(CoreType::Map),
                     "@value" : [
                         "dur",dur.encode_v3(),
                         "counts",self.counts.encode_v3(),
@@ -914,7 +1088,7 @@ impl EncodeGraphSON for Metrics {
         let dur = self.duration as f64 / 1000. / 1000.;
         if self.nested_metrics.is_empty() {
             json!({
-                "@type" : "g:Metrics",
+                "@type" : type_identifier(CoreType::Metrics),
                 "@value" : {
 
                         "dur":dur.encode_v2(),
@@ -926,7 +1100,7 @@ impl EncodeGraphSON for Metrics {
             })
         } else {
             json!({
-                "@type" : "g:Metrics",
+                "@type" : type_identifier(CoreType::Metrics),
                 "@value" : {
                     "dur":dur.encode_v2(),
                     "counts":self.counts.encode_v2(),
@@ -949,7 +1123,7 @@ impl DecodeGraphSON for Metrics {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:Metrics")?;
+        let value_object = validate_type(j_val, CoreType::Metrics)?;
 
         let metrics = HashMap::<String, GremlinValue>::decode_v3(value_object)?;
 
@@ -1003,7 +1177,7 @@ impl DecodeGraphSON for Metrics {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:Metrics")?;
+        let value_object = validate_type(j_val, CoreType::Metrics)?;
 
         let metrics = HashMap::<String, GremlinValue>::decode_v2(value_object)?;
 
@@ -1064,7 +1238,7 @@ impl DecodeGraphSON for Metrics {
 impl EncodeGraphSON for TraversalMetrics {
     fn encode_v3(&self) -> serde_json::Value {
         json!({
-            "@type" : "g:TraversalMetrics",
+            "@type" : type_identifier(CoreType::TraversalMetrics),
             "@value" : [
                 "dur", self.duration.encode_v3(),
                 "metrics", self.metrics.encode_v3()
@@ -1074,7 +1248,7 @@ impl EncodeGraphSON for TraversalMetrics {
 
     fn encode_v2(&self) -> serde_json::Value {
         json!({
-            "@type" : "g:TraversalMetrics",
+            "@type" : type_identifier(CoreType::TraversalMetrics),
             "@value" : [
                 "dur", self.duration.encode_v2(),
                 "metrics", self.metrics.encode_v2()
@@ -1092,7 +1266,7 @@ impl DecodeGraphSON for TraversalMetrics {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:TraversalMetrics")?;
+        let value_object = validate_type(j_val, CoreType::TraversalMetrics)?;
 
         let metrics = HashMap::<String, GremlinValue>::decode_v3(value_object)?;
 
@@ -1112,7 +1286,7 @@ impl DecodeGraphSON for TraversalMetrics {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:Metrics")?;
+        let value_object = validate_type(j_val, CoreType::TraversalMetrics)?;
 
         let metrics = HashMap::<String, GremlinValue>::decode_v2(value_object)?;
 
@@ -1120,7 +1294,7 @@ impl DecodeGraphSON for TraversalMetrics {
             .get("dur")
             .and_then(|v| v.get_cloned::<f64>())
             .map(|dur| (dur * 1000. * 1000.) as i64)
-            .ok_or_else(|| GraphSonError::KeyNotFound("du".to_string()))?;
+            .ok_or_else(|| GraphSonError::KeyNotFound("dur".to_string()))?;
         let metrics = metrics
             .get("metrics")
             .and_then(|v| v.get_cloned::<Vec<Metrics>>())
@@ -1140,7 +1314,7 @@ impl EncodeGraphSON for Path {
     fn encode_v3(&self) -> serde_json::Value {
         json!(
             {
-                "@type" : "g:Path",
+                "@type" : type_identifier(CoreType::Path),
                 "@value" : {
                   "labels" : self.labels.encode_v3(),
                   "objects" : self.objects.encode_v3()
@@ -1151,7 +1325,7 @@ impl EncodeGraphSON for Path {
     fn encode_v2(&self) -> serde_json::Value {
         json!(
             {
-                "@type" : "g:Path",
+                "@type" : type_identifier(CoreType::Path),
                 "@value" : {
                   "labels" : self.labels.encode_v2(),
                   "objects" : self.objects.encode_v2()
@@ -1160,10 +1334,19 @@ impl EncodeGraphSON for Path {
         )
     }
 
+    /// GraphSON 1.0 is untyped, vertices and edges are written in their 1.0 shape tagged with
+    /// `"type"`, all other objects as plain JSON. Decoding is lossy for plain objects: numbers
+    /// come back as `Int`, `Long`, `BigInteger` or `Double` by range and all other scalars, like
+    /// dates or UUIDs, as the JSON type they were written as.
     fn encode_v1(&self) -> serde_json::Value {
+        let objects = self.objects.iter().map(|object| match object {
+            GremlinValue::Vertex(vertex) => vertex.encode_v1(),
+            GremlinValue::Edge(edge) => edge.encode_v1(),
+            object => object.to_plain_json(),
+        });
         json!({
             "labels": self.labels.encode_v1(),
-            "objects" : self.objects.encode_v1(),
+            "objects" : objects.collect::<Vec<_>>(),
         })
     }
 }
@@ -1173,7 +1356,7 @@ impl DecodeGraphSON for Path {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:Path")?;
+        let value_object = validate_type(j_val, CoreType::Path)?;
 
         let labels = get_val_by_key_v3(value_object, "labels", "Path")?;
         let objects = get_val_by_key_v3(value_object, "objects", "Path")?;
@@ -1184,7 +1367,7 @@ impl DecodeGraphSON for Path {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:Path")?;
+        let value_object = validate_type(j_val, CoreType::Path)?;
 
         let labels = get_val_by_key_v2(value_object, "labels", "Path")?;
         let objects = get_val_by_key_v2(value_object, "objects", "Path")?;
@@ -1192,73 +1375,172 @@ impl DecodeGraphSON for Path {
         Ok(Path { labels, objects })
     }
 
-    fn decode_v1(_j_val: &serde_json::Value) -> Result<Self, GraphSonError>
+    fn decode_v1(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
     where
         Self: std::marker::Sized,
     {
-        todo!()
+        let labels = get_val_by_key_v1(j_val, "labels", "Path")?;
+        let objects = j_val
+            .get("objects")
+            .ok_or_else(|| GraphSonError::KeyNotFound("objects".to_string()))?
+            .as_array()
+            .ok_or_else(|| GraphSonError::WrongJsonType("array".to_string()))?
+            .iter()
+            .map(|object| match object.get("type").and_then(|t| t.as_str()) {
+                Some("vertex") => Vertex::decode_v1(object).map(GremlinValue::Vertex),
+                Some("edge") => Edge::decode_v1(object).map(GremlinValue::Edge),
+                _ => Ok(GremlinValue::from_plain_json(object)),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Path { labels, objects })
     }
 }
 
+/// GraphSON 1.0 writes the properties of an element as object of key to untyped value.
+fn properties_encode_v1(properties: &[Property]) -> serde_json::Value {
+    serde_json::Value::Object(
+        properties
+            .iter()
+            .map(|property| (property.key.to_string(), property.value.to_plain_json()))
+            .collect(),
+    )
+}
+
+fn properties_decode_v1(j_val: &serde_json::Value) -> Result<Vec<Property>, GraphSonError> {
+    let map = j_val
+        .as_object()
+        .ok_or_else(|| GraphSonError::WrongJsonType("object".to_string()))?;
+    Ok(map
+        .iter()
+        .map(|(key, value)| Property {
+            key: key.as_str().into(),
+            value: Box::new(GremlinValue::from_plain_json(value)),
+            parent: EitherParent::None,
+        })
+        .collect())
+}
+
+/// Vertex properties nested in a GraphSON 1.0 vertex may omit their label, which is then taken
+/// from the key they are listed under.
+fn vertex_property_decode_v1(
+    j_val: &serde_json::Value,
+    label: Option<&String>,
+) -> Result<VertexProperty, GraphSonError> {
+    let id = get_val_by_key_v1(j_val, "id", "VertexProperty")?;
+    let label = match (j_val.get("label"), label) {
+        (Some(label), _) => Arc::<str>::decode_v1(label)?,
+        (None, Some(label)) => label.as_str().into(),
+        (None, None) => return Err(GraphSonError::KeyNotFound("label".to_string())),
+    };
+    let value = j_val
+        .get("value")
+        .ok_or_else(|| GraphSonError::KeyNotFound("value".to_string()))?;
+    let properties = j_val
+        .get("properties")
+        .map(properties_decode_v1)
+        .transpose()?;
+
+    Ok(VertexProperty {
+        id,
+        label,
+        value: Box::new(GremlinValue::from_plain_json(value)),
+        parent: None,
+        properties,
+    })
+}
+
 impl EncodeGraphSON for Vertex {
     fn encode_v3(&self) -> serde_json::Value {
-        if let Some(properties) = &self.properties {
+        if let Some(properties) = self.properties.as_ref().filter(|vec| !vec.is_empty()) {
             let mut map = HashMap::<String, Vec<serde_json::Value>>::new();
             for property in properties {
-                if map.contains_key(&property.label) {
-                    let v = map.get_mut(&property.label).unwrap();
+                if map.contains_key(&*property.label) {
+                    let v = map.get_mut(&*property.label).unwrap();
                     v.push(property.encode_v3());
                 } else {
-                    map.insert(property.label.clone(), vec![property.encode_v3()]);
+                    map.insert(property.label.to_string(), vec![property.encode_v3()]);
                 }
             }
             json!({
-                  "@type" : "g:Vertex",
+                  "@type" : type_identifier(CoreType::Vertex),
                   "@value" : {
                     "id" : self.id.encode_v3(),
-                    "label" : self.label,
+                    "label" : &*self.label,
                     "properties" : map
             }})
         } else {
             json!({
-                  "@type" : "g:Vertex",
+                  "@type" : type_identifier(CoreType::Vertex),
                   "@value" : {
                     "id" : self.id.encode_v3(),
-                    "label" : self.label,
+                    "label" : &*self.label,
             }})
         }
     }
 
     fn encode_v2(&self) -> serde_json::Value {
-        if let Some(properties) = &self.properties {
+        if let Some(properties) = self.properties.as_ref().filter(|vec| !vec.is_empty()) {
             let mut map = HashMap::<String, Vec<serde_json::Value>>::new();
             for property in properties {
-                if map.contains_key(&property.label) {
-                    let v = map.get_mut(&property.label).unwrap();
+                if map.contains_key(&*property.label) {
+                    let v = map.get_mut(&*property.label).unwrap();
                     v.push(property.encode_v2());
                 } else {
-                    map.insert(property.label.clone(), vec![property.encode_v2()]);
+                    map.insert(property.label.to_string(), vec![property.encode_v2()]);
                 }
             }
             json!({
-                  "@type" : "g:Vertex",
+                  "@type" : type_identifier(CoreType::Vertex),
                   "@value" : {
                     "id" : self.id.encode_v2(),
-                    "label" : self.label,
+                    "label" : &*self.label,
                     "properties" : map
             }})
         } else {
             json!({
-                  "@type" : "g:Vertex",
+                  "@type" : type_identifier(CoreType::Vertex),
                   "@value" : {
                     "id" : self.id.encode_v2(),
-                    "label" : self.label,
+                    "label" : &*self.label,
             }})
         }
     }
 
     fn encode_v1(&self) -> serde_json::Value {
-        todo!()
+        let mut vertex = json!({
+            "id" : self.id.encode_v1(),
+            "label" : &*self.label,
+            "type" : "vertex",
+        });
+        if let Some(vertex_properties) = self.properties.as_ref().filter(|vec| !vec.is_empty()) {
+            let mut properties = Map::new();
+            for property in vertex_properties {
+                if let serde_json::Value::Array(values) = properties
+                    .entry(property.label.to_string())
+                    .or_insert_with(|| json!([]))
+                {
+                    values.push(property.encode_v1());
+                }
+            }
+            vertex["properties"] = serde_json::Value::Object(properties);
+        }
+        vertex
+    }
+}
+
+/// Vertex properties are usually an object of label to array of VertexProperty,
+/// but some producers emit a flat array of VertexProperty instead
+fn vertex_property_values(properties: &serde_json::Value) -> Option<Vec<&serde_json::Value>> {
+    match properties {
+        serde_json::Value::Object(map) => Some(
+            map.values()
+                .flat_map(|val| val.as_array())
+                .flatten()
+                .collect(),
+        ),
+        serde_json::Value::Array(array) => Some(array.iter().collect()),
+        _ => None,
     }
 }
 
@@ -1267,18 +1549,17 @@ impl DecodeGraphSON for Vertex {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:Vertex")?;
+        let value_object = validate_type(j_val, CoreType::Vertex)?;
 
         let id = get_val_by_key_v3(value_object, "id", "Vertex")?;
         let label = get_val_by_key_v3(value_object, "label", "Vertex")?;
 
         let properties = value_object
             .get("properties")
-            .and_then(|obj| obj.as_object())
-            .map(|map| {
-                map.values()
-                    .flat_map(|val| val.as_array())
-                    .flatten()
+            .and_then(vertex_property_values)
+            .map(|values| {
+                values
+                    .into_iter()
                     .map(DecodeGraphSON::decode_v3)
                     .collect::<Result<Vec<_>, _>>()
             })
@@ -1294,18 +1575,17 @@ impl DecodeGraphSON for Vertex {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:Vertex")?;
+        let value_object = validate_type(j_val, CoreType::Vertex)?;
 
         let id = get_val_by_key_v2(value_object, "id", "Vertex")?;
         let label = get_val_by_key_v2(value_object, "label", "Vertex")?;
 
         let properties = value_object
             .get("properties")
-            .and_then(|obj| obj.as_object())
-            .map(|map| {
-                map.values()
-                    .flat_map(|val| val.as_array())
-                    .flatten()
+            .and_then(vertex_property_values)
+            .map(|values| {
+                values
+                    .into_iter()
                     .map(DecodeGraphSON::decode_v2)
                     .collect::<Result<Vec<_>, _>>()
             })
@@ -1318,28 +1598,52 @@ impl DecodeGraphSON for Vertex {
         })
     }
 
-    fn decode_v1(_j_val: &serde_json::Value) -> Result<Self, GraphSonError>
+    fn decode_v1(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
     where
         Self: std::marker::Sized,
     {
-        todo!()
+        let id = get_val_by_key_v1(j_val, "id", "Vertex")?;
+        let label = get_val_by_key_v1(j_val, "label", "Vertex")?;
+
+        let properties = match j_val.get("properties") {
+            Some(serde_json::Value::Object(map)) => {
+                let mut properties = Vec::with_capacity(map.len());
+                for (label, values) in map {
+                    let values = values
+                        .as_array()
+                        .ok_or_else(|| GraphSonError::WrongJsonType("array".to_string()))?;
+                    for value in values {
+                        properties.push(vertex_property_decode_v1(value, Some(label))?);
+                    }
+                }
+                Some(properties)
+            }
+            Some(_) => return Err(GraphSonError::WrongJsonType("object".to_string())),
+            None => None,
+        };
+
+        Ok(Vertex {
+            id,
+            label,
+            properties,
+        })
     }
 }
 
 impl EncodeGraphSON for VertexProperty {
     fn encode_v3(&self) -> serde_json::Value {
         let mut jval_map = Map::new();
-        if let Some(props) = &self.properties {
+        if let Some(props) = self.properties.as_ref().filter(|vec| !vec.is_empty()) {
             for property in props {
-                jval_map.insert(property.key.clone(), property.value.encode_v3());
+                jval_map.insert(property.key.to_string(), property.value.encode_v3());
             }
             json!(
                 {
-                    "@type" : "g:VertexProperty",
+                    "@type" : type_identifier(CoreType::VertexProperty),
                     "@value" : {
                       "id" : self.id.encode_v3(),
                       "value" : self.value.encode_v3(),
-                      "label" : self.label,
+                      "label" : &*self.label,
                       "properties" : jval_map
                     }
                 }
@@ -1347,11 +1651,11 @@ impl EncodeGraphSON for VertexProperty {
         } else {
             json!(
                 {
-                    "@type" : "g:VertexProperty",
+                    "@type" : type_identifier(CoreType::VertexProperty),
                     "@value" : {
                       "id" : self.id.encode_v3(),
                       "value" : self.value.encode_v3(),
-                      "label" : self.label
+                      "label" : &*self.label
                     }
                 }
             )
@@ -1360,17 +1664,17 @@ impl EncodeGraphSON for VertexProperty {
 
     fn encode_v2(&self) -> serde_json::Value {
         let mut jval_map = Map::new();
-        if let Some(props) = &self.properties {
+        if let Some(props) = self.properties.as_ref().filter(|vec| !vec.is_empty()) {
             for property in props {
-                jval_map.insert(property.key.clone(), property.value.encode_v2());
+                jval_map.insert(property.key.to_string(), property.value.encode_v2());
             }
             json!(
                 {
-                    "@type" : "g:VertexProperty",
+                    "@type" : type_identifier(CoreType::VertexProperty),
                     "@value" : {
                       "id" : self.id.encode_v2(),
                       "value" : self.value.encode_v2(),
-                      "label" : self.label,
+                      "label" : &*self.label,
                       "vertex" : self.parent.as_ref().map(|v| v.id.encode_v2()),
                       "properties" : jval_map
                     }
@@ -1379,12 +1683,12 @@ impl EncodeGraphSON for VertexProperty {
         } else {
             json!(
                 {
-                    "@type" : "g:VertexProperty",
+                    "@type" : type_identifier(CoreType::VertexProperty),
                     "@value" : {
                       "id" : self.id.encode_v2(),
                       "value" : self.value.encode_v2(),
                       "vertex" : self.parent.as_ref().map(|v| v.id.encode_v2()),
-                      "label" : self.label
+                      "label" : &*self.label
                     }
                 }
             )
@@ -1392,11 +1696,15 @@ impl EncodeGraphSON for VertexProperty {
     }
 
     fn encode_v1(&self) -> serde_json::Value {
-        json!({
+        let mut vertex_property = json!({
           "id" : self.id.encode_v1(),
-          "value" : self.value.encode_v1(),
-          "label" : self.label
-        })
+          "value" : self.value.to_plain_json(),
+          "label" : &*self.label
+        });
+        if let Some(properties) = self.properties.as_deref().filter(|vec| !vec.is_empty()) {
+            vertex_property["properties"] = properties_encode_v1(properties);
+        }
+        vertex_property
     }
 }
 
@@ -1405,7 +1713,7 @@ impl DecodeGraphSON for VertexProperty {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:VertexProperty")?;
+        let value_object = validate_type(j_val, CoreType::VertexProperty)?;
 
         let id = get_val_by_key_v3(value_object, "id", "VertexProperty")?;
         let label = get_val_by_key_v3(value_object, "label", "VertexProperty")?;
@@ -1436,12 +1744,19 @@ impl DecodeGraphSON for VertexProperty {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:VertexProperty")?;
+        let value_object = validate_type(j_val, CoreType::VertexProperty)?;
 
         let id = get_val_by_key_v2(value_object, "id", "VertexProperty")?;
         let label = get_val_by_key_v2(value_object, "label", "VertexProperty")?;
         let value = get_val_by_key_v2(value_object, "value", "VertexProperty")?;
-        let vertex_id = get_val_by_key_v2(value_object, "vertex", "VertexProperty")?;
+        let parent = match value_object.get("vertex") {
+            Some(serde_json::Value::Null) => None,
+            _ => Some(Vertex {
+                id: get_val_by_key_v2(value_object, "vertex", "VertexProperty")?,
+                label: Arc::default(),
+                properties: None,
+            }),
+        };
 
         let properties = value_object
             .get("properties")
@@ -1459,29 +1774,25 @@ impl DecodeGraphSON for VertexProperty {
             id,
             label,
             value: Box::new(value),
-            parent: Some(Vertex {
-                id: vertex_id,
-                label: String::default(),
-                properties: None,
-            }),
+            parent,
             properties,
         })
     }
 
-    fn decode_v1(_j_val: &serde_json::Value) -> Result<Self, GraphSonError>
+    fn decode_v1(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
     where
         Self: std::marker::Sized,
     {
-        todo!()
+        vertex_property_decode_v1(j_val, None)
     }
 }
 
 impl EncodeGraphSON for Property {
     fn encode_v3(&self) -> serde_json::Value {
         json!({
-          "@type" : "g:Property",
+          "@type" : type_identifier(CoreType::Property),
           "@value" : {
-            "key" : self.key,
+            "key" : &*self.key,
             "value" : self.value.encode_v3()
           }
         })
@@ -1489,9 +1800,9 @@ impl EncodeGraphSON for Property {
 
     fn encode_v2(&self) -> serde_json::Value {
         json!({
-          "@type" : "g:Property",
+          "@type" : type_identifier(CoreType::Property),
           "@value" : {
-            "key" : self.key,
+            "key" : &*self.key,
             "value" : self.value.encode_v2(),
             "element" : self.parent.encode_v2()
           }
@@ -1500,7 +1811,7 @@ impl EncodeGraphSON for Property {
 
     fn encode_v1(&self) -> serde_json::Value {
         json!({
-          "key" : self.key,
+          "key" : &*self.key,
           "value" : self.value.encode_v1()
         })
     }
@@ -1511,7 +1822,7 @@ impl DecodeGraphSON for Property {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:Property")?;
+        let value_object = validate_type(j_val, CoreType::Property)?;
 
         let key = get_val_by_key_v3(value_object, "key", "Property")?;
 
@@ -1528,7 +1839,7 @@ impl DecodeGraphSON for Property {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:Property")?;
+        let value_object = validate_type(j_val, CoreType::Property)?;
 
         let key = get_val_by_key_v2(value_object, "key", "Property")?;
 
@@ -1567,7 +1878,7 @@ impl EncodeGraphSON for EitherParent {
     fn encode_v2(&self) -> serde_json::Value {
         match self {
             EitherParent::Edge(e) => json!({
-              "@type" : "g:Edge",
+              "@type" : type_identifier(CoreType::Edge),
               "@value" : {
                 "id" : e.id.encode_v2(),
                 "label" : e.label.encode_v2(),
@@ -1597,7 +1908,7 @@ impl DecodeGraphSON for EitherParent {
     where
         Self: std::marker::Sized,
     {
-        if let Ok(value_object) = validate_type(j_val, "g:Edge") {
+        if let Ok(value_object) = validate_type(j_val, CoreType::Edge) {
             let id = get_val_by_key_v2(value_object, "id", "EitherParent")?;
             let label = get_val_by_key_v2(value_object, "label", "EitherParent")?;
             let out_v_id = get_val_by_key_v2(value_object, "outV", "EitherParent")?;
@@ -1613,7 +1924,7 @@ impl DecodeGraphSON for EitherParent {
                 parent: None,
                 properties: None,
             }))
-        } else if let Ok(value_object) = validate_type(j_val, "g:VertexProperty") {
+        } else if let Ok(value_object) = validate_type(j_val, CoreType::VertexProperty) {
             // Not sure what VertexProptery looks like
             let id = get_val_by_key_v2(value_object, "id", "EitherParent")?;
             let label = get_val_by_key_v2(value_object, "label", "EitherParent")?;
@@ -1644,7 +1955,7 @@ impl DecodeGraphSON for EitherParent {
 impl EncodeGraphSON for Traverser {
     fn encode_v3(&self) -> serde_json::Value {
         json!({
-        "@type" : "g:Traverser",
+        "@type" : type_identifier(CoreType::Traverser),
         "@value" : {
           "bulk" : self.bulk.encode_v3(),
           "value": self.value.encode_v3()
@@ -1653,7 +1964,7 @@ impl EncodeGraphSON for Traverser {
 
     fn encode_v2(&self) -> serde_json::Value {
         json!({
-        "@type" : "g:Traverser",
+        "@type" : type_identifier(CoreType::Traverser),
         "@value" : {
           "bulk" : self.bulk.encode_v2(),
           "value": self.value.encode_v2()
@@ -1670,7 +1981,7 @@ impl DecodeGraphSON for Traverser {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:Traverser")?;
+        let value_object = validate_type(j_val, CoreType::Traverser)?;
 
         let bulk = get_val_by_key_v3(value_object, "bulk", "Traverser")?;
         let value = get_val_by_key_v3(value_object, "value", "Traverser")?;
@@ -1685,7 +1996,7 @@ impl DecodeGraphSON for Traverser {
     where
         Self: std::marker::Sized,
     {
-        let value_object = validate_type(j_val, "g:Traverser")?;
+        let value_object = validate_type(j_val, CoreType::Traverser)?;
 
         let bulk = get_val_by_key_v2(value_object, "bulk", "Traverser")?;
         let value = get_val_by_key_v2(value_object, "value", "Traverser")?;
@@ -1704,6 +2015,69 @@ impl DecodeGraphSON for Traverser {
     }
 }
 
+impl EncodeGraphSON for TraversalStrategy {
+    fn encode_v3(&self) -> serde_json::Value {
+        json!({
+        "@type" : type_identifier(CoreType::TraversalStrategy),
+        "@value" : {
+          "class" : self.strategy_class.encode_v3(),
+          "configuration": self.configuration.encode_v3()
+        }})
+    }
+
+    fn encode_v2(&self) -> serde_json::Value {
+        json!({
+        "@type" : type_identifier(CoreType::TraversalStrategy),
+        "@value" : {
+          "class" : self.strategy_class.encode_v2(),
+          "configuration": self.configuration.encode_v2()
+        }})
+    }
+
+    fn encode_v1(&self) -> serde_json::Value {
+        unimplemented!("not supported in GraphSON V1")
+    }
+}
+
+impl DecodeGraphSON for TraversalStrategy {
+    fn decode_v3(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
+    where
+        Self: std::marker::Sized,
+    {
+        let value_object = validate_type(j_val, CoreType::TraversalStrategy)?;
+
+        let strategy_class = get_val_by_key_v3(value_object, "class", "TraversalStrategy")?;
+        let configuration = get_val_by_key_v3(value_object, "configuration", "TraversalStrategy")?;
+
+        Ok(TraversalStrategy {
+            strategy_class,
+            configuration,
+        })
+    }
+
+    fn decode_v2(j_val: &serde_json::Value) -> Result<Self, GraphSonError>
+    where
+        Self: std::marker::Sized,
+    {
+        let value_object = validate_type(j_val, CoreType::TraversalStrategy)?;
+
+        let strategy_class = get_val_by_key_v2(value_object, "class", "TraversalStrategy")?;
+        let configuration = get_val_by_key_v2(value_object, "configuration", "TraversalStrategy")?;
+
+        Ok(TraversalStrategy {
+            strategy_class,
+            configuration,
+        })
+    }
+
+    fn decode_v1(_j_val: &serde_json::Value) -> Result<Self, GraphSonError>
+    where
+        Self: std::marker::Sized,
+    {
+        unimplemented!("not supported in GraphSON V1")
+    }
+}
+
 impl EncodeGraphSON for MapKeys {
     fn encode_v3(&self) -> serde_json::Value {
         match self {
@@ -1713,21 +2087,19 @@ impl EncodeGraphSON for MapKeys {
             MapKeys::Uuid(val) => val.encode_v3(),
             MapKeys::T(val) => val.encode_v3(),
             MapKeys::Direction(val) => val.encode_v3(),
+            MapKeys::Merge(val) => val.encode_v3(),
         }
     }
 
     fn encode_v2(&self) -> serde_json::Value {
         match self {
-            MapKeys::Int(_) => panic!(
-                "non String Mapkeys are not suppoted in GraphSONV2, tried Serilization with i32"
-            ),
+            MapKeys::Int(val) => val.encode_v2(),
             MapKeys::String(val) => val.encode_v2(),
-            MapKeys::Long(_) => panic!(
-                "non String Mapkeys are not suppoted in GraphSONV2, tried Serilization with i64"
-            ),
+            MapKeys::Long(val) => val.encode_v2(),
             MapKeys::Uuid(val) => val.to_string().encode_v2(),
             MapKeys::T(val) => val.to_string().encode_v2(),
             MapKeys::Direction(val) => val.to_string().encode_v2(),
+            MapKeys::Merge(val) => val.to_string().encode_v2(),
         }
     }
 
@@ -1743,6 +2115,7 @@ impl EncodeGraphSON for MapKeys {
             MapKeys::Uuid(val) => val.to_string().encode_v2(),
             MapKeys::T(val) => val.to_string().encode_v2(),
             MapKeys::Direction(val) => val.to_string().encode_v2(),
+            MapKeys::Merge(val) => val.to_string().encode_v2(),
         }
     }
 }
@@ -1823,6 +2196,22 @@ fn big_dec_scale_decode_v3() {
     assert_eq!(res, expected)
 }
 
+#[test]
+fn big_dec_huge_exponent_v3() {
+    let huge = BigDecimal::from_str("1e400").unwrap();
+    let jval = huge.encode_v3();
+    assert_eq!(huge, BigDecimal::decode_v3(&jval).unwrap());
+
+    let s = json!({"@type" : "gx:BigDecimal", "@value" : "1e400"});
+    assert_eq!(huge, BigDecimal::decode_v3(&s).unwrap());
+
+    let s = json!({"@type" : "gx:BigInteger", "@value" : "123456789987654321123456789987654321"});
+    assert_eq!(
+        BigInt::from_str("123456789987654321123456789987654321").unwrap(),
+        BigInt::decode_v3(&s).unwrap()
+    );
+}
+
 #[test]
 fn bulkset_encode_v3() {
     let expected = r#"{"@type":"g:BulkSet","@value":["marko",{"@type":"g:Int64","@value":1},"josh",{"@type":"g:Int64","@value":2}]}"#;
@@ -1845,6 +2234,19 @@ fn bulkset_decode_v3() {
     assert_eq!(res, expected)
 }
 
+#[test]
+fn tree_encode_decode_v3() {
+    let mut tree = Tree::new();
+    tree.insert(1).insert("a");
+    tree.insert(2);
+
+    let expected = r#"{"@type":"g:Tree","@value":[{"key":{"@type":"g:Int32","@value":1},"value":{"@type":"g:Tree","@value":[{"key":"a","value":{"@type":"g:Tree","@value":[]}}]}},{"key":{"@type":"g:Int32","@value":2},"value":{"@type":"g:Tree","@value":[]}}]}"#;
+    let expected: serde_json::Value = serde_json::from_str(expected).unwrap();
+
+    assert_eq!(tree.encode_v3(), expected);
+    assert_eq!(Tree::decode_v3(&expected).unwrap(), tree);
+}
+
 #[test]
 fn bytecode_encode_v3() {
     let byte_buffer = ByteBuffer(vec![b'a', b'b', b'c', b'd', 255, 128, 129, 130]);
@@ -1942,14 +2344,14 @@ fn bytecode_decode_int_parameter_v3() {
 fn edge_encode_v3() {
     let e = Edge {
         id: 13.into(),
-        label: "develops".to_string(),
+        label: "develops".into(),
         in_v_id: 10.into(),
-        in_v_label: "software".to_string(),
+        in_v_label: "software".into(),
         out_v_id: 1.into(),
-        out_v_label: "person".to_string(),
+        out_v_label: "person".into(),
         parent: None,
         properties: Some(vec![Property {
-            key: "since".to_string(),
+            key: "since".into(),
             value: Box::new(2009.into()),
             parent: property::EitherParent::None,
         }]),
@@ -1965,11 +2367,11 @@ fn edge_encode_v3() {
 fn edge_encode_v3_without_props() {
     let e = Edge {
         id: 13.into(),
-        label: "develops".to_string(),
+        label: "develops".into(),
         in_v_id: 10.into(),
-        in_v_label: "software".to_string(),
+        in_v_label: "software".into(),
         out_v_id: 1.into(),
-        out_v_label: "person".to_string(),
+        out_v_label: "person".into(),
         parent: None,
         properties: None,
     };
@@ -1984,14 +2386,14 @@ fn edge_encode_v3_without_props() {
 fn edge_decode_v3() {
     let expected = Edge {
         id: 13.into(),
-        label: "develops".to_string(),
+        label: "develops".into(),
         in_v_id: 10.into(),
-        in_v_label: "software".to_string(),
+        in_v_label: "software".into(),
         out_v_id: 1.into(),
-        out_v_label: "person".to_string(),
+        out_v_label: "person".into(),
         parent: None,
         properties: Some(vec![Property {
-            key: "since".to_string(),
+            key: "since".into(),
             value: Box::new(2009.into()),
             parent: property::EitherParent::None,
         }]),
@@ -2039,11 +2441,11 @@ fn edge_decode_v3() {
 fn edge_decode_v3_without_props() {
     let expected = Edge {
         id: 13.into(),
-        label: "develops".to_string(),
+        label: "develops".into(),
         in_v_id: 10.into(),
-        in_v_label: "software".to_string(),
+        in_v_label: "software".into(),
         out_v_id: 1.into(),
-        out_v_label: "person".to_string(),
+        out_v_label: "person".into(),
         parent: None,
         properties: None,
     };
@@ -2078,14 +2480,14 @@ fn edge_decode_v3_without_props() {
 fn edge_encode_v2() {
     let e = Edge {
         id: 13.into(),
-        label: "develops".to_string(),
+        label: "develops".into(),
         in_v_id: 10.into(),
-        in_v_label: "software".to_string(),
+        in_v_label: "software".into(),
         out_v_id: 1.into(),
-        out_v_label: "person".to_string(),
+        out_v_label: "person".into(),
         parent: None,
         properties: Some(vec![Property {
-            key: "since".to_string(),
+            key: "since".into(),
             value: Box::new(2009.into()),
             parent: property::EitherParent::None,
         }]),
@@ -2101,11 +2503,11 @@ fn edge_encode_v2() {
 fn edge_encode_v2_without_props() {
     let e = Edge {
         id: 13.into(),
-        label: "develops".to_string(),
+        label: "develops".into(),
         in_v_id: 10.into(),
-        in_v_label: "software".to_string(),
+        in_v_label: "software".into(),
         out_v_id: 1.into(),
-        out_v_label: "person".to_string(),
+        out_v_label: "person".into(),
         parent: None,
         properties: None,
     };
@@ -2120,14 +2522,14 @@ fn edge_encode_v2_without_props() {
 fn edge_decode_v2() {
     let expected = Edge {
         id: 13.into(),
-        label: "develops".to_string(),
+        label: "develops".into(),
         in_v_id: 10.into(),
-        in_v_label: "software".to_string(),
+        in_v_label: "software".into(),
         out_v_id: 1.into(),
-        out_v_label: "person".to_string(),
+        out_v_label: "person".into(),
         parent: None,
         properties: Some(vec![Property {
-            key: "since".to_string(),
+            key: "since".into(),
             value: Box::new(2009.into()),
             parent: property::EitherParent::None,
         }]),
@@ -2169,11 +2571,11 @@ fn edge_decode_v2() {
 fn edge_decode_v2_without_props() {
     let expected = Edge {
         id: 13.into(),
-        label: "develops".to_string(),
+        label: "develops".into(),
         in_v_id: 10.into(),
-        in_v_label: "software".to_string(),
+        in_v_label: "software".into(),
         out_v_id: 1.into(),
-        out_v_label: "person".to_string(),
+        out_v_label: "person".into(),
         parent: None,
         properties: None,
     };
@@ -2301,6 +2703,39 @@ fn metrics_decode_v3() {
     assert_eq!(metrics_res, expected)
 }
 
+#[test]
+fn metrics_decode_v3_nested_annotation() {
+    let expected = Metrics {
+        id: "7.0.0()".to_string(),
+        name: "TinkerGraphStep(vertex,[~label.eq(person)])".to_string(),
+        duration: 100000000,
+        counts: HashMap::from([("elementCount".to_string(), 4)]),
+        annotations: HashMap::from([
+            ("percentDur".to_string(), 25.0f64.into()),
+            (
+                "index".to_string(),
+                HashMap::from([
+                    (
+                        MapKeys::String("name".to_string()),
+                        GremlinValue::from("lookup"),
+                    ),
+                    (
+                        MapKeys::String("keys".to_string()),
+                        vec![GremlinValue::from("name"), GremlinValue::from("age")].into(),
+                    ),
+                ])
+                .into(),
+            ),
+        ]),
+        nested_metrics: vec![],
+    };
+
+    let str = r#"{"@type":"g:Metrics","@value":{"@type":"g:Map","@value":["dur",{"@type":"g:Double","@value":100.0},"counts",{"@type":"g:Map","@value":["elementCount",{"@type":"g:Int64","@value":4}]},"name","TinkerGraphStep(vertex,[~label.eq(person)])","annotations",{"@type":"g:Map","@value":["percentDur",{"@type":"g:Double","@value":25.0},"index",{"@type":"g:Map","@value":["name","lookup","keys",{"@type":"g:List","@value":["name","age"]}]}]},"id","7.0.0()"]}}"#;
+    let jval: serde_json::Value = serde_json::from_str(str).unwrap();
+    let metrics_res = Metrics::decode_v3(&jval).unwrap();
+    assert_eq!(metrics_res, expected)
+}
+
 #[test]
 fn metrics_encode_v2() {
     let metric = Metrics {
@@ -2484,6 +2919,34 @@ fn metrics_decode_v2_traversal() {
     assert_eq!(metrics_res, expected)
 }
 
+#[test]
+fn traversal_metrics_decode_v2() {
+    let expected = TraversalMetrics {
+        duration: 4_000_000,
+        metrics: vec![Metrics {
+            id: "7.0.0()".to_string(),
+            name: "TinkerGraphStep(vertex,[])".to_string(),
+            duration: 100_000_000,
+            counts: HashMap::from([("elementCount".to_string(), 4)]),
+            annotations: HashMap::from([("percentDur".to_string(), 25.0f64.into())]),
+            nested_metrics: vec![],
+        }],
+    };
+
+    let str = r#"{"@type":"g:TraversalMetrics","@value":{"dur":{"@type":"g:Double","@value":4.0},"metrics":[{"@type":"g:Metrics","@value":{"dur":{"@type":"g:Double","@value":100.0},"counts":{"elementCount":{"@type":"g:Int64","@value":4}},"name":"TinkerGraphStep(vertex,[])","annotations":{"percentDur":{"@type":"g:Double","@value":25.0}},"id":"7.0.0()"}}]}}"#;
+    let jval: serde_json::Value = serde_json::from_str(str).unwrap();
+    let metrics_res = TraversalMetrics::decode_v2(&jval).unwrap();
+    assert_eq!(metrics_res, expected);
+
+    let str =
+        r#"{"@type":"g:Metrics","@value":{"dur":{"@type":"g:Double","@value":4.0},"metrics":[]}}"#;
+    let jval: serde_json::Value = serde_json::from_str(str).unwrap();
+    assert!(matches!(
+        TraversalMetrics::decode_v2(&jval),
+        Err(GraphSonError::WrongTypeIdentifier { .. })
+    ));
+}
+
 #[test]
 fn path_encode_v3() {
     let p = Path {
@@ -2600,7 +3063,7 @@ fn path_decode_v2() {
 fn vertex_encode_v3() {
     let v = Vertex {
         id: 1_i32.into(),
-        label: String::from("person"),
+        label: "person".into(),
         properties: Some(vec![
             VertexProperty {
                 id: 0i64.into(),
@@ -2756,7 +3219,7 @@ fn vertex_decode_v3() {
 
     let expected = Vertex {
         id: 1_i32.into(),
-        label: String::from("person"),
+        label: "person".into(),
         properties: Some(vec![
             VertexProperty::new(0i64, "name", "marko", None, None),
             VertexProperty::new(
@@ -2803,11 +3266,8 @@ fn vertex_decode_v3() {
     let mut v = Vertex::decode_v3(&value).unwrap();
     v.properties.as_mut().into_iter().for_each(|p| {
         for i in p {
-            if i.properties.is_some() {
-                i.properties
-                    .as_mut()
-                    .unwrap()
-                    .sort_by(|p1, p2| p1.key.cmp(&p2.key).reverse())
+            if let Some(props) = &mut i.properties {
+                props.sort_by(|p1, p2| p1.key.cmp(&p2.key).reverse())
             }
         }
     });
@@ -2815,7 +3275,7 @@ fn vertex_decode_v3() {
     v.properties
         .as_mut()
         .unwrap()
-        .sort_by(|p1, p2| p1.id.as_i64().unwrap().cmp(&p2.id.as_i64().unwrap()));
+        .sort_by_key(|p| p.id.as_i64().unwrap());
     assert_eq!(v, expected)
 }
 
@@ -2834,7 +3294,7 @@ fn vertex_decode_v3_without_props() {
 
     let expected = Vertex {
         id: 1_i32.into(),
-        label: String::from("person"),
+        label: "person".into(),
         properties: None,
     };
 
@@ -2846,7 +3306,7 @@ fn vertex_decode_v3_without_props() {
 fn vertex_encode_v2() {
     let v = Vertex {
         id: 1_i32.into(),
-        label: String::from("person"),
+        label: "person".into(),
         properties: Some(vec![
             VertexProperty {
                 id: 0i64.into(),
@@ -3022,7 +3482,7 @@ fn vertex_decode_v2() {
 
     let expected = Vertex {
         id: 1_i32.into(),
-        label: String::from("person"),
+        label: "person".into(),
         properties: Some(vec![
             VertexProperty::new(0i64, "name", "marko", Some(Vertex::new(1, "", None)), None),
             VertexProperty::new(
@@ -3069,11 +3529,8 @@ fn vertex_decode_v2() {
     let mut v = Vertex::decode_v2(&value).unwrap();
     v.properties.as_mut().into_iter().for_each(|p| {
         for i in p {
-            if i.properties.is_some() {
-                i.properties
-                    .as_mut()
-                    .unwrap()
-                    .sort_by(|p1, p2| p1.key.cmp(&p2.key).reverse())
+            if let Some(props) = &mut i.properties {
+                props.sort_by(|p1, p2| p1.key.cmp(&p2.key).reverse())
             }
         }
     });
@@ -3081,7 +3538,7 @@ fn vertex_decode_v2() {
     v.properties
         .as_mut()
         .unwrap()
-        .sort_by(|p1, p2| p1.id.as_i64().unwrap().cmp(&p2.id.as_i64().unwrap()));
+        .sort_by_key(|p| p.id.as_i64().unwrap());
     assert_eq!(v, expected)
 }
 
@@ -3100,7 +3557,7 @@ fn vertex_decode_v2_without_props() {
 
     let expected = Vertex {
         id: 1_i32.into(),
-        label: String::from("person"),
+        label: "person".into(),
         properties: None,
     };
 
@@ -3125,7 +3582,7 @@ fn property_decode_v3() {
     let val = serde_json::from_str(s).unwrap();
     let p = Property::decode_v3(&val).unwrap();
     let expected = Property {
-        key: "since".to_string(),
+        key: "since".into(),
         value: Box::new(2009.into()),
         parent: EitherParent::None,
     };
@@ -3135,7 +3592,7 @@ fn property_decode_v3() {
 #[test]
 fn property_encode_v3() {
     let property = Property {
-        key: "since".to_string(),
+        key: "since".into(),
         value: Box::new(2009.into()),
         parent: EitherParent::None,
     };
@@ -3179,13 +3636,13 @@ fn property_decode_v2() {
     let val = serde_json::from_str(s).unwrap();
     let p = Property::decode_v2(&val).unwrap();
     let expected = Property {
-        key: "since".to_string(),
+        key: "since".into(),
         value: Box::new(2009.into()),
         parent: EitherParent::Edge(Edge {
             id: 13.into(),
-            label: "develops".to_string(),
+            label: "develops".into(),
             in_v_id: 10.into(),
-            in_v_label: "".to_string(),
+            in_v_label: "".into(),
             out_v_id: 1.into(),
             out_v_label: Default::default(),
             parent: None,
@@ -3198,13 +3655,13 @@ fn property_decode_v2() {
 #[test]
 fn property_encode_v2() {
     let property = Property {
-        key: "since".to_string(),
+        key: "since".into(),
         value: Box::new(2009.into()),
         parent: EitherParent::Edge(Edge {
             id: 13.into(),
-            label: "develops".to_string(),
+            label: "develops".into(),
             in_v_id: 10.into(),
-            in_v_label: "".to_string(),
+            in_v_label: "".into(),
             out_v_id: 1.into(),
             out_v_label: Default::default(),
             parent: None,
@@ -3339,11 +3796,8 @@ fn traverser_decode_v3() {
         .into_iter()
         .for_each(|p| {
             for i in p {
-                if i.properties.is_some() {
-                    i.properties
-                        .as_mut()
-                        .unwrap()
-                        .sort_by(|p1, p2| p1.key.cmp(&p2.key).reverse())
+                if let Some(props) = &mut i.properties {
+                    props.sort_by(|p1, p2| p1.key.cmp(&p2.key).reverse())
                 }
             }
         });
@@ -3353,7 +3807,7 @@ fn traverser_decode_v3() {
         .properties
         .as_mut()
         .unwrap()
-        .sort_by(|p1, p2| p1.id.as_i64().unwrap().cmp(&p2.id.as_i64().unwrap()));
+        .sort_by_key(|p| p.id.as_i64().unwrap());
     assert_eq!(res, expected)
 }
 
@@ -3484,7 +3938,7 @@ fn traverser_decode_v2() {
         value: Box::new(
             Vertex {
                 id: 1_i32.into(),
-                label: String::from("person"),
+                label: "person".into(),
                 properties: Some(vec![
                     VertexProperty::new(
                         0i64,
@@ -3547,11 +4001,8 @@ fn traverser_decode_v2() {
         .into_iter()
         .for_each(|p| {
             for i in p {
-                if i.properties.is_some() {
-                    i.properties
-                        .as_mut()
-                        .unwrap()
-                        .sort_by(|p1, p2| p1.key.cmp(&p2.key).reverse())
+                if let Some(props) = &mut i.properties {
+                    props.sort_by(|p1, p2| p1.key.cmp(&p2.key).reverse())
                 }
             }
         });
@@ -3561,6 +4012,291 @@ fn traverser_decode_v2() {
         .properties
         .as_mut()
         .unwrap()
-        .sort_by(|p1, p2| p1.id.as_i64().unwrap().cmp(&p2.id.as_i64().unwrap()));
+        .sort_by_key(|p| p.id.as_i64().unwrap());
     assert_eq!(res, expected)
 }
+
+#[test]
+fn empty_properties_omitted() {
+    let vertex = Vertex {
+        id: 1_i32.into(),
+        label: "person".into(),
+        properties: Some(vec![]),
+    };
+    let edge = Edge {
+        id: 13_i32.into(),
+        label: "develops".into(),
+        in_v_id: 10_i32.into(),
+        in_v_label: "software".into(),
+        out_v_id: 1_i32.into(),
+        out_v_label: "person".into(),
+        parent: None,
+        properties: Some(vec![]),
+    };
+    let vertex_property = VertexProperty {
+        id: 0_i64.into(),
+        label: "name".into(),
+        value: Box::new("marko".into()),
+        parent: None,
+        properties: Some(vec![]),
+    };
+
+    for jval in [
+        vertex.encode_v3(),
+        vertex.encode_v2(),
+        edge.encode_v3(),
+        edge.encode_v2(),
+        vertex_property.encode_v3(),
+        vertex_property.encode_v2(),
+    ] {
+        assert!(jval["@value"].get("properties").is_none(), "{jval}");
+    }
+}
+
+#[test]
+fn vertex_get_path_v3() {
+    let jval = json!({
+        "@type" : "g:Vertex",
+        "@value" : {
+            "id" : {"@type" : "g:Int32", "@value" : 1},
+            "label" : "person",
+            "properties" : {
+                "name" : [{
+                    "@type" : "g:VertexProperty",
+                    "@value" : {"id" : {"@type" : "g:Int64", "@value" : 0}, "value" : "marko", "label" : "name"}
+                }],
+                "location" : [{
+                    "@type" : "g:VertexProperty",
+                    "@value" : {
+                        "id" : {"@type" : "g:Int64", "@value" : 6},
+                        "value" : "san diego",
+                        "label" : "location",
+                        "properties" : {"startTime" : {"@type" : "g:Int32", "@value" : 1997}}
+                    }
+                }]
+            }
+        }
+    });
+
+    let vertex = GremlinValue::decode_v3(&jval).unwrap();
+    assert_eq!(
+        vertex.get_path("properties.name[0].value"),
+        Some(&GremlinValue::from("marko"))
+    );
+    assert_eq!(
+        vertex.get_path("properties.location[0].properties.startTime"),
+        Some(&GremlinValue::Int(1997))
+    );
+    assert_eq!(vertex.get_path("properties.name[1].value"), None);
+    assert_eq!(vertex.get_path("properties.name"), None);
+}
+
+#[test]
+fn graph_roundtrip() {
+    let graph = Graph {
+        vertices: vec![
+            Vertex::new(1, "person", None),
+            Vertex::new(2, "software", None),
+        ],
+        edges: vec![GraphEdge {
+            id: 9.into(),
+            label: "created".into(),
+            in_v_id: 2.into(),
+            in_v_label: Some("software".into()),
+            out_v_id: 1.into(),
+            out_v_label: Some("person".into()),
+            parent: None,
+            properties: vec![],
+        }],
+    };
+
+    let v3 = graph.encode_v3();
+    assert_eq!("tinker:graph", v3["@type"]);
+    assert_eq!(
+        GremlinValue::Graph(graph.clone()),
+        GremlinValue::decode_v3(&v3).unwrap()
+    );
+
+    let v2 = graph.encode_v2();
+    assert_eq!("tinker:graph", v2["@type"]);
+    assert_eq!(
+        GremlinValue::Graph(graph),
+        GremlinValue::decode_v2(&v2).unwrap()
+    );
+}
+
+#[test]
+fn set_decode_v2() {
+    let expected = Set::new(vec![1, 2]);
+
+    let bare = json!([{"@type" : "g:Int32", "@value" : 1}, {"@type" : "g:Int32", "@value" : 2}]);
+    assert_eq!(expected, Set::<i32>::decode_v2(&bare).unwrap());
+
+    let list = json!({
+        "@type" : "g:List",
+        "@value" : [{"@type" : "g:Int32", "@value" : 1}, {"@type" : "g:Int32", "@value" : 2}]
+    });
+    assert_eq!(expected, Set::<i32>::decode_v2(&list).unwrap());
+
+    let wrong = json!({"@type" : "g:Map", "@value" : []});
+    assert!(Set::<i32>::decode_v2(&wrong).is_err());
+}
+
+#[test]
+fn set_decode_v1() {
+    let set = Set::<i32>::decode_v1(&json!([1, 2, 3])).unwrap();
+    assert_eq!(Set::new(vec![1, 2, 3]), set);
+    assert_eq!(json!([1, 2, 3]), set.encode_v1());
+
+    assert!(Set::<i32>::decode_v1(&json!({"@type" : "g:Set", "@value" : [1]})).is_err());
+}
+
+#[test]
+fn path_roundtrip_v1() {
+    let path = Path {
+        labels: vec![Set::new(vec!["a".to_string()]), Set::new(vec![])],
+        objects: vec!["marko".into(), 29.into()],
+    };
+
+    let expected = json!({
+        "labels" : [["a"], []],
+        "objects" : ["marko", 29]
+    });
+
+    assert_eq!(expected, path.encode_v1());
+    assert_eq!(path, Path::decode_v1(&expected).unwrap());
+}
+
+#[test]
+fn path_elements_roundtrip_v1() {
+    let marko = Vertex::new(
+        1,
+        "person",
+        Some(vec![VertexProperty::new(
+            0_i64, "name", "marko", None, None,
+        )]),
+    );
+    let knows = Edge {
+        id: 7.into(),
+        label: "knows".into(),
+        in_v_id: 2.into(),
+        in_v_label: "person".into(),
+        out_v_id: 1.into(),
+        out_v_label: "person".into(),
+        parent: None,
+        properties: Some(vec![Property::new("weight", 0.5_f64, EitherParent::None)]),
+    };
+    let path = Path {
+        labels: vec![
+            Set::new(vec!["a".to_string()]),
+            Set::new(vec!["b".to_string()]),
+        ],
+        objects: vec![marko.into(), knows.into()],
+    };
+
+    let expected = json!({
+        "labels" : [["a"], ["b"]],
+        "objects" : [{
+            "id" : 1,
+            "label" : "person",
+            "type" : "vertex",
+            "properties" : {"name" : [{"id" : 0, "value" : "marko", "label" : "name"}]}
+        }, {
+            "id" : 7,
+            "label" : "knows",
+            "type" : "edge",
+            "inVLabel" : "person",
+            "outVLabel" : "person",
+            "inV" : 2,
+            "outV" : 1,
+            "properties" : {"weight" : 0.5}
+        }]
+    });
+    assert_eq!(expected, path.encode_v1());
+
+    // ids are untyped in v1, the Long id of the vertex property comes back as Int
+    let mut decoded = Path::decode_v1(&expected).unwrap();
+    if let Some(GremlinValue::Vertex(vertex)) = decoded.objects.first_mut() {
+        for vertex_property in vertex.properties.iter_mut().flatten() {
+            assert_eq!(ElementId::Int(0), vertex_property.id);
+            vertex_property.id = ElementId::Long(0);
+        }
+    }
+    assert_eq!(path, decoded);
+}
+
+#[test]
+fn vertex_flat_properties_decode_v3() {
+    let jval = json!({
+        "@type" : "g:Vertex",
+        "@value" : {
+            "id" : {"@type" : "g:Int32", "@value" : 1},
+            "label" : "person",
+            "properties" : [{
+                "@type" : "g:VertexProperty",
+                "@value" : {"id" : {"@type" : "g:Int64", "@value" : 0}, "value" : "marko", "label" : "name"}
+            }, {
+                "@type" : "g:VertexProperty",
+                "@value" : {"id" : {"@type" : "g:Int64", "@value" : 6}, "value" : "san diego", "label" : "location"}
+            }]
+        }
+    });
+
+    let vertex = Vertex::decode_v3(&jval).unwrap();
+    let properties = vertex.properties.unwrap();
+    assert_eq!(2, properties.len());
+    assert_eq!("name", &*properties[0].label);
+    assert_eq!(GremlinValue::from("marko"), *properties[0].value);
+    assert_eq!("location", &*properties[1].label);
+}
+
+#[test]
+fn bytecode_with_source_roundtrip_v3() {
+    let mut bytecode = Bytecode::default();
+    bytecode.push_new_source("inject", vec![29.into()]);
+    bytecode.push_new_step("V", vec![]);
+    bytecode.push_new_step("hasLabel", vec!["person".into()]);
+
+    let jval = bytecode.encode_v3();
+    assert_eq!(
+        json!([["inject", {"@type" : "g:Int32", "@value" : 29}]]),
+        jval["@value"]["source"]
+    );
+    assert_eq!(bytecode, Bytecode::decode_v3(&jval).unwrap());
+    assert_eq!(
+        bytecode,
+        Bytecode::decode_v2(&bytecode.encode_v2()).unwrap()
+    );
+}
+
+#[test]
+fn elements_without_properties_roundtrip_v1() {
+    let vertex = Vertex::new(1, "person", None);
+    let edge = Edge {
+        id: 7.into(),
+        label: "knows".into(),
+        in_v_id: 2.into(),
+        in_v_label: "person".into(),
+        out_v_id: 1.into(),
+        out_v_label: "person".into(),
+        parent: None,
+        properties: None,
+    };
+
+    let j_vertex = vertex.encode_v1();
+    assert_eq!(
+        json!({"id" : 1, "label" : "person", "type" : "vertex"}),
+        j_vertex
+    );
+    assert_eq!(vertex, Vertex::decode_v1(&j_vertex).unwrap());
+    assert_eq!(j_vertex, Vertex::new(1, "person", Some(vec![])).encode_v1());
+
+    let j_edge = edge.encode_v1();
+    assert_eq!(None, j_edge.get("properties"));
+    assert_eq!(edge, Edge::decode_v1(&j_edge).unwrap());
+    let empty = Edge {
+        properties: Some(vec![]),
+        ..edge
+    };
+    assert_eq!(j_edge, empty.encode_v1());
+}
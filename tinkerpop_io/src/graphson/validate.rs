@@ -0,0 +1,168 @@
+use serde_json::Value;
+
+use crate::error::GraphSonError;
+use crate::specs::{core_type_from_graphson, CoreType};
+
+use super::GraphSonVersion;
+
+/// Checks the structure of a GraphSON payload without decoding it.
+///
+/// Every `@type` has to be a string accompanied by a `@value`, and the `@value` of the known
+/// element types has to hold the keys required to decode it. The payload is walked completely
+/// and all errors are returned, each wrapped in a [`GraphSonError::FieldError`] holding the
+/// JSON pointer of the offending value. GraphSON v1 has no type information, so it is always valid.
+pub fn validate(j_val: &Value, version: GraphSonVersion) -> Result<(), Vec<GraphSonError>> {
+    let mut errors = Vec::new();
+    if version != GraphSonVersion::V1 {
+        validate_value(j_val, version, "", &mut errors);
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn validate_value(
+    j_val: &Value,
+    version: GraphSonVersion,
+    path: &str,
+    errors: &mut Vec<GraphSonError>,
+) {
+    match j_val {
+        Value::Array(array) => {
+            for (index, value) in array.iter().enumerate() {
+                validate_value(value, version, &format!("{path}/{index}"), errors);
+            }
+        }
+        Value::Object(object) => match object.get("@type") {
+            Some(Value::String(identifier)) => {
+                let Some(value) = object.get("@value") else {
+                    push_error(
+                        errors,
+                        path,
+                        GraphSonError::KeyNotFound("@value".to_string()),
+                    );
+                    return;
+                };
+                let value_path = format!("{path}/@value");
+                if let Err(err) = validate_typed_value(identifier, value, version) {
+                    push_error(errors, &value_path, err);
+                }
+                validate_value(value, version, &value_path, errors);
+            }
+            Some(_) => push_error(
+                errors,
+                &format!("{path}/@type"),
+                GraphSonError::WrongJsonType("str".to_string()),
+            ),
+            None => {
+                for (key, value) in object {
+                    validate_value(value, version, &format!("{path}/{key}"), errors);
+                }
+            }
+        },
+        _ => {}
+    }
+}
+
+fn validate_typed_value(
+    identifier: &str,
+    value: &Value,
+    version: GraphSonVersion,
+) -> Result<(), GraphSonError> {
+    match core_type_from_graphson(identifier) {
+        Some(
+            CoreType::Int32
+            | CoreType::Long
+            | CoreType::Double
+            | CoreType::Float
+            | CoreType::Byte
+            | CoreType::Short,
+        ) => {
+            if value.is_number() || value.is_string() {
+                Ok(())
+            } else {
+                Err(GraphSonError::WrongJsonType("number".to_string()))
+            }
+        }
+        Some(CoreType::List | CoreType::Set | CoreType::BulkSet)
+            if version == GraphSonVersion::V3 =>
+        {
+            expect_array(value).map(|_| ())
+        }
+        Some(CoreType::Map) if version == GraphSonVersion::V3 => {
+            if expect_array(value)?.len() % 2 == 0 {
+                Ok(())
+            } else {
+                Err(GraphSonError::WrongFixedValue(
+                    "even number of g:Map entries".to_string(),
+                ))
+            }
+        }
+        Some(CoreType::Vertex) => required_keys(value, &["id", "label"]),
+        Some(CoreType::Edge) => required_keys(value, &["id", "label", "inV", "outV"]),
+        Some(CoreType::VertexProperty) => required_keys(value, &["id", "label", "value"]),
+        Some(CoreType::Property) => required_keys(value, &["key", "value"]),
+        Some(CoreType::Path) => required_keys(value, &["labels", "objects"]),
+        Some(CoreType::Traverser) => required_keys(value, &["bulk", "value"]),
+        _ => Ok(()),
+    }
+}
+
+fn expect_array(value: &Value) -> Result<&Vec<Value>, GraphSonError> {
+    value
+        .as_array()
+        .ok_or_else(|| GraphSonError::WrongJsonType("array".to_string()))
+}
+
+fn required_keys(value: &Value, keys: &[&str]) -> Result<(), GraphSonError> {
+    let object = value
+        .as_object()
+        .ok_or_else(|| GraphSonError::WrongJsonType("object".to_string()))?;
+    match keys.iter().find(|key| !object.contains_key(**key)) {
+        Some(key) => Err(GraphSonError::KeyNotFound(key.to_string())),
+        None => Ok(()),
+    }
+}
+
+fn push_error(errors: &mut Vec<GraphSonError>, path: &str, source: GraphSonError) {
+    errors.push(GraphSonError::FieldError {
+        context: path.to_string(),
+        source: Box::new(source),
+    });
+}
+
+#[test]
+fn validate_collects_errors() {
+    use serde_json::json;
+
+    let jval = json!({
+        "@type" : "g:List",
+        "@value" : [
+            {"@type" : "g:Vertex", "@value" : {"id" : {"@type" : "g:Int64", "@value" : 1}}},
+            {"@type" : "g:Int32", "@value" : 1},
+            {"@type" : "g:Int32"}
+        ]
+    });
+
+    let errors = validate(&jval, GraphSonVersion::V3).unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(
+        &errors[0],
+        GraphSonError::FieldError { context, source }
+            if context == "/@value/0/@value" && matches!(**source, GraphSonError::KeyNotFound(ref key) if key == "label")
+    ));
+    assert!(matches!(
+        &errors[1],
+        GraphSonError::FieldError { context, source }
+            if context == "/@value/2" && matches!(**source, GraphSonError::KeyNotFound(ref key) if key == "@value")
+    ));
+
+    assert!(validate(
+        &json!({"@type" : "g:Int32", "@value" : 1}),
+        GraphSonVersion::V3
+    )
+    .is_ok());
+    assert!(validate(&jval, GraphSonVersion::V1).is_ok());
+}
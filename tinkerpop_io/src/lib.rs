@@ -19,6 +19,12 @@ mod extended;
 #[cfg(feature = "graph_son")]
 pub mod graphson;
 
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(all(feature = "graph_binary", feature = "graph_son"))]
+pub mod transcode;
+
 use bigdecimal::BigDecimal;
 #[cfg(feature = "extended")]
 use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
@@ -29,14 +35,19 @@ use custom::Custom;
 use extended::chrono::{Instant, MonthDay, OffsetTime, Period, Year, YearMonth, ZonedDateTime};
 
 use num::BigInt;
+pub use specs::CoreType;
 use structure::enums::P;
 use structure::map::MapKeys;
 use structure::set::Set;
 pub use structure::Binding;
 
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::Arc;
 
+use crate::error::{CoerceError, MergeError, TypeError, ValidationError};
 use crate::macros::{TryBorrowFrom, TryMutBorrowFrom};
 use crate::structure::bulkset::BulkSet;
 use crate::structure::bytebuffer::ByteBuffer;
@@ -45,18 +56,41 @@ use crate::structure::edge::Edge;
 use crate::structure::enums::{
     Barrier, Cardinality, Column, Direction, Merge, Operator, Order, Pick, Pop, Scope, TextP, T,
 };
-use crate::structure::graph::Graph;
+use crate::structure::graph::{Graph, GraphEdge};
+use crate::structure::id::ElementId;
 use crate::structure::lambda::Lambda;
 use crate::structure::metrics::{Metrics, TraversalMetrics};
 use crate::structure::path::Path;
-use crate::structure::property::Property;
+use crate::structure::property::{EitherParent, Property};
 use crate::structure::traverser::{TraversalStrategy, Traverser};
+use crate::structure::tree::Tree;
 use crate::structure::vertex::Vertex;
 use crate::structure::vertex_property::VertexProperty;
 use uuid::Uuid;
 
 /// All possible Values supported in the [graphbinary serialization format](https://tinkerpop.apache.org/docs/current/dev/io/#graphbinary)
+///
+/// Primitives and structures such as `Vertex`, `Edge`, `VertexProperty`, `Property`, `Path` or
+/// `Graph` convert into their variant with `From`.
+///
+/// ```
+/// # use tinkerpop_io::GremlinValue;
+/// # use tinkerpop_io::structure::property::{EitherParent, Property};
+/// # use tinkerpop_io::structure::vertex::Vertex;
+/// # use tinkerpop_io::structure::vertex_property::VertexProperty;
+/// let vertex = Vertex::new(1_i64, "person", None);
+/// assert_eq!(GremlinValue::Vertex(vertex.clone()), GremlinValue::from(vertex));
+///
+/// let property = Property::new("since", 2009, EitherParent::None);
+/// let value: GremlinValue = property.clone().into();
+/// assert_eq!(GremlinValue::Property(property), value);
+///
+/// let vertex_property = VertexProperty::new(0_i64, "name", "marko", None, None);
+/// let value: GremlinValue = vertex_property.clone().into();
+/// assert_eq!(GremlinValue::VertexProperty(vertex_property), value);
+/// ```
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum GremlinValue {
     Int(i32),
@@ -102,13 +136,14 @@ pub enum GremlinValue {
     TextP(TextP),
     TraversalStrategy(TraversalStrategy),
     BulkSet(BulkSet),
+    Tree(Tree),
     Metrics(Metrics),
     TraversalMetrics(TraversalMetrics),
     Merge(Merge),
     UnspecifiedNullObject,
     #[cfg(feature = "custom")]
     Custom(Custom),
-    #[cfg(feature = "extended")]
+    /// Available without the `extended` feature.
     Char(char),
     #[cfg(feature = "extended")]
     Duration(Duration),
@@ -137,6 +172,10 @@ pub enum GremlinValue {
     #[cfg(feature = "extended")]
     ZonedDateTime(ZonedDateTime),
     #[cfg(feature = "extended")]
+    #[cfg_attr(
+        feature = "serde-derive",
+        serde(with = "crate::extended::chrono::fixed_offset_seconds")
+    )]
     ZoneOffset(FixedOffset),
 }
 
@@ -220,70 +259,2535 @@ impl GremlinValue {
             graph_binary => Some(graph_binary),
         }
     }
-}
 
-impl Display for GremlinValue {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    /// Builds a `GremlinValue::Class` from a fully-qualified JVM class name.
+    /// The name is trimmed and has to be a dotted path of Java identifiers.
+    ///
+    /// ```
+    /// # use tinkerpop_io::GremlinValue;
+    /// # use tinkerpop_io::error::ValidationError;
+    ///
+    /// let class = GremlinValue::class(" java.lang.String ").unwrap();
+    /// assert_eq!(Some("java.lang.String"), class.as_class());
+    ///
+    /// assert!(matches!(
+    ///     GremlinValue::class(""),
+    ///     Err(ValidationError::ClassName(_))
+    /// ));
+    /// assert!(GremlinValue::class("java..String").is_err());
+    /// assert!(GremlinValue::class("java.lang.1String").is_err());
+    ///
+    /// ```
+    pub fn class(name: &str) -> Result<GremlinValue, ValidationError> {
+        let name = name.trim();
+        let is_identifier = |segment: &str| {
+            let mut chars = segment.chars();
+            chars
+                .next()
+                .filter(|c| c.is_alphabetic() || *c == '_' || *c == '$')
+                .is_some()
+                && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+        };
+        if name.split('.').all(is_identifier) {
+            Ok(GremlinValue::Class(name.to_string()))
+        } else {
+            Err(ValidationError::ClassName(name.to_string()))
+        }
+    }
+
+    /// Returns the class name if the `GremlinValue` is a `Class`.
+    #[must_use]
+    pub fn as_class(&self) -> Option<&str> {
         match self {
-            GremlinValue::Int(val) => write!(f, "{val}_i32"),
-            GremlinValue::Long(val) => write!(f, "{val}_i64"),
-            GremlinValue::String(val) => write!(f, "\"{val}\""),
-            GremlinValue::Class(val) => write!(f, "Class:{val}"),
-            GremlinValue::Double(val) => write!(f, "{val}_f64"),
-            GremlinValue::Float(val) => write!(f, "{val}_f32"),
-            GremlinValue::List(val) => {
-                write!(f, "List::[")?;
-                for i in val {
-                    write!(f, " {i},")?;
+            GremlinValue::Class(name) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the `Vertex` if the `GremlinValue` is a `Vertex`.
+    /// Returns None if `GremlinValue` enum holds another Type
+    ///
+    /// ```
+    /// # use tinkerpop_io::GremlinValue;
+    /// # use tinkerpop_io::structure::vertex::Vertex;
+    ///
+    /// let vertex = Vertex::new(1_i64, "person", None);
+    /// let gb = GremlinValue::Vertex(vertex.clone());
+    ///
+    /// assert_eq!(Some(&vertex), gb.as_vertex());
+    /// assert_eq!(None, GremlinValue::Int(1).as_vertex());
+    ///
+    /// ```
+    #[must_use]
+    pub fn as_vertex(&self) -> Option<&Vertex> {
+        self.get_ref::<Vertex>()
+    }
+
+    /// Returns a reference to the `Edge` if the `GremlinValue` is an `Edge`.
+    #[must_use]
+    pub fn as_edge(&self) -> Option<&Edge> {
+        self.get_ref::<Edge>()
+    }
+
+    /// Returns a reference to the `Path` if the `GremlinValue` is a `Path`.
+    #[must_use]
+    pub fn as_path(&self) -> Option<&Path> {
+        self.get_ref::<Path>()
+    }
+
+    /// Returns a reference to the `Property` if the `GremlinValue` is a `Property`.
+    #[must_use]
+    pub fn as_property(&self) -> Option<&Property> {
+        self.get_ref::<Property>()
+    }
+
+    /// Returns the str if the `GremlinValue` is a `String`.
+    #[must_use]
+    pub fn as_string(&self) -> Option<&str> {
+        self.get_ref::<str>()
+    }
+
+    /// Returns a reference to the map if the `GremlinValue` is a `Map`.
+    #[must_use]
+    pub fn as_map(&self) -> Option<&HashMap<MapKeys, GremlinValue>> {
+        self.get_ref::<HashMap<MapKeys, GremlinValue>>()
+    }
+
+    /// Returns the value of the `String` key if the `GremlinValue` is a `Map` holding it.
+    /// Returns None for all other variants.
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use tinkerpop_io::GremlinValue;
+    ///
+    /// let map = GremlinValue::Map(HashMap::from([("name".into(), "marko".into())]));
+    ///
+    /// assert_eq!(Some(&GremlinValue::from("marko")), map.get_map_value("name"));
+    /// assert_eq!(None, map.get_map_value("age"));
+    /// assert_eq!(None, GremlinValue::from("name").get_map_value("name"));
+    /// ```
+    #[must_use]
+    pub fn get_map_value(&self, key: &str) -> Option<&GremlinValue> {
+        match self {
+            GremlinValue::Map(map) => map.get(&MapKeys::from(key)),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the value of the `String` key if the `GremlinValue` is a
+    /// `Map` holding it. Returns None for all other variants.
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use tinkerpop_io::GremlinValue;
+    ///
+    /// let mut map = GremlinValue::Map(HashMap::from([("age".into(), 29.into())]));
+    /// *map.get_map_value_mut("age").unwrap() = 30.into();
+    ///
+    /// assert_eq!(Some(&GremlinValue::Int(30)), map.get_map_value("age"));
+    /// ```
+    #[must_use]
+    pub fn get_map_value_mut(&mut self, key: &str) -> Option<&mut GremlinValue> {
+        match self {
+            GremlinValue::Map(map) => map.get_mut(&MapKeys::from(key)),
+            _ => None,
+        }
+    }
+
+    /// Drops all entries of a `Map` whose key is not a `String` contained in `keys`.
+    /// Other variants are left unchanged.
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use tinkerpop_io::GremlinValue;
+    ///
+    /// let mut map = GremlinValue::Map(HashMap::from([
+    ///     ("name".into(), "marko".into()),
+    ///     ("age".into(), 29.into()),
+    ///     ("city".into(), "santa fe".into()),
+    ///     (1.into(), "one".into()),
+    /// ]));
+    /// map.retain_map_keys(&["name", "age"]);
+    ///
+    /// assert_eq!(
+    ///     GremlinValue::Map(HashMap::from([
+    ///         ("name".into(), "marko".into()),
+    ///         ("age".into(), 29.into()),
+    ///     ])),
+    ///     map
+    /// );
+    /// ```
+    pub fn retain_map_keys(&mut self, keys: &[&str]) {
+        if let GremlinValue::Map(map) = self {
+            map.retain(
+                |key, _| matches!(key, MapKeys::String(key) if keys.contains(&key.as_str())),
+            );
+        }
+    }
+
+    /// Returns the value as f64 if the `GremlinValue` holds one of the numeric variants
+    /// `Byte`, `Short`, `Int`, `Long`, `Float` or `Double`.
+    /// Returns None for all other variants.
+    ///
+    /// ```
+    /// # use tinkerpop_io::GremlinValue;
+    ///
+    /// assert_eq!(Some(1.0), GremlinValue::Byte(1).as_f64());
+    /// assert_eq!(Some(2.0), GremlinValue::Short(2).as_f64());
+    /// assert_eq!(Some(3.0), GremlinValue::Int(3).as_f64());
+    /// assert_eq!(Some(4.0), GremlinValue::Long(4).as_f64());
+    /// assert_eq!(Some(5.5), GremlinValue::Float(5.5).as_f64());
+    /// assert_eq!(Some(6.5), GremlinValue::Double(6.5).as_f64());
+    /// assert_eq!(None, GremlinValue::String("7".to_string()).as_f64());
+    ///
+    /// ```
+    #[must_use]
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            GremlinValue::Byte(val) => Some(f64::from(*val)),
+            GremlinValue::Short(val) => Some(f64::from(*val)),
+            GremlinValue::Int(val) => Some(f64::from(*val)),
+            GremlinValue::Long(val) => Some(*val as f64),
+            GremlinValue::Float(val) => Some(f64::from(*val)),
+            GremlinValue::Double(val) => Some(*val),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as i64 if the `GremlinValue` holds one of the numeric variants
+    /// `Byte`, `Short`, `Int`, `Long`, `Float` or `Double`.
+    /// Floating point values are only returned if they are integral and in range of i64.
+    /// Returns None for all other variants.
+    ///
+    /// ```
+    /// # use tinkerpop_io::GremlinValue;
+    ///
+    /// assert_eq!(Some(1), GremlinValue::Byte(1).as_i64());
+    /// assert_eq!(Some(2), GremlinValue::Short(2).as_i64());
+    /// assert_eq!(Some(3), GremlinValue::Int(3).as_i64());
+    /// assert_eq!(Some(4), GremlinValue::Long(4).as_i64());
+    /// assert_eq!(Some(5), GremlinValue::Float(5.0).as_i64());
+    /// assert_eq!(Some(6), GremlinValue::Double(6.0).as_i64());
+    /// assert_eq!(None, GremlinValue::Double(6.5).as_i64());
+    /// assert_eq!(None, GremlinValue::Boolean(true).as_i64());
+    ///
+    /// ```
+    #[must_use]
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            GremlinValue::Byte(val) => Some(i64::from(*val)),
+            GremlinValue::Short(val) => Some(i64::from(*val)),
+            GremlinValue::Int(val) => Some(i64::from(*val)),
+            GremlinValue::Long(val) => Some(*val),
+            GremlinValue::Float(val) => integral_f64_to_i64(f64::from(*val)),
+            GremlinValue::Double(val) => integral_f64_to_i64(*val),
+            _ => None,
+        }
+    }
+
+    /// Returns the milliseconds since the unix epoch if the `GremlinValue` is a point in time,
+    /// a `Date`, `Timestamp` or, with the `extended` feature, an `Instant`, `OffsetDateTime` or
+    /// `ZonedDateTime`. Sub millisecond precision is truncated. Returns None for all other variants.
+    ///
+    /// ```
+    /// # use tinkerpop_io::GremlinValue;
+    ///
+    /// assert_eq!(Some(1_481_750_076_295), GremlinValue::Date(1_481_750_076_295).as_epoch_millis());
+    /// assert_eq!(Some(1_481_750_076_295), GremlinValue::Timestamp(1_481_750_076_295).as_epoch_millis());
+    /// assert_eq!(None, GremlinValue::Long(1_481_750_076_295).as_epoch_millis());
+    ///
+    /// # #[cfg(all(feature = "extended", feature = "graph_son"))]
+    /// # {
+    /// use tinkerpop_io::graphson::DecodeGraphSON;
+    ///
+    /// let instant = GremlinValue::decode_v3(&serde_json::json!({
+    ///     "@type" : "gx:Instant",
+    ///     "@value" : "2016-12-14T21:14:36.295123Z"
+    /// }))
+    /// .unwrap();
+    /// assert_eq!(Some(1_481_750_076_295), instant.as_epoch_millis());
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn as_epoch_millis(&self) -> Option<i64> {
+        match self {
+            GremlinValue::Date(millis) | GremlinValue::Timestamp(millis) => Some(*millis),
+            #[cfg(feature = "extended")]
+            GremlinValue::Instant(instant) => instant
+                .secs
+                .checked_mul(1000)?
+                .checked_add(i64::from(instant.nanos / 1_000_000)),
+            #[cfg(feature = "extended")]
+            GremlinValue::OffsetDateTime(date_time) => Some(date_time.timestamp_millis()),
+            #[cfg(feature = "extended")]
+            GremlinValue::ZonedDateTime(date_time) => Some(date_time.0.timestamp_millis()),
+            _ => None,
+        }
+    }
+
+    /// Converts the value into the variant of `target` where a sensible conversion exists.
+    ///
+    /// | target | accepted variants |
+    /// |---|---|
+    /// | `Byte`, `Short`, `Int32`, `Long` | `Byte`, `Short`, `Int`, `Long`, integral `Float` and `Double`, numeric `String` |
+    /// | `Float` | `Byte`, `Short`, `Float`, numeric `String` |
+    /// | `Double` | `Byte`, `Short`, `Int`, `Long`, `Float`, `Double`, numeric `String` |
+    /// | `BigInteger` | `Byte`, `Short`, `Int`, `Long`, `BigInteger`, numeric `String` |
+    /// | `BigDecimal` | `Byte`, `Short`, `Int`, `Long`, `Float`, `Double`, `BigInteger`, `BigDecimal`, numeric `String` |
+    /// | `String` | `String`, `Byte`, `Short`, `Int`, `Long`, `Float`, `Double`, `Boolean`, `Uuid`, `BigInteger`, `BigDecimal` |
+    /// | `Boolean` | `Boolean`, `"true"` and `"false"` |
+    /// | `Uuid` | `Uuid`, `String` |
+    /// | `List`, `Set` | `List`, `Set` |
+    ///
+    /// Integral values which do not fit into the target fail with [`CoerceError::OutOfRange`],
+    /// strings which cannot be parsed with [`CoerceError::Parse`] and all other combinations,
+    /// including every target not listed above, with [`CoerceError::Unsupported`].
+    ///
+    /// ```
+    /// # use tinkerpop_io::{CoreType, GremlinValue};
+    ///
+    /// assert_eq!(GremlinValue::Long(1), GremlinValue::Int(1).coerce_to(CoreType::Long).unwrap());
+    /// assert_eq!(GremlinValue::Int(42), GremlinValue::from("42").coerce_to(CoreType::Int32).unwrap());
+    /// assert_eq!(GremlinValue::Double(2.0), GremlinValue::Long(2).coerce_to(CoreType::Double).unwrap());
+    /// assert_eq!(GremlinValue::from("1.5"), GremlinValue::Double(1.5).coerce_to(CoreType::String).unwrap());
+    ///
+    /// assert!(GremlinValue::Long(i64::MAX).coerce_to(CoreType::Int32).is_err());
+    /// assert!(GremlinValue::from("a").coerce_to(CoreType::Int32).is_err());
+    /// assert!(GremlinValue::List(vec![]).coerce_to(CoreType::Int32).is_err());
+    /// ```
+    pub fn coerce_to(self, target: CoreType) -> Result<GremlinValue, CoerceError> {
+        let unsupported = |value: &GremlinValue| CoerceError::Unsupported {
+            value: value.to_string(),
+            target,
+        };
+        match (target, self) {
+            (CoreType::Byte, value) => coerce_integral(&value, target).map(GremlinValue::Byte),
+            (CoreType::Short, value) => coerce_integral(&value, target).map(GremlinValue::Short),
+            (CoreType::Int32, value) => coerce_integral(&value, target).map(GremlinValue::Int),
+            (CoreType::Long, value) => coerce_integral(&value, target).map(GremlinValue::Long),
+            (CoreType::Float, GremlinValue::Byte(val)) => Ok(GremlinValue::Float(f32::from(val))),
+            (CoreType::Float, GremlinValue::Short(val)) => Ok(GremlinValue::Float(f32::from(val))),
+            (CoreType::Float, GremlinValue::Float(val)) => Ok(GremlinValue::Float(val)),
+            (CoreType::Float, GremlinValue::String(s)) => {
+                coerce_parse(&s, target).map(GremlinValue::Float)
+            }
+            (CoreType::Double, GremlinValue::String(s)) => {
+                coerce_parse(&s, target).map(GremlinValue::Double)
+            }
+            (CoreType::Double, value) => value
+                .as_f64()
+                .map(GremlinValue::Double)
+                .ok_or_else(|| unsupported(&value)),
+            (CoreType::BigInteger, GremlinValue::BigInteger(val)) => {
+                Ok(GremlinValue::BigInteger(val))
+            }
+            (CoreType::BigInteger, GremlinValue::String(s)) => {
+                coerce_parse(&s, target).map(GremlinValue::BigInteger)
+            }
+            (
+                CoreType::BigInteger,
+                value @ (GremlinValue::Byte(_)
+                | GremlinValue::Short(_)
+                | GremlinValue::Int(_)
+                | GremlinValue::Long(_)),
+            ) => value
+                .as_i64()
+                .map(|val| GremlinValue::BigInteger(BigInt::from(val)))
+                .ok_or_else(|| unsupported(&value)),
+            (CoreType::BigDecimal, GremlinValue::BigDecimal(val)) => {
+                Ok(GremlinValue::BigDecimal(val))
+            }
+            (CoreType::BigDecimal, GremlinValue::BigInteger(val)) => {
+                Ok(GremlinValue::BigDecimal(BigDecimal::new(val, 0)))
+            }
+            (CoreType::BigDecimal, GremlinValue::String(s)) => {
+                coerce_parse(&s, target).map(GremlinValue::BigDecimal)
+            }
+            (
+                CoreType::BigDecimal,
+                value @ (GremlinValue::Byte(_)
+                | GremlinValue::Short(_)
+                | GremlinValue::Int(_)
+                | GremlinValue::Long(_)),
+            ) => value
+                .as_i64()
+                .map(|val| GremlinValue::BigDecimal(BigDecimal::from(val)))
+                .ok_or_else(|| unsupported(&value)),
+            (CoreType::BigDecimal, value @ (GremlinValue::Float(_) | GremlinValue::Double(_))) => {
+                let float = value.as_f64().ok_or_else(|| unsupported(&value))?;
+                coerce_parse(&float.to_string(), target).map(GremlinValue::BigDecimal)
+            }
+            (CoreType::String, GremlinValue::String(s)) => Ok(GremlinValue::String(s)),
+            (CoreType::String, GremlinValue::Byte(val)) => {
+                Ok(GremlinValue::String(val.to_string()))
+            }
+            (CoreType::String, GremlinValue::Short(val)) => {
+                Ok(GremlinValue::String(val.to_string()))
+            }
+            (CoreType::String, GremlinValue::Int(val)) => Ok(GremlinValue::String(val.to_string())),
+            (CoreType::String, GremlinValue::Long(val)) => {
+                Ok(GremlinValue::String(val.to_string()))
+            }
+            (CoreType::String, GremlinValue::Float(val)) => {
+                Ok(GremlinValue::String(val.to_string()))
+            }
+            (CoreType::String, GremlinValue::Double(val)) => {
+                Ok(GremlinValue::String(val.to_string()))
+            }
+            (CoreType::String, GremlinValue::Boolean(val)) => {
+                Ok(GremlinValue::String(val.to_string()))
+            }
+            (CoreType::String, GremlinValue::Uuid(val)) => {
+                Ok(GremlinValue::String(val.to_string()))
+            }
+            (CoreType::String, GremlinValue::BigInteger(val)) => {
+                Ok(GremlinValue::String(val.to_string()))
+            }
+            (CoreType::String, GremlinValue::BigDecimal(val)) => {
+                Ok(GremlinValue::String(val.to_string()))
+            }
+            (CoreType::Boolean, GremlinValue::Boolean(val)) => Ok(GremlinValue::Boolean(val)),
+            (CoreType::Boolean, GremlinValue::String(s)) => {
+                coerce_parse(&s, target).map(GremlinValue::Boolean)
+            }
+            (CoreType::Uuid, GremlinValue::Uuid(val)) => Ok(GremlinValue::Uuid(val)),
+            (CoreType::Uuid, GremlinValue::String(s)) => {
+                coerce_parse(&s, target).map(GremlinValue::Uuid)
+            }
+            (CoreType::List, GremlinValue::List(list)) => Ok(GremlinValue::List(list)),
+            (CoreType::List, GremlinValue::Set(set)) => {
+                Ok(GremlinValue::List(set.into_iter().collect()))
+            }
+            (CoreType::Set, GremlinValue::Set(set)) => Ok(GremlinValue::Set(set)),
+            (CoreType::Set, GremlinValue::List(list)) => Ok(GremlinValue::Set(Set::new(list))),
+            (_, value) => Err(unsupported(&value)),
+        }
+    }
+
+    /// Resolves a nested value by a path of `.` separated keys and `[index]` segments.
+    /// Keys are looked up in `Map` entries, indices in `List` and `Set` values.
+    /// `Vertex` values resolve `properties.<key>` to the vertex properties with that label,
+    /// `VertexProperty` values resolve `value` and `properties.<key>`, `Property` values resolve `value`.
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use tinkerpop_io::GremlinValue;
+    ///
+    /// let gb = GremlinValue::Map(HashMap::from([(
+    ///     "names".into(),
+    ///     GremlinValue::List(vec!["marko".into(), "josh".into()]),
+    /// )]));
+    ///
+    /// assert_eq!(Some(&GremlinValue::from("josh")), gb.get_path("names[1]"));
+    /// assert_eq!(None, gb.get_path("names[2]"));
+    /// assert_eq!(None, gb.get_path("ages"));
+    /// ```
+    #[must_use]
+    pub fn get_path(&self, path: &str) -> Option<&GremlinValue> {
+        let mut node = PathNode::Value(self);
+        for segment in path.split('.') {
+            let (key, mut indices) = match segment.find('[') {
+                Some(start) => segment.split_at(start),
+                None => (segment, ""),
+            };
+            if !key.is_empty() {
+                node = node.key(key)?;
+            }
+            while let Some(rest) = indices.strip_prefix('[') {
+                let end = rest.find(']')?;
+                node = node.index(rest[..end].parse().ok()?)?;
+                indices = &rest[end + 1..];
+            }
+            if !indices.is_empty() {
+                return None;
+            }
+        }
+        match node {
+            PathNode::Value(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Recursively unwraps nested `List`, `Set` and `Traverser` values into a flat Vec of leaf values.
+    /// A `Traverser` is expanded into `bulk` copies of its flattened value.
+    /// All other variants, including `Map`, are treated as leaves and kept as they are.
+    ///
+    /// ```
+    /// # use tinkerpop_io::GremlinValue;
+    /// # use tinkerpop_io::structure::traverser::Traverser;
+    ///
+    /// let gb = GremlinValue::List(vec![
+    ///     GremlinValue::List(vec![GremlinValue::Int(1)]),
+    ///     GremlinValue::Traverser(Traverser::with_bulk(2, GremlinValue::Int(3)).unwrap()),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     vec![GremlinValue::Int(1), GremlinValue::Int(3), GremlinValue::Int(3)],
+    ///     gb.flatten_lists()
+    /// );
+    /// assert_eq!(vec![GremlinValue::Int(1)], GremlinValue::Int(1).flatten_lists());
+    ///
+    /// ```
+    #[must_use]
+    pub fn flatten_lists(self) -> Vec<GremlinValue> {
+        let mut flat = Vec::new();
+        self.flatten_into(&mut flat);
+        flat
+    }
+
+    fn flatten_into(self, flat: &mut Vec<GremlinValue>) {
+        match self {
+            GremlinValue::List(list) => list.into_iter().for_each(|val| val.flatten_into(flat)),
+            GremlinValue::Set(set) => set.into_iter().for_each(|val| val.flatten_into(flat)),
+            GremlinValue::Traverser(traverser) => {
+                let values = traverser.value.flatten_lists();
+                for _ in 0..traverser.bulk {
+                    flat.extend(values.iter().cloned());
                 }
-                write!(f, "]")
             }
-            GremlinValue::Set(val) => write!(f, "Set::{val}"),
-            GremlinValue::Map(val) => {
-                write!(f, "Map::[")?;
-                for (key, value) in val {
-                    writeln!(f, "{{{key}:{value}}},")?;
+            leaf => flat.push(leaf),
+        }
+    }
+
+    /// Counts the elements of a collection like the `count()` step with the given [`Scope`].
+    /// `Scope::Global` counts the top-level elements of a `List`, `Set` or `BulkSet`, while
+    /// `Scope::Local` sums the number of elements within each of them, where a nested `List`,
+    /// `Set`, `BulkSet` or `Map` counts its elements and every other value counts as one.
+    /// Returns None if the value is not a `List`, `Set` or `BulkSet` or if the count overflows
+    /// an `i64`.
+    ///
+    /// ```
+    /// # use tinkerpop_io::GremlinValue;
+    /// # use tinkerpop_io::structure::bulkset::BulkSet;
+    /// # use tinkerpop_io::structure::enums::Scope;
+    /// let lists = GremlinValue::List(vec![
+    ///     GremlinValue::List(vec![1.into(), 2.into()]),
+    ///     GremlinValue::List(vec![3.into(), 4.into(), 5.into()]),
+    ///     GremlinValue::Int(6),
+    /// ]);
+    ///
+    /// assert_eq!(Some(3), lists.count(Scope::Global));
+    /// assert_eq!(Some(6), lists.count(Scope::Local));
+    /// assert_eq!(None, GremlinValue::Int(1).count(Scope::Global));
+    ///
+    /// let bulk_set = BulkSet::new(vec![(1.into(), i64::MAX), (2.into(), 1)]);
+    /// let bulk_set = GremlinValue::BulkSet(bulk_set);
+    /// assert_eq!(None, bulk_set.count(Scope::Global));
+    /// assert_eq!(None, GremlinValue::List(vec![bulk_set]).count(Scope::Local));
+    /// ```
+    #[must_use]
+    pub fn count(&self, scope: Scope) -> Option<i64> {
+        let elements: Vec<(&GremlinValue, i64)> = match self {
+            GremlinValue::List(list) => list.iter().map(|val| (val, 1)).collect(),
+            GremlinValue::Set(set) => set.iter().map(|val| (val, 1)).collect(),
+            GremlinValue::BulkSet(bulk_set) => {
+                bulk_set.iter().map(|(val, bulk)| (val, *bulk)).collect()
+            }
+            _ => return None,
+        };
+        elements.iter().try_fold(0_i64, |count, (val, bulk)| {
+            let len = match scope {
+                Scope::Global => Some(1),
+                Scope::Local => local_len(val),
+            };
+            count.checked_add(bulk.checked_mul(len?)?)
+        })
+    }
+
+    /// Rewrites numbers into a canonical type so that values coming from different producers
+    /// compare equal, e.g. `Int(5)` from one server and `Long(5)` from another.
+    ///
+    /// The policy is:
+    /// * `Byte`, `Short`, `Int`, `Long` and `BigInteger` become `Int` if they fit into an `i32`,
+    ///   otherwise `Long` if they fit into an `i64`, otherwise they stay a `BigInteger`
+    /// * `Float` is widened to `Double`
+    /// * `Long` map keys which fit into an `i32` become `Int` keys, if the map already holds the
+    ///   equal `Int` key, the entry of the `Int` key is kept and the narrowed entry is dropped
+    /// * `BigDecimal` and all non numeric values are left untouched
+    ///
+    /// Lists, sets, map values, bulk sets and traversers are canonicalized recursively. Set items
+    /// which become equal are deduplicated like in [`GremlinValue::set_from`], bulk set items
+    /// which become equal are merged by adding up their bulks.
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use tinkerpop_io::GremlinValue;
+    /// # use tinkerpop_io::structure::map::MapKeys;
+    /// let mixed = GremlinValue::List(vec![
+    ///     GremlinValue::Int(5),
+    ///     GremlinValue::Long(5),
+    ///     GremlinValue::Long(3_000_000_000),
+    ///     GremlinValue::Short(7),
+    ///     GremlinValue::Float(0.5),
+    /// ]);
+    /// assert_eq!(
+    ///     GremlinValue::List(vec![
+    ///         GremlinValue::Int(5),
+    ///         GremlinValue::Int(5),
+    ///         GremlinValue::Long(3_000_000_000),
+    ///         GremlinValue::Int(7),
+    ///         GremlinValue::Double(0.5),
+    ///     ]),
+    ///     mixed.canonicalize_numbers()
+    /// );
+    ///
+    /// let set = GremlinValue::set_from([GremlinValue::Int(5), GremlinValue::Long(5)]);
+    /// assert_eq!(GremlinValue::set_from([GremlinValue::Int(5)]), set.canonicalize_numbers());
+    ///
+    /// let map = GremlinValue::Map(HashMap::from([
+    ///     (MapKeys::Long(5), "long".into()),
+    ///     (MapKeys::Int(5), "int".into()),
+    /// ]));
+    /// assert_eq!(
+    ///     GremlinValue::Map(HashMap::from([(MapKeys::Int(5), "int".into())])),
+    ///     map.canonicalize_numbers()
+    /// );
+    /// ```
+    #[must_use]
+    pub fn canonicalize_numbers(self) -> GremlinValue {
+        match self {
+            GremlinValue::Byte(val) => GremlinValue::Int(val.into()),
+            GremlinValue::Short(val) => GremlinValue::Int(val.into()),
+            GremlinValue::Long(val) => i32::try_from(val)
+                .map(GremlinValue::Int)
+                .unwrap_or(GremlinValue::Long(val)),
+            GremlinValue::BigInteger(val) => {
+                if let Ok(int) = i32::try_from(&val) {
+                    GremlinValue::Int(int)
+                } else if let Ok(long) = i64::try_from(&val) {
+                    GremlinValue::Long(long)
+                } else {
+                    GremlinValue::BigInteger(val)
                 }
-                write!(f, "]")
             }
-            GremlinValue::Uuid(val) => write!(f, "Uuid::{val}"),
-            GremlinValue::Edge(val) => write!(f, "Edge::{val}"),
-            GremlinValue::Path(val) => write!(f, "Path::{val}"),
-            GremlinValue::Property(val) => write!(f, "Property::{val}"),
-            GremlinValue::Graph(val) => write!(f, "Graph::{val}"),
-            GremlinValue::Vertex(val) => write!(f, "Vertex::{val}"),
-            GremlinValue::VertexProperty(val) => write!(f, "VertexProperty::{val}"),
-            GremlinValue::Barrier(val) => write!(f, "Barrier::{val}"),
-            GremlinValue::Binding(val) => write!(f, "Binding::{val}"),
-            GremlinValue::Bytecode(val) => write!(f, "Bytecode::{val}"),
-            GremlinValue::Cardinality(val) => write!(f, "Cardinality::{val}"),
-            GremlinValue::Column(val) => write!(f, "Column::{val}"),
-            GremlinValue::Direction(val) => write!(f, "Direction::{val}"),
-            GremlinValue::Operator(val) => write!(f, "Operator::{val}"),
-            GremlinValue::Order(val) => write!(f, "Order::{val}"),
-            GremlinValue::Pick(val) => write!(f, "Pick::{val}"),
-            GremlinValue::Pop(val) => write!(f, "Pop::{val}"),
-            GremlinValue::Lambda(val) => write!(f, "Lambda::{val}"),
-            GremlinValue::P(val) => write!(f, "P::{val}"),
-            GremlinValue::Scope(val) => write!(f, "Scope::{val}"),
-            GremlinValue::T(val) => write!(f, "T::{val}"),
-            GremlinValue::BigDecimal(val) => write!(f, "BigDecimal::{val}"),
-            GremlinValue::BigInteger(val) => write!(f, "BigInteger::{val}"),
-            GremlinValue::Traverser(val) => write!(f, "Traverser::{val}"),
-            GremlinValue::Byte(val) => write!(f, "{val}_u8"),
-            GremlinValue::ByteBuffer(val) => write!(f, "ByteBuffer::{val}_u8"),
-            GremlinValue::Short(val) => write!(f, "{val}_i16"),
-            GremlinValue::Boolean(val) => write!(f, "{val}"),
-            GremlinValue::TextP(val) => write!(f, "TextP::{val}"),
-            GremlinValue::TraversalStrategy(val) => write!(f, "TraversalStrategy::{val}"),
-            GremlinValue::BulkSet(val) => write!(f, "BulkSet::{val}"),
-            GremlinValue::Metrics(val) => write!(f, "{val}"),
-            GremlinValue::TraversalMetrics(val) => write!(f, "{val}"),
-            GremlinValue::Merge(val) => write!(f, "Merge::{val}"),
-            GremlinValue::UnspecifiedNullObject => write!(f, "UnspecifiedNullObject"),
-            GremlinValue::Date(val) => write!(f, "Date::{val}"),
-            GremlinValue::Timestamp(val) => write!(f, "Timestamp::{val}"),
-            #[cfg(feature = "extended")]
+            GremlinValue::Float(val) => GremlinValue::Double(val.into()),
+            GremlinValue::List(list) => GremlinValue::List(
+                list.into_iter()
+                    .map(GremlinValue::canonicalize_numbers)
+                    .collect(),
+            ),
+            GremlinValue::Set(set) => {
+                GremlinValue::set_from(set.into_iter().map(GremlinValue::canonicalize_numbers))
+            }
+            GremlinValue::Map(map) => {
+                let mut canonical = HashMap::with_capacity(map.len());
+                let mut narrowed = Vec::new();
+                for (key, value) in map {
+                    let value = value.canonicalize_numbers();
+                    match key {
+                        MapKeys::Long(long) => match i32::try_from(long) {
+                            Ok(int) => narrowed.push((MapKeys::Int(int), value)),
+                            Err(_) => {
+                                canonical.insert(MapKeys::Long(long), value);
+                            }
+                        },
+                        key => {
+                            canonical.insert(key, value);
+                        }
+                    }
+                }
+                for (key, value) in narrowed {
+                    canonical.entry(key).or_insert(value);
+                }
+                GremlinValue::Map(canonical)
+            }
+            GremlinValue::BulkSet(bulk_set) => {
+                let mut merged: Vec<(GremlinValue, i64)> = Vec::new();
+                for (value, bulk) in bulk_set.0 {
+                    let value = value.canonicalize_numbers();
+                    match merged.iter_mut().find(|(item, _)| *item == value) {
+                        Some((_, merged_bulk)) => *merged_bulk = merged_bulk.saturating_add(bulk),
+                        None => merged.push((value, bulk)),
+                    }
+                }
+                GremlinValue::BulkSet(BulkSet::new(merged))
+            }
+            GremlinValue::Traverser(traverser) => GremlinValue::Traverser(Traverser {
+                bulk: traverser.bulk,
+                value: Box::new(traverser.value.canonicalize_numbers()),
+            }),
+            value => value,
+        }
+    }
+
+    /// Builds a `Set` from the values, keeping only the first of equal values in iteration order.
+    ///
+    /// ```
+    /// # use tinkerpop_io::GremlinValue;
+    /// # use tinkerpop_io::structure::set::Set;
+    /// let set = GremlinValue::set_from(vec![1.into(), "a".into(), 1.into(), 2.into(), "a".into()]);
+    /// assert_eq!(GremlinValue::Set(Set::new(vec![1.into(), "a".into(), 2.into()])), set);
+    /// ```
+    #[must_use]
+    pub fn set_from(iter: impl IntoIterator<Item = GremlinValue>) -> GremlinValue {
+        let mut items: Vec<GremlinValue> = Vec::new();
+        for value in iter {
+            if !items.contains(&value) {
+                items.push(value);
+            }
+        }
+        GremlinValue::Set(Set::new(items))
+    }
+
+    /// Builds a `Map` from key value pairs, later entries replace earlier ones with an equal key.
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use tinkerpop_io::GremlinValue;
+    /// let map = GremlinValue::map_from([("name", GremlinValue::from("marko")), ("age", 29.into())]);
+    /// assert_eq!(
+    ///     GremlinValue::Map(HashMap::from([
+    ///         ("name".into(), "marko".into()),
+    ///         ("age".into(), 29.into()),
+    ///     ])),
+    ///     map
+    /// );
+    /// ```
+    #[must_use]
+    pub fn map_from<K, V>(entries: impl IntoIterator<Item = (K, V)>) -> GremlinValue
+    where
+        K: Into<MapKeys>,
+        V: Into<GremlinValue>,
+    {
+        GremlinValue::Map(
+            entries
+                .into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+        )
+    }
+
+    /// Appends `value` to a `List`. An `UnspecifiedNullObject` is promoted to an empty list and a
+    /// scalar, like `Int` or `String`, to a list holding the scalar before appending.
+    ///
+    /// Returns [`TypeError::NotAList`] for all other variants, e.g. `Set`, `Map` or graph elements.
+    ///
+    /// ```
+    /// # use tinkerpop_io::GremlinValue;
+    /// let mut list = GremlinValue::List(vec![1.into()]);
+    /// list.push(2).unwrap();
+    /// assert_eq!(GremlinValue::List(vec![1.into(), 2.into()]), list);
+    ///
+    /// let mut null = GremlinValue::UnspecifiedNullObject;
+    /// null.push("a").unwrap();
+    /// assert_eq!(GremlinValue::List(vec!["a".into()]), null);
+    ///
+    /// let mut scalar = GremlinValue::Int(1);
+    /// scalar.extend([2, 3]).unwrap();
+    /// assert_eq!(GremlinValue::List(vec![1.into(), 2.into(), 3.into()]), scalar);
+    ///
+    /// assert!(GremlinValue::Map(Default::default()).push(1).is_err());
+    /// ```
+    pub fn push(&mut self, value: impl Into<GremlinValue>) -> Result<(), TypeError> {
+        self.promote_to_list()?.push(value.into());
+        Ok(())
+    }
+
+    /// Appends all values of `iter` to a `List`, promoting the value like [`GremlinValue::push`].
+    pub fn extend<I>(&mut self, iter: I) -> Result<(), TypeError>
+    where
+        I: IntoIterator,
+        I::Item: Into<GremlinValue>,
+    {
+        self.promote_to_list()?
+            .extend(iter.into_iter().map(Into::into));
+        Ok(())
+    }
+
+    fn promote_to_list(&mut self) -> Result<&mut Vec<GremlinValue>, TypeError> {
+        let promoted = match &mut *self {
+            GremlinValue::List(_) => None,
+            GremlinValue::UnspecifiedNullObject => Some(Vec::new()),
+            scalar if scalar.is_scalar() => Some(vec![std::mem::take(scalar)]),
+            other => return Err(TypeError::NotAList(other.to_string())),
+        };
+        if let Some(list) = promoted {
+            *self = GremlinValue::List(list);
+        }
+        match self {
+            GremlinValue::List(list) => Ok(list),
+            _ => unreachable!("value was promoted to a List"),
+        }
+    }
+
+    fn is_scalar(&self) -> bool {
+        matches!(
+            self,
+            GremlinValue::Int(_)
+                | GremlinValue::Long(_)
+                | GremlinValue::String(_)
+                | GremlinValue::Date(_)
+                | GremlinValue::Timestamp(_)
+                | GremlinValue::Class(_)
+                | GremlinValue::Double(_)
+                | GremlinValue::Float(_)
+                | GremlinValue::Uuid(_)
+                | GremlinValue::BigDecimal(_)
+                | GremlinValue::BigInteger(_)
+                | GremlinValue::Byte(_)
+                | GremlinValue::Short(_)
+                | GremlinValue::Boolean(_)
+                | GremlinValue::Char(_)
+        )
+    }
+
+    /// Applies `f` in place to every `String` in the value tree, including map keys, path labels,
+    /// labels of graph elements and keys of properties. Graphs, metrics, bytecode arguments,
+    /// bindings, predicates and strategy configurations are visited as well.
+    ///
+    /// Strings that name rather than carry data are left untouched: element ids, the ids, names,
+    /// count and annotation keys of metrics, bytecode step and source names, binding keys,
+    /// predicate names, strategy classes and the language and script of a `Lambda`.
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use tinkerpop_io::GremlinValue;
+    /// # use tinkerpop_io::structure::bytecode::Bytecode;
+    /// # use tinkerpop_io::structure::lambda::Lambda;
+    /// # use tinkerpop_io::structure::vertex::Vertex;
+    /// # use tinkerpop_io::structure::vertex_property::VertexProperty;
+    /// let mut map = GremlinValue::Map(HashMap::from([(
+    ///     "names".into(),
+    ///     GremlinValue::List(vec!["marko".into(), 1.into()]),
+    /// )]));
+    /// map.map_strings(|s| *s = s.to_uppercase());
+    /// assert_eq!(
+    ///     GremlinValue::Map(HashMap::from([(
+    ///         "NAMES".into(),
+    ///         GremlinValue::List(vec!["MARKO".into(), 1.into()]),
+    ///     )])),
+    ///     map
+    /// );
+    ///
+    /// let name = |key, value| VertexProperty::new(0_i64, key, value, None, None);
+    /// let mut vertex = GremlinValue::Vertex(Vertex::new(1, "person", Some(vec![name("name", "marko")])));
+    /// vertex.map_strings(|s| *s = s.to_uppercase());
+    /// assert_eq!(
+    ///     GremlinValue::Vertex(Vertex::new(1, "PERSON", Some(vec![name("NAME", "MARKO")]))),
+    ///     vertex
+    /// );
+    ///
+    /// let bytecode = |label| {
+    ///     let mut bytecode = Bytecode::new();
+    ///     bytecode.push_new_step("hasLabel", vec![label]);
+    ///     bytecode.push_new_step("map", vec![Lambda::new("it.get()").into()]);
+    ///     GremlinValue::Bytecode(bytecode)
+    /// };
+    /// let mut traversal = bytecode("person".into());
+    /// traversal.map_strings(|s| *s = s.to_uppercase());
+    /// assert_eq!(bytecode("PERSON".into()), traversal);
+    /// ```
+    pub fn map_strings(&mut self, f: impl Fn(&mut String)) {
+        self.map_strings_dyn(&f);
+    }
+
+    fn map_strings_dyn(&mut self, f: &dyn Fn(&mut String)) {
+        match self {
+            GremlinValue::String(s) => f(s),
+            GremlinValue::List(list) => list.iter_mut().for_each(|val| val.map_strings_dyn(f)),
+            GremlinValue::Set(set) => set.iter_mut().for_each(|val| val.map_strings_dyn(f)),
+            GremlinValue::Map(map) => {
+                *map = std::mem::take(map)
+                    .into_iter()
+                    .map(|(mut key, mut value)| {
+                        if let MapKeys::String(key) = &mut key {
+                            f(key);
+                        }
+                        value.map_strings_dyn(f);
+                        (key, value)
+                    })
+                    .collect();
+            }
+            GremlinValue::BulkSet(bulk_set) => bulk_set
+                .iter_mut()
+                .for_each(|(val, _)| val.map_strings_dyn(f)),
+            GremlinValue::Traverser(traverser) => traverser.value.map_strings_dyn(f),
+            GremlinValue::Tree(tree) => tree_map_strings(tree, f),
+            GremlinValue::Path(path) => {
+                path.labels
+                    .iter_mut()
+                    .for_each(|labels| labels.iter_mut().for_each(f));
+                path.objects
+                    .iter_mut()
+                    .for_each(|val| val.map_strings_dyn(f));
+            }
+            GremlinValue::Vertex(vertex) => vertex_map_strings(vertex, f),
+            GremlinValue::VertexProperty(vertex_property) => {
+                vertex_property_map_strings(vertex_property, f);
+            }
+            GremlinValue::Edge(edge) => edge_map_strings(edge, f),
+            GremlinValue::Property(property) => property_map_strings(property, f),
+            GremlinValue::Graph(graph) => {
+                for vertex in &mut graph.vertices {
+                    vertex_map_strings(vertex, f);
+                }
+                for edge in &mut graph.edges {
+                    graph_edge_map_strings(edge, f);
+                }
+            }
+            GremlinValue::Metrics(metrics) => metrics_map_strings(metrics, f),
+            GremlinValue::TraversalMetrics(traversal_metrics) => traversal_metrics
+                .metrics
+                .iter_mut()
+                .for_each(|metrics| metrics_map_strings(metrics, f)),
+            GremlinValue::Bytecode(bytecode) => {
+                let steps = bytecode.steps.iter_mut().map(|step| &mut step.values);
+                let sources = bytecode.sources.iter_mut().map(|source| &mut source.values);
+                steps
+                    .chain(sources)
+                    .flatten()
+                    .for_each(|val| val.map_strings_dyn(f));
+            }
+            GremlinValue::Binding(binding) => binding.value.map_strings_dyn(f),
+            GremlinValue::P(p) => p.value.iter_mut().for_each(|val| val.map_strings_dyn(f)),
+            GremlinValue::TextP(text_p) => text_p
+                .value
+                .iter_mut()
+                .for_each(|val| val.map_strings_dyn(f)),
+            GremlinValue::TraversalStrategy(strategy) => strategy
+                .configuration
+                .values_mut()
+                .for_each(|val| val.map_strings_dyn(f)),
+            _ => {}
+        }
+    }
+
+    /// Iterates the vertices of a `Graph`, yields nothing for all other variants.
+    pub fn vertices(&self) -> impl Iterator<Item = &Vertex> {
+        match self {
+            GremlinValue::Graph(graph) => graph.vertices().iter(),
+            _ => [].iter(),
+        }
+    }
+
+    /// Iterates the edges of a `Graph`, yields nothing for all other variants.
+    pub fn edges(&self) -> impl Iterator<Item = &GraphEdge> {
+        match self {
+            GremlinValue::Graph(graph) => graph.edges().iter(),
+            _ => [].iter(),
+        }
+    }
+
+    /// Encodes the value to GraphBinary with map entries ordered by their encoded key,
+    /// see [`binary::to_bytes_deterministic`].
+    #[cfg(feature = "graph_binary")]
+    pub fn to_bytes_sorted(&self) -> Result<Vec<u8>, error::EncodeError> {
+        binary::to_bytes_deterministic(self)
+    }
+
+    /// Returns the maximum nesting level of the value, a scalar has depth 1. Lists, sets, map
+    /// values, paths, trees, traversers, bulk sets, predicates and graph elements with their
+    /// properties each add a level, parents of elements are not followed.
+    ///
+    /// ```
+    /// # use tinkerpop_io::GremlinValue;
+    /// assert_eq!(1, GremlinValue::Int(1).depth());
+    ///
+    /// let three_levels = GremlinValue::List(vec![GremlinValue::List(vec![GremlinValue::List(vec![])])]);
+    /// assert_eq!(3, three_levels.depth());
+    ///
+    /// let nested = GremlinValue::List(vec![
+    ///     1.into(),
+    ///     GremlinValue::List(vec![GremlinValue::List(vec![2.into()])]),
+    /// ]);
+    /// assert_eq!(4, nested.depth());
+    /// ```
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        let mut depth = 0;
+        self.walk(1, &mut |level| depth = depth.max(level));
+        depth
+    }
+
+    /// Returns the number of nodes in the value, counting containers, graph elements, their
+    /// properties and scalars, following the same structure as [`GremlinValue::depth`].
+    ///
+    /// ```
+    /// # use tinkerpop_io::GremlinValue;
+    /// assert_eq!(1, GremlinValue::Int(1).node_count());
+    ///
+    /// let nested = GremlinValue::List(vec![
+    ///     1.into(),
+    ///     GremlinValue::List(vec![GremlinValue::List(vec![2.into()])]),
+    /// ]);
+    /// assert_eq!(5, nested.node_count());
+    /// ```
+    #[must_use]
+    pub fn node_count(&self) -> usize {
+        let mut count = 0;
+        self.walk(1, &mut |_| count += 1);
+        count
+    }
+
+    fn walk(&self, level: usize, visit: &mut dyn FnMut(usize)) {
+        visit(level);
+        let next = level + 1;
+        match self {
+            GremlinValue::List(list) => list.iter().for_each(|val| val.walk(next, visit)),
+            GremlinValue::Set(set) => set.iter().for_each(|val| val.walk(next, visit)),
+            GremlinValue::Map(map) => map.values().for_each(|val| val.walk(next, visit)),
+            GremlinValue::Path(path) => path.objects.iter().for_each(|val| val.walk(next, visit)),
+            GremlinValue::P(p) => p.value.iter().for_each(|val| val.walk(next, visit)),
+            GremlinValue::BulkSet(bulk_set) => {
+                bulk_set.iter().for_each(|(val, _)| val.walk(next, visit));
+            }
+            GremlinValue::Traverser(traverser) => traverser.value.walk(next, visit),
+            GremlinValue::Tree(tree) => tree_walk(tree, next, visit),
+            GremlinValue::Vertex(vertex) => vertex_properties_walk(vertex, next, visit),
+            GremlinValue::VertexProperty(vertex_property) => {
+                vertex_property_children_walk(vertex_property, next, visit);
+            }
+            GremlinValue::Edge(edge) => edge
+                .properties
+                .iter()
+                .flatten()
+                .for_each(|property| property_walk(property, next, visit)),
+            GremlinValue::Property(property) => property.value.walk(next, visit),
+            GremlinValue::Graph(graph) => {
+                for vertex in &graph.vertices {
+                    visit(next);
+                    vertex_properties_walk(vertex, next + 1, visit);
+                }
+                for edge in &graph.edges {
+                    visit(next);
+                    for property in &edge.properties {
+                        property_walk(property, next + 1, visit);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Hashes the value from a canonical byte form so that logically equal values hash equal,
+    /// which allows using the hash as a dedup key although `GremlinValue` is not `Hash`.
+    ///
+    /// Every value is prefixed with its GraphBinary type code, integers are written as big-endian
+    /// fixed-width bytes and strings, byte buffers and sequences are length-prefixed, so the hash
+    /// is the same across platforms and Rust releases. Entries of maps, sets, bulk sets, trees and
+    /// strategy configurations as well as the vertices and edges of a graph are hashed independent
+    /// of their order. Floating point values are hashed by their bit pattern with `-0.0` hashed as
+    /// `0.0` and all NaNs alike, `BigDecimal`s in normalized form. Extended and custom types are
+    /// hashed by their `Display` form.
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use tinkerpop_io::GremlinValue;
+    /// let mut left = HashMap::new();
+    /// let mut right = HashMap::new();
+    /// for i in 0..16 {
+    ///     left.insert(i.to_string().into(), GremlinValue::Int(i));
+    ///     right.insert((15 - i).to_string().into(), GremlinValue::Int(15 - i));
+    /// }
+    ///
+    /// assert_eq!(
+    ///     GremlinValue::Map(left).stable_hash(),
+    ///     GremlinValue::Map(right).stable_hash()
+    /// );
+    /// assert_ne!(GremlinValue::Int(1).stable_hash(), GremlinValue::Long(1).stable_hash());
+    /// assert_eq!(
+    ///     GremlinValue::Double(0.0).stable_hash(),
+    ///     GremlinValue::Double(-0.0).stable_hash()
+    /// );
+    ///
+    /// // pinned, the hash must not change between releases or platforms
+    /// let list = GremlinValue::List(vec![1.into(), "marko".into()]);
+    /// assert_eq!(0x7851_6b48_8944_ca92, list.stable_hash());
+    /// ```
+    #[must_use]
+    pub fn stable_hash(&self) -> u64 {
+        let mut hasher = StableHasher::default();
+        self.stable_hash_into(&mut hasher);
+        hasher.finish()
+    }
+
+    fn stable_hash_into(&self, h: &mut StableHasher) {
+        h.write_tag(self.core_type().into());
+        match self {
+            GremlinValue::Int(val) => h.write_int(i64::from(*val)),
+            GremlinValue::Long(val) | GremlinValue::Date(val) | GremlinValue::Timestamp(val) => {
+                h.write_int(*val);
+            }
+            GremlinValue::Short(val) => h.write_int(i64::from(*val)),
+            GremlinValue::Byte(val) => h.write_int(i64::from(*val)),
+            GremlinValue::Boolean(val) => h.write_tag(u8::from(*val)),
+            GremlinValue::Char(val) => h.write_int(i64::from(u32::from(*val))),
+            GremlinValue::String(val) | GremlinValue::Class(val) => h.write_string(val),
+            GremlinValue::Float(val) => h.write(&normalized_f32(*val).to_bits().to_be_bytes()),
+            GremlinValue::Double(val) => h.write(&normalized_f64(*val).to_bits().to_be_bytes()),
+            GremlinValue::Uuid(val) => h.write(val.as_bytes()),
+            GremlinValue::BigInteger(val) => h.write_bytes(&val.to_signed_bytes_be()),
+            GremlinValue::BigDecimal(val) => {
+                let (unscaled, scale) = val.normalized().as_bigint_and_exponent();
+                h.write_bytes(&unscaled.to_signed_bytes_be());
+                h.write_int(scale);
+            }
+            GremlinValue::ByteBuffer(val) => h.write_bytes(val.as_bytes()),
+            GremlinValue::Barrier(val) => h.write_string(val.as_str()),
+            GremlinValue::Cardinality(val) => h.write_string(val.as_str()),
+            GremlinValue::Column(val) => h.write_string(val.as_str()),
+            GremlinValue::Direction(val) => h.write_string(val.as_str()),
+            GremlinValue::Operator(val) => h.write_string(val.as_str()),
+            GremlinValue::Order(val) => h.write_string(val.as_str()),
+            GremlinValue::Pick(val) => h.write_string(val.as_str()),
+            GremlinValue::Pop(val) => h.write_string(val.as_str()),
+            GremlinValue::Scope(val) => h.write_string(val.as_str()),
+            GremlinValue::T(val) => h.write_string(val.as_str()),
+            GremlinValue::Merge(val) => h.write_string(val.as_str()),
+            GremlinValue::List(list) => sequence_stable_hash(list, h),
+            GremlinValue::Set(set) => unordered_hash(h, set.iter().map(GremlinValue::stable_hash)),
+            GremlinValue::Map(map) => unordered_hash(
+                h,
+                map.iter().map(|(key, value)| {
+                    let mut entry = StableHasher::default();
+                    map_key_stable_hash(key, &mut entry);
+                    value.stable_hash_into(&mut entry);
+                    entry.finish()
+                }),
+            ),
+            GremlinValue::BulkSet(bulk_set) => unordered_hash(
+                h,
+                bulk_set.iter().map(|(value, bulk)| {
+                    let mut entry = StableHasher::default();
+                    value.stable_hash_into(&mut entry);
+                    entry.write_int(*bulk);
+                    entry.finish()
+                }),
+            ),
+            GremlinValue::Traverser(traverser) => {
+                h.write_int(traverser.bulk);
+                traverser.value.stable_hash_into(h);
+            }
+            GremlinValue::Path(path) => {
+                h.write_len(path.labels.len());
+                for labels in &path.labels {
+                    h.write_len(labels.set().len());
+                    labels.iter().for_each(|label| h.write_string(label));
+                }
+                sequence_stable_hash(&path.objects, h);
+            }
+            GremlinValue::Tree(tree) => tree_stable_hash(tree, h),
+            GremlinValue::Graph(graph) => {
+                unordered_hash(
+                    h,
+                    graph.vertices.iter().map(|vertex| {
+                        let mut entry = StableHasher::default();
+                        vertex_stable_hash(vertex, &mut entry);
+                        entry.finish()
+                    }),
+                );
+                unordered_hash(
+                    h,
+                    graph.edges.iter().map(|edge| {
+                        let mut entry = StableHasher::default();
+                        graph_edge_stable_hash(edge, &mut entry);
+                        entry.finish()
+                    }),
+                );
+            }
+            GremlinValue::Vertex(vertex) => vertex_stable_hash(vertex, h),
+            GremlinValue::VertexProperty(vertex_property) => {
+                vertex_property_stable_hash(vertex_property, h);
+            }
+            GremlinValue::Edge(edge) => edge_stable_hash(edge, h),
+            GremlinValue::Property(property) => property_stable_hash(property, h),
+            GremlinValue::Metrics(metrics) => metrics_stable_hash(metrics, h),
+            GremlinValue::TraversalMetrics(traversal_metrics) => {
+                h.write_int(traversal_metrics.duration);
+                h.write_len(traversal_metrics.metrics.len());
+                for metrics in &traversal_metrics.metrics {
+                    metrics_stable_hash(metrics, h);
+                }
+            }
+            GremlinValue::TraversalStrategy(strategy) => {
+                h.write_string(&strategy.strategy_class);
+                unordered_hash(h, strategy.configuration.iter().map(string_entry_hash));
+            }
+            GremlinValue::Bytecode(bytecode) => {
+                let steps = bytecode.steps.iter().map(|step| (&step.name, &step.values));
+                let sources = bytecode
+                    .sources
+                    .iter()
+                    .map(|source| (&source.name, &source.values));
+                h.write_len(bytecode.steps.len());
+                h.write_len(bytecode.sources.len());
+                for (name, values) in steps.chain(sources) {
+                    h.write_string(name);
+                    sequence_stable_hash(values, h);
+                }
+            }
+            GremlinValue::P(p) => {
+                h.write_string(&p.predicate);
+                sequence_stable_hash(&p.value, h);
+            }
+            GremlinValue::TextP(text_p) => {
+                h.write_string(&text_p.predicate);
+                sequence_stable_hash(&text_p.value, h);
+            }
+            GremlinValue::Lambda(lambda) => {
+                h.write_string(&lambda.language);
+                h.write_string(&lambda.script);
+                h.write_int(i64::from(lambda.arguments_length));
+            }
+            GremlinValue::Binding(binding) => {
+                h.write_string(&binding.key);
+                binding.value.stable_hash_into(h);
+            }
+            GremlinValue::UnspecifiedNullObject => {}
+            #[allow(unreachable_patterns)]
+            other => h.write_string(&other.to_string()),
+        }
+    }
+
+    /// The GraphBinary type of the value.
+    pub(crate) fn core_type(&self) -> CoreType {
+        match self {
+            GremlinValue::Int(_) => CoreType::Int32,
+            GremlinValue::Long(_) => CoreType::Long,
+            GremlinValue::String(_) => CoreType::String,
+            GremlinValue::Date(_) => CoreType::Date,
+            GremlinValue::Timestamp(_) => CoreType::Timestamp,
+            GremlinValue::Class(_) => CoreType::Class,
+            GremlinValue::Double(_) => CoreType::Double,
+            GremlinValue::Float(_) => CoreType::Float,
+            GremlinValue::List(_) => CoreType::List,
+            GremlinValue::Set(_) => CoreType::Set,
+            GremlinValue::Map(_) => CoreType::Map,
+            GremlinValue::Uuid(_) => CoreType::Uuid,
+            GremlinValue::Edge(_) => CoreType::Edge,
+            GremlinValue::Path(_) => CoreType::Path,
+            GremlinValue::Property(_) => CoreType::Property,
+            GremlinValue::Graph(_) => CoreType::Graph,
+            GremlinValue::Vertex(_) => CoreType::Vertex,
+            GremlinValue::VertexProperty(_) => CoreType::VertexProperty,
+            GremlinValue::Barrier(_) => CoreType::Barrier,
+            GremlinValue::Binding(_) => CoreType::Binding,
+            GremlinValue::Bytecode(_) => CoreType::ByteCode,
+            GremlinValue::Cardinality(_) => CoreType::Cardinality,
+            GremlinValue::Column(_) => CoreType::Column,
+            GremlinValue::Direction(_) => CoreType::Direction,
+            GremlinValue::Operator(_) => CoreType::Operator,
+            GremlinValue::Order(_) => CoreType::Order,
+            GremlinValue::Pick(_) => CoreType::Pick,
+            GremlinValue::Pop(_) => CoreType::Pop,
+            GremlinValue::Lambda(_) => CoreType::Lambda,
+            GremlinValue::P(_) => CoreType::P,
+            GremlinValue::Scope(_) => CoreType::Scope,
+            GremlinValue::T(_) => CoreType::T,
+            GremlinValue::Traverser(_) => CoreType::Traverser,
+            GremlinValue::BigDecimal(_) => CoreType::BigDecimal,
+            GremlinValue::BigInteger(_) => CoreType::BigInteger,
+            GremlinValue::Byte(_) => CoreType::Byte,
+            GremlinValue::ByteBuffer(_) => CoreType::ByteBuffer,
+            GremlinValue::Short(_) => CoreType::Short,
+            GremlinValue::Boolean(_) => CoreType::Boolean,
+            GremlinValue::TextP(_) => CoreType::TextP,
+            GremlinValue::TraversalStrategy(_) => CoreType::TraversalStrategy,
+            GremlinValue::BulkSet(_) => CoreType::BulkSet,
+            GremlinValue::Tree(_) => CoreType::Tree,
+            GremlinValue::Metrics(_) => CoreType::Metrics,
+            GremlinValue::TraversalMetrics(_) => CoreType::TraversalMetrics,
+            GremlinValue::Merge(_) => CoreType::Merge,
+            GremlinValue::Char(_) => CoreType::Char,
+            GremlinValue::UnspecifiedNullObject => CoreType::UnspecifiedNullObject,
+            #[cfg(feature = "extended")]
+            GremlinValue::Duration(_) => CoreType::Duration,
+            #[cfg(feature = "extended")]
+            GremlinValue::InetAddress(_) => CoreType::InetAddress,
+            #[cfg(feature = "extended")]
+            GremlinValue::Instant(_) => CoreType::Instant,
+            #[cfg(feature = "extended")]
+            GremlinValue::LocalDate(_) => CoreType::LocalDate,
+            #[cfg(feature = "extended")]
+            GremlinValue::LocalDateTime(_) => CoreType::LocalDateTime,
+            #[cfg(feature = "extended")]
+            GremlinValue::LocalTime(_) => CoreType::LocalTime,
+            #[cfg(feature = "extended")]
+            GremlinValue::MonthDay(_) => CoreType::MonthDay,
+            #[cfg(feature = "extended")]
+            GremlinValue::OffsetDateTime(_) => CoreType::OffsetDateTime,
+            #[cfg(feature = "extended")]
+            GremlinValue::OffsetTime(_) => CoreType::OffsetTime,
+            #[cfg(feature = "extended")]
+            GremlinValue::Period(_) => CoreType::Period,
+            #[cfg(feature = "extended")]
+            GremlinValue::Year(_) => CoreType::Year,
+            #[cfg(feature = "extended")]
+            GremlinValue::YearMonth(_) => CoreType::YearMonth,
+            #[cfg(feature = "extended")]
+            GremlinValue::ZonedDateTime(_) => CoreType::ZonedDateTime,
+            #[cfg(feature = "extended")]
+            GremlinValue::ZoneOffset(_) => CoreType::ZoneOffset,
+            #[cfg(feature = "custom")]
+            GremlinValue::Custom(_) => CoreType::Custom,
+        }
+    }
+
+    /// Iterates the key/value pairs of a `Map`, or the value/bulk pairs of a `BulkSet` with the bulk
+    /// as `Long`. Returns None for all other variants.
+    ///
+    /// Map keys and bulks are not stored as `GremlinValue`, so these sides are yielded owned.
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use tinkerpop_io::GremlinValue;
+    /// # use tinkerpop_io::structure::bulkset::BulkSet;
+    /// let map = GremlinValue::Map(HashMap::from([("marko".into(), GremlinValue::Int(29))]));
+    /// let entries = map
+    ///     .iter_entries()
+    ///     .unwrap()
+    ///     .map(|(k, v)| (k.into_owned(), v.into_owned()))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(vec![(GremlinValue::from("marko"), GremlinValue::Int(29))], entries);
+    ///
+    /// let bulk_set = GremlinValue::BulkSet(BulkSet::new(vec![(GremlinValue::from("a"), 3)]));
+    /// let entries = bulk_set
+    ///     .iter_entries()
+    ///     .unwrap()
+    ///     .map(|(k, v)| (k.into_owned(), v.into_owned()))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(vec![(GremlinValue::from("a"), GremlinValue::Long(3))], entries);
+    ///
+    /// assert!(GremlinValue::Int(1).iter_entries().is_none());
+    /// ```
+    #[must_use]
+    pub fn iter_entries(
+        &self,
+    ) -> Option<impl Iterator<Item = (Cow<'_, GremlinValue>, Cow<'_, GremlinValue>)>> {
+        let (map, bulk_set) = match self {
+            GremlinValue::Map(map) => (Some(map), None),
+            GremlinValue::BulkSet(bulk_set) => (None, Some(bulk_set)),
+            _ => return None,
+        };
+        let map_entries = map.into_iter().flatten().map(|(key, value)| {
+            (
+                Cow::Owned(GremlinValue::from(key.clone())),
+                Cow::Borrowed(value),
+            )
+        });
+        let bulk_entries = bulk_set
+            .into_iter()
+            .flat_map(BulkSet::iter)
+            .map(|(value, bulk)| (Cow::Borrowed(value), Cow::Owned(GremlinValue::Long(*bulk))));
+        Some(map_entries.chain(bulk_entries))
+    }
+
+    /// Selects the keys or values of a `Map` as a `List`, as `select(Column.keys)` and
+    /// `select(Column.values)` do on the server. Keys and values are returned in the same order.
+    /// Returns None for all other variants.
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use tinkerpop_io::GremlinValue;
+    /// # use tinkerpop_io::structure::enums::Column;
+    /// let map = GremlinValue::Map(HashMap::from([
+    ///     ("marko".into(), GremlinValue::Int(29)),
+    ///     ("josh".into(), GremlinValue::Int(32)),
+    /// ]));
+    ///
+    /// let Some(GremlinValue::List(keys)) = map.select_column(Column::Keys) else {
+    ///     panic!("keys are not a List")
+    /// };
+    /// let Some(GremlinValue::List(values)) = map.select_column(Column::Values) else {
+    ///     panic!("values are not a List")
+    /// };
+    /// assert_eq!(2, keys.len());
+    /// assert!(keys.contains(&GremlinValue::from("marko")));
+    /// assert!(keys.contains(&GremlinValue::from("josh")));
+    /// let marko = keys.iter().position(|key| key == &GremlinValue::from("marko"));
+    /// assert_eq!(Some(&GremlinValue::Int(29)), marko.map(|i| &values[i]));
+    ///
+    /// assert_eq!(None, GremlinValue::Int(1).select_column(Column::Keys));
+    /// ```
+    #[must_use]
+    pub fn select_column(&self, col: Column) -> Option<GremlinValue> {
+        let map = self.as_map()?;
+        let list = match col {
+            Column::Keys => map.keys().cloned().map(GremlinValue::from).collect(),
+            Column::Values => map.values().cloned().collect(),
+        };
+        Some(GremlinValue::List(list))
+    }
+
+    /// Estimates the number of bytes allocated on the heap by this value, counting the capacity
+    /// of strings and collections and recursing into lists, sets, maps, paths and graph elements.
+    /// Labels and keys shared with other values count with their part of the allocation.
+    /// The estimate is not exact, but grows with the size of the value.
+    ///
+    /// ```
+    /// # use tinkerpop_io::GremlinValue;
+    /// # use tinkerpop_io::structure::bulkset::BulkSet;
+    /// # use tinkerpop_io::structure::lambda::Lambda;
+    ///
+    /// let empty = GremlinValue::List(Vec::new());
+    /// let list = GremlinValue::List(vec![GremlinValue::from("marko"); 100]);
+    ///
+    /// assert!(list.heap_size() > empty.heap_size());
+    /// assert_eq!(0, GremlinValue::Int(1).heap_size());
+    ///
+    /// let bulk_set = GremlinValue::BulkSet(BulkSet::new(vec![("marko".into(), 2)]));
+    /// assert!(bulk_set.heap_size() > "marko".len());
+    /// assert!(GremlinValue::Lambda(Lambda::new("{ it }")).heap_size() > 0);
+    /// ```
+    #[must_use]
+    pub fn heap_size(&self) -> usize {
+        match self {
+            GremlinValue::String(s) | GremlinValue::Class(s) => s.capacity(),
+            GremlinValue::List(list) => vec_heap_size(list, GremlinValue::heap_size),
+            GremlinValue::Set(set) => vec_heap_size(set.set(), GremlinValue::heap_size),
+            GremlinValue::Map(map) => {
+                map.capacity()
+                    * (std::mem::size_of::<MapKeys>() + std::mem::size_of::<GremlinValue>())
+                    + map
+                        .iter()
+                        .map(|(key, value)| map_key_heap_size(key) + value.heap_size())
+                        .sum::<usize>()
+            }
+            GremlinValue::Edge(edge) => edge_heap_size(edge),
+            GremlinValue::Path(path) => {
+                vec_heap_size(&path.labels, |set| {
+                    vec_heap_size(set.set(), String::capacity)
+                }) + vec_heap_size(&path.objects, GremlinValue::heap_size)
+            }
+            GremlinValue::Property(property) => property_heap_size(property),
+            GremlinValue::Vertex(vertex) => vertex_heap_size(vertex),
+            GremlinValue::VertexProperty(vertex_property) => {
+                vertex_property_heap_size(vertex_property)
+            }
+            GremlinValue::Traverser(traverser) => {
+                std::mem::size_of::<GremlinValue>() + traverser.value.heap_size()
+            }
+            GremlinValue::Graph(graph) => {
+                vec_heap_size(&graph.vertices, vertex_heap_size)
+                    + vec_heap_size(&graph.edges, graph_edge_heap_size)
+            }
+            GremlinValue::Tree(tree) => tree_heap_size(tree),
+            GremlinValue::BulkSet(bulk_set) => {
+                vec_heap_size(&bulk_set.0, |(value, _)| value.heap_size())
+            }
+            GremlinValue::Bytecode(bytecode) => {
+                vec_heap_size(&bytecode.steps, |step| {
+                    step.name.capacity() + vec_heap_size(&step.values, GremlinValue::heap_size)
+                }) + vec_heap_size(&bytecode.sources, |source| {
+                    source.name.capacity() + vec_heap_size(&source.values, GremlinValue::heap_size)
+                })
+            }
+            GremlinValue::Metrics(metrics) => metrics_heap_size(metrics),
+            GremlinValue::TraversalMetrics(traversal_metrics) => {
+                vec_heap_size(&traversal_metrics.metrics, metrics_heap_size)
+            }
+            GremlinValue::P(p) => {
+                p.predicate.capacity() + vec_heap_size(&p.value, GremlinValue::heap_size)
+            }
+            GremlinValue::TextP(text_p) => {
+                text_p.predicate.capacity() + vec_heap_size(&text_p.value, GremlinValue::heap_size)
+            }
+            GremlinValue::Lambda(lambda) => lambda.language.capacity() + lambda.script.capacity(),
+            GremlinValue::Binding(binding) => {
+                binding.key.capacity()
+                    + std::mem::size_of::<GremlinValue>()
+                    + binding.value.heap_size()
+            }
+            GremlinValue::TraversalStrategy(strategy) => {
+                strategy.strategy_class.capacity()
+                    + string_map_heap_size(&strategy.configuration, GremlinValue::heap_size)
+            }
+            GremlinValue::ByteBuffer(buf) => buf.bytes().capacity(),
+            GremlinValue::BigInteger(big_int) => (big_int.bits() / 8) as usize,
+            GremlinValue::BigDecimal(big_decimal) => {
+                (big_decimal.as_bigint_and_exponent().0.bits() / 8) as usize
+            }
+            _ => 0,
+        }
+    }
+
+    /// Merges two `GremlinValue::Map` into one, resolving keys present in both maps with the [`MergePolicy`].
+    /// Returns [`MergeError::NotAMap`] if either value is not a Map.
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use tinkerpop_io::{GremlinValue, MergePolicy};
+    ///
+    /// let left = || GremlinValue::Map(HashMap::from([("marko".into(), 1_i64.into())]));
+    /// let right = || {
+    ///     GremlinValue::Map(HashMap::from([
+    ///         ("marko".into(), 2_i64.into()),
+    ///         ("josh".into(), 3_i64.into()),
+    ///     ]))
+    /// };
+    ///
+    /// let merged = left().merge(right(), MergePolicy::KeepLeft).unwrap();
+    /// assert_eq!(
+    ///     GremlinValue::Map(HashMap::from([
+    ///         ("marko".into(), 1_i64.into()),
+    ///         ("josh".into(), 3_i64.into()),
+    ///     ])),
+    ///     merged
+    /// );
+    ///
+    /// let merged = left().merge(right(), MergePolicy::KeepRight).unwrap();
+    /// assert_eq!(right(), merged);
+    ///
+    /// let merged = left().merge(right(), MergePolicy::SumNumeric).unwrap();
+    /// assert_eq!(
+    ///     GremlinValue::Map(HashMap::from([
+    ///         ("marko".into(), 3_i64.into()),
+    ///         ("josh".into(), 3_i64.into()),
+    ///     ])),
+    ///     merged
+    /// );
+    ///
+    /// assert!(left().merge(GremlinValue::Int(1), MergePolicy::KeepLeft).is_err());
+    ///
+    /// ```
+    pub fn merge(
+        self,
+        other: GremlinValue,
+        policy: MergePolicy,
+    ) -> Result<GremlinValue, MergeError> {
+        let (mut left, right) = match (self, other) {
+            (GremlinValue::Map(left), GremlinValue::Map(right)) => (left, right),
+            _ => return Err(MergeError::NotAMap),
+        };
+
+        for (key, right_val) in right {
+            let merged = match (left.remove(&key), policy) {
+                (None, _) | (Some(_), MergePolicy::KeepRight) => right_val,
+                (Some(left_val), MergePolicy::KeepLeft) => left_val,
+                (Some(left_val), MergePolicy::SumNumeric) => {
+                    sum_numeric(&key, &left_val, &right_val)?
+                }
+            };
+            left.insert(key, merged);
+        }
+        Ok(GremlinValue::Map(left))
+    }
+
+    /// Lifts plain JSON without GraphSON `@type` wrappers into a `GremlinValue` with best-effort
+    /// typing. Use the [`graphson`] module to decode typed GraphSON instead.
+    ///
+    /// Integral numbers within the `i32` range become `Int`, larger ones `Long` and numbers
+    /// beyond the `i64` range `BigInteger`. All other numbers become `Double`, arrays become
+    /// `List`, objects become `Map` with `String` keys and `null` becomes `UnspecifiedNullObject`.
+    ///
+    /// ```
+    /// # use tinkerpop_io::GremlinValue;
+    /// # use tinkerpop_io::structure::map::MapKeys;
+    /// # use std::collections::HashMap;
+    /// let json = serde_json::json!({"name": "marko", "age": 29, "knows": {"weight": 0.5}});
+    ///
+    /// let knows = HashMap::from([(MapKeys::from("weight"), GremlinValue::Double(0.5))]);
+    /// let expected = HashMap::from([
+    ///     (MapKeys::from("name"), GremlinValue::from("marko")),
+    ///     (MapKeys::from("age"), GremlinValue::Int(29)),
+    ///     (MapKeys::from("knows"), GremlinValue::Map(knows)),
+    /// ]);
+    /// assert_eq!(GremlinValue::Map(expected), GremlinValue::from_plain_json(&json));
+    ///
+    /// let json = serde_json::json!([1, 3_000_000_000_i64, "a", true, null]);
+    /// assert_eq!(
+    ///     GremlinValue::List(vec![
+    ///         GremlinValue::Int(1),
+    ///         GremlinValue::Long(3_000_000_000),
+    ///         GremlinValue::from("a"),
+    ///         GremlinValue::Boolean(true),
+    ///         GremlinValue::UnspecifiedNullObject,
+    ///     ]),
+    ///     GremlinValue::from_plain_json(&json)
+    /// );
+    /// ```
+    #[cfg(feature = "graph_son")]
+    #[must_use]
+    pub fn from_plain_json(j: &serde_json::Value) -> GremlinValue {
+        match j {
+            serde_json::Value::Null => GremlinValue::UnspecifiedNullObject,
+            serde_json::Value::Bool(b) => GremlinValue::Boolean(*b),
+            serde_json::Value::Number(n) => {
+                if let Some(i) = n.as_i64() {
+                    i32::try_from(i).map_or(GremlinValue::Long(i), GremlinValue::Int)
+                } else if let Ok(big) = BigInt::from_str(&n.to_string()) {
+                    GremlinValue::BigInteger(big)
+                } else {
+                    GremlinValue::Double(n.as_f64().unwrap_or(f64::NAN))
+                }
+            }
+            serde_json::Value::String(s) => GremlinValue::String(s.clone()),
+            serde_json::Value::Array(array) => {
+                GremlinValue::List(array.iter().map(GremlinValue::from_plain_json).collect())
+            }
+            serde_json::Value::Object(object) => GremlinValue::Map(
+                object
+                    .iter()
+                    .map(|(k, v)| (MapKeys::from(k.as_str()), GremlinValue::from_plain_json(v)))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Renders the value as natural JSON without GraphSON `@type` wrappers, for consumers that
+    /// don't understand GraphSON. Containers and graph elements are rendered recursively, a
+    /// vertex becomes `{"id", "label", "properties": {key: [values]}}` and map keys are rendered
+    /// with their `Display` representation. A bulk set becomes a list of `{"value", "bulk"}`
+    /// objects, like a traverser. Values without a natural JSON counterpart, such as
+    /// `Bytecode` or `P`, are rendered as their `Display` string.
+    ///
+    /// ```
+    /// # use tinkerpop_io::GremlinValue;
+    /// # use tinkerpop_io::structure::bulkset::BulkSet;
+    /// # use tinkerpop_io::structure::vertex::Vertex;
+    /// # use tinkerpop_io::structure::vertex_property::VertexProperty;
+    /// let marko = Vertex::new(
+    ///     1_i32,
+    ///     "person",
+    ///     Some(vec![
+    ///         VertexProperty::new(0_i64, "name", "marko", None, None),
+    ///         VertexProperty::new(6_i64, "location", "san diego", None, None),
+    ///         VertexProperty::new(7_i64, "location", "santa cruz", None, None),
+    ///     ]),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     serde_json::json!({
+    ///         "id": 1,
+    ///         "label": "person",
+    ///         "properties": {
+    ///             "name": ["marko"],
+    ///             "location": ["san diego", "santa cruz"]
+    ///         }
+    ///     }),
+    ///     GremlinValue::Vertex(marko).to_plain_json()
+    /// );
+    ///
+    /// let bulk_set = BulkSet::new(vec![("marko".into(), i64::MAX)]);
+    /// assert_eq!(
+    ///     serde_json::json!([{"value": "marko", "bulk": i64::MAX}]),
+    ///     GremlinValue::BulkSet(bulk_set).to_plain_json()
+    /// );
+    /// ```
+    #[cfg(feature = "graph_son")]
+    #[must_use]
+    pub fn to_plain_json(&self) -> serde_json::Value {
+        use serde_json::{json, Value};
+
+        match self {
+            GremlinValue::UnspecifiedNullObject => Value::Null,
+            GremlinValue::Boolean(b) => Value::Bool(*b),
+            GremlinValue::Byte(val) => Value::from(*val),
+            GremlinValue::Short(val) => Value::from(*val),
+            GremlinValue::Int(val) => Value::from(*val),
+            GremlinValue::Long(val) | GremlinValue::Date(val) | GremlinValue::Timestamp(val) => {
+                Value::from(*val)
+            }
+            GremlinValue::Float(val) => Value::from(f64::from(*val)),
+            GremlinValue::Double(val) => Value::from(*val),
+            GremlinValue::BigInteger(val) => plain_json_number(val.to_string()),
+            GremlinValue::BigDecimal(val) => plain_json_number(val.to_string()),
+            GremlinValue::String(val) | GremlinValue::Class(val) => Value::String(val.clone()),
+            GremlinValue::Char(val) => Value::String(val.to_string()),
+            GremlinValue::Uuid(val) => Value::String(val.to_string()),
+            GremlinValue::List(list) => list.iter().map(GremlinValue::to_plain_json).collect(),
+            GremlinValue::Set(set) => set.iter().map(GremlinValue::to_plain_json).collect(),
+            GremlinValue::Map(map) => Value::Object(
+                map.iter()
+                    .map(|(key, value)| (key.to_string(), value.to_plain_json()))
+                    .collect(),
+            ),
+            GremlinValue::BulkSet(bulk_set) => bulk_set
+                .iter()
+                .map(|(value, bulk)| {
+                    json!({
+                        "value": value.to_plain_json(),
+                        "bulk": bulk,
+                    })
+                })
+                .collect(),
+            GremlinValue::Traverser(traverser) => json!({
+                "bulk": traverser.bulk,
+                "value": traverser.value.to_plain_json(),
+            }),
+            GremlinValue::Path(path) => json!({
+                "labels": path
+                    .labels
+                    .iter()
+                    .map(|labels| labels.iter().cloned().collect::<Vec<_>>())
+                    .collect::<Vec<_>>(),
+                "objects": path.objects.iter().map(GremlinValue::to_plain_json).collect::<Value>(),
+            }),
+            GremlinValue::Tree(tree) => Value::Object(
+                tree.iter()
+                    .map(|(key, subtree)| {
+                        (
+                            key.to_string(),
+                            GremlinValue::Tree(subtree.clone()).to_plain_json(),
+                        )
+                    })
+                    .collect(),
+            ),
+            GremlinValue::Vertex(vertex) => vertex_to_plain_json(vertex),
+            GremlinValue::VertexProperty(vertex_property) => {
+                vertex_property_to_plain_json(vertex_property)
+            }
+            GremlinValue::Edge(edge) => json!({
+                "id": element_id_to_plain_json(&edge.id),
+                "label": &*edge.label,
+                "inV": element_id_to_plain_json(&edge.in_v_id),
+                "inVLabel": &*edge.in_v_label,
+                "outV": element_id_to_plain_json(&edge.out_v_id),
+                "outVLabel": &*edge.out_v_label,
+                "properties": properties_to_plain_json(edge.properties.as_deref()),
+            }),
+            GremlinValue::Property(property) => json!({
+                "key": &*property.key,
+                "value": property.value.to_plain_json(),
+            }),
+            other => Value::String(other.to_string()),
+        }
+    }
+}
+
+/// Policy to resolve keys present in both maps during [`GremlinValue::merge`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergePolicy {
+    KeepLeft,
+    KeepRight,
+    /// Adds the numeric values of both maps, as in combining `groupCount` results.
+    /// Values of the same variant keep their variant, mixed integral values are summed as `Long`
+    /// and mixed values including a floating point are summed as `Double`.
+    SumNumeric,
+}
+
+fn sum_numeric(
+    key: &MapKeys,
+    left: &GremlinValue,
+    right: &GremlinValue,
+) -> Result<GremlinValue, MergeError> {
+    let sum = match (left, right) {
+        (GremlinValue::Byte(l), GremlinValue::Byte(r)) => l.checked_add(*r).map(GremlinValue::Byte),
+        (GremlinValue::Short(l), GremlinValue::Short(r)) => {
+            l.checked_add(*r).map(GremlinValue::Short)
+        }
+        (GremlinValue::Int(l), GremlinValue::Int(r)) => l.checked_add(*r).map(GremlinValue::Int),
+        (GremlinValue::Long(l), GremlinValue::Long(r)) => l.checked_add(*r).map(GremlinValue::Long),
+        (GremlinValue::Float(l), GremlinValue::Float(r)) => Some(GremlinValue::Float(l + r)),
+        (GremlinValue::Double(l), GremlinValue::Double(r)) => Some(GremlinValue::Double(l + r)),
+        (GremlinValue::Float(_) | GremlinValue::Double(_), _)
+        | (_, GremlinValue::Float(_) | GremlinValue::Double(_)) => {
+            match (left.as_f64(), right.as_f64()) {
+                (Some(l), Some(r)) => Some(GremlinValue::Double(l + r)),
+                _ => return Err(MergeError::NotNumeric(key.to_string())),
+            }
+        }
+        _ => match (left.as_i64(), right.as_i64()) {
+            (Some(l), Some(r)) => l.checked_add(r).map(GremlinValue::Long),
+            _ => return Err(MergeError::NotNumeric(key.to_string())),
+        },
+    };
+    sum.ok_or_else(|| MergeError::Overflow(key.to_string()))
+}
+
+enum PathNode<'a> {
+    Value(&'a GremlinValue),
+    VertexProperty(&'a VertexProperty),
+    VertexProperties(Vec<&'a VertexProperty>),
+    Properties(&'a [Property]),
+}
+
+impl<'a> PathNode<'a> {
+    fn key(self, key: &str) -> Option<PathNode<'a>> {
+        match self {
+            PathNode::Value(GremlinValue::Map(map)) => map
+                .get(&MapKeys::from(key))
+                .or_else(|| T::from_str(key).ok().and_then(|t| map.get(&MapKeys::T(t))))
+                .map(PathNode::Value),
+            PathNode::Value(GremlinValue::Vertex(vertex)) if key == "properties" => Some(
+                PathNode::VertexProperties(vertex.properties.iter().flatten().collect()),
+            ),
+            PathNode::Value(GremlinValue::VertexProperty(vertex_property)) => {
+                PathNode::VertexProperty(vertex_property).key(key)
+            }
+            PathNode::VertexProperty(vertex_property) => match key {
+                "value" => Some(PathNode::Value(&vertex_property.value)),
+                "properties" => vertex_property
+                    .properties
+                    .as_deref()
+                    .map(PathNode::Properties),
+                _ => None,
+            },
+            PathNode::Value(GremlinValue::Property(property)) if key == "value" => {
+                Some(PathNode::Value(&property.value))
+            }
+            PathNode::VertexProperties(properties) => Some(PathNode::VertexProperties(
+                properties
+                    .into_iter()
+                    .filter(|property| &*property.label == key)
+                    .collect(),
+            )),
+            PathNode::Properties(properties) => properties
+                .iter()
+                .find(|property| &*property.key == key)
+                .map(|property| PathNode::Value(&property.value)),
+            PathNode::Value(_) => None,
+        }
+    }
+
+    fn index(self, index: usize) -> Option<PathNode<'a>> {
+        match self {
+            PathNode::Value(GremlinValue::List(list)) => list.get(index).map(PathNode::Value),
+            PathNode::Value(GremlinValue::Set(set)) => set.iter().nth(index).map(PathNode::Value),
+            PathNode::VertexProperties(properties) => properties
+                .get(index)
+                .map(|property| PathNode::VertexProperty(property)),
+            _ => None,
+        }
+    }
+}
+
+fn coerce_integral<T: TryFrom<i64> + FromStr>(
+    value: &GremlinValue,
+    target: CoreType,
+) -> Result<T, CoerceError> {
+    if let GremlinValue::String(s) = value {
+        return coerce_parse(s, target);
+    }
+    let integral = value.as_i64().ok_or_else(|| CoerceError::Unsupported {
+        value: value.to_string(),
+        target,
+    })?;
+    T::try_from(integral).map_err(|_| CoerceError::OutOfRange {
+        value: value.to_string(),
+        target,
+    })
+}
+
+fn coerce_parse<T: FromStr>(s: &str, target: CoreType) -> Result<T, CoerceError> {
+    s.trim().parse().map_err(|_| CoerceError::Parse {
+        value: s.to_string(),
+        target,
+    })
+}
+
+fn vec_heap_size<T>(vec: &Vec<T>, item_heap_size: impl Fn(&T) -> usize) -> usize {
+    vec.capacity() * std::mem::size_of::<T>() + vec.iter().map(item_heap_size).sum::<usize>()
+}
+
+fn string_map_heap_size<V>(
+    map: &HashMap<String, V>,
+    value_heap_size: impl Fn(&V) -> usize,
+) -> usize {
+    map.capacity() * (std::mem::size_of::<String>() + std::mem::size_of::<V>())
+        + map
+            .iter()
+            .map(|(key, value)| key.capacity() + value_heap_size(value))
+            .sum::<usize>()
+}
+
+fn tree_heap_size(tree: &Tree) -> usize {
+    vec_heap_size(&tree.0, |(key, branch)| {
+        key.heap_size() + tree_heap_size(branch)
+    })
+}
+
+fn metrics_heap_size(metrics: &Metrics) -> usize {
+    metrics.id.capacity()
+        + metrics.name.capacity()
+        + string_map_heap_size(&metrics.counts, |_| 0)
+        + string_map_heap_size(&metrics.annotations, GremlinValue::heap_size)
+        + vec_heap_size(&metrics.nested_metrics, metrics_heap_size)
+}
+
+fn map_key_heap_size(key: &MapKeys) -> usize {
+    match key {
+        MapKeys::String(s) => s.capacity(),
+        _ => 0,
+    }
+}
+
+/// Splits the allocation of a shared label or key evenly among the values holding it.
+fn shared_string_heap_size(s: &Arc<str>) -> usize {
+    (2 * std::mem::size_of::<usize>() + s.len()).div_ceil(Arc::strong_count(s))
+}
+
+fn element_id_heap_size(id: &ElementId) -> usize {
+    id.as_str().map_or(0, str::len)
+}
+
+#[cfg(feature = "graph_son")]
+fn plain_json_number(number: String) -> serde_json::Value {
+    serde_json::from_str(&number).unwrap_or(serde_json::Value::String(number))
+}
+
+#[cfg(feature = "graph_son")]
+fn element_id_to_plain_json(id: &ElementId) -> serde_json::Value {
+    match id {
+        ElementId::String(id) => serde_json::Value::String(id.clone()),
+        ElementId::Int(id) => serde_json::Value::from(*id),
+        ElementId::Long(id) => serde_json::Value::from(*id),
+        ElementId::Uuid(id) => serde_json::Value::String(id.to_string()),
+    }
+}
+
+#[cfg(feature = "graph_son")]
+fn vertex_to_plain_json(vertex: &Vertex) -> serde_json::Value {
+    let mut properties = serde_json::Map::new();
+    for vertex_property in vertex.properties.iter().flatten() {
+        if let serde_json::Value::Array(values) = properties
+            .entry(vertex_property.label.to_string())
+            .or_insert_with(|| serde_json::Value::Array(Vec::new()))
+        {
+            values.push(vertex_property.value.to_plain_json());
+        }
+    }
+    serde_json::json!({
+        "id": element_id_to_plain_json(&vertex.id),
+        "label": &*vertex.label,
+        "properties": properties,
+    })
+}
+
+#[cfg(feature = "graph_son")]
+fn vertex_property_to_plain_json(vertex_property: &VertexProperty) -> serde_json::Value {
+    serde_json::json!({
+        "id": element_id_to_plain_json(&vertex_property.id),
+        "label": &*vertex_property.label,
+        "value": vertex_property.value.to_plain_json(),
+        "properties": properties_to_plain_json(vertex_property.properties.as_deref()),
+    })
+}
+
+#[cfg(feature = "graph_son")]
+fn properties_to_plain_json(properties: Option<&[Property]>) -> serde_json::Value {
+    serde_json::Value::Object(
+        properties
+            .into_iter()
+            .flatten()
+            .map(|property| (property.key.to_string(), property.value.to_plain_json()))
+            .collect(),
+    )
+}
+
+fn local_len(val: &GremlinValue) -> Option<i64> {
+    match val {
+        GremlinValue::List(_) | GremlinValue::Set(_) | GremlinValue::BulkSet(_) => {
+            val.count(Scope::Global)
+        }
+        GremlinValue::Map(map) => i64::try_from(map.len()).ok(),
+        _ => Some(1),
+    }
+}
+
+fn tree_walk(tree: &Tree, level: usize, visit: &mut dyn FnMut(usize)) {
+    for (key, subtree) in &tree.0 {
+        key.walk(level, visit);
+        tree_walk(subtree, level + 1, visit);
+    }
+}
+
+fn vertex_properties_walk(vertex: &Vertex, level: usize, visit: &mut dyn FnMut(usize)) {
+    for vertex_property in vertex.properties.iter().flatten() {
+        visit(level);
+        vertex_property_children_walk(vertex_property, level + 1, visit);
+    }
+}
+
+fn vertex_property_children_walk(
+    vertex_property: &VertexProperty,
+    level: usize,
+    visit: &mut dyn FnMut(usize),
+) {
+    vertex_property.value.walk(level, visit);
+    for property in vertex_property.properties.iter().flatten() {
+        property_walk(property, level, visit);
+    }
+}
+
+fn property_walk(property: &Property, level: usize, visit: &mut dyn FnMut(usize)) {
+    visit(level);
+    property.value.walk(level + 1, visit);
+}
+
+fn tree_map_strings(tree: &mut Tree, f: &dyn Fn(&mut String)) {
+    for (key, subtree) in &mut tree.0 {
+        key.map_strings_dyn(f);
+        tree_map_strings(subtree, f);
+    }
+}
+
+/// Applies `f` to a label or key, which keeps its shared storage if `f` leaves it unchanged.
+fn shared_string_map(s: &mut Arc<str>, f: &dyn Fn(&mut String)) {
+    let mut mapped = s.to_string();
+    f(&mut mapped);
+    if *mapped != **s {
+        *s = mapped.into();
+    }
+}
+
+fn vertex_map_strings(vertex: &mut Vertex, f: &dyn Fn(&mut String)) {
+    shared_string_map(&mut vertex.label, f);
+    for vertex_property in vertex.properties.iter_mut().flatten() {
+        vertex_property_map_strings(vertex_property, f);
+    }
+}
+
+fn vertex_property_map_strings(vertex_property: &mut VertexProperty, f: &dyn Fn(&mut String)) {
+    shared_string_map(&mut vertex_property.label, f);
+    vertex_property.value.map_strings_dyn(f);
+    if let Some(parent) = &mut vertex_property.parent {
+        vertex_map_strings(parent, f);
+    }
+    for property in vertex_property.properties.iter_mut().flatten() {
+        property_map_strings(property, f);
+    }
+}
+
+fn edge_map_strings(edge: &mut Edge, f: &dyn Fn(&mut String)) {
+    shared_string_map(&mut edge.label, f);
+    shared_string_map(&mut edge.in_v_label, f);
+    shared_string_map(&mut edge.out_v_label, f);
+    if let Some(parent) = &mut edge.parent {
+        vertex_map_strings(parent, f);
+    }
+    for property in edge.properties.iter_mut().flatten() {
+        property_map_strings(property, f);
+    }
+}
+
+fn graph_edge_map_strings(edge: &mut GraphEdge, f: &dyn Fn(&mut String)) {
+    shared_string_map(&mut edge.label, f);
+    for label in edge.in_v_label.iter_mut().chain(&mut edge.out_v_label) {
+        shared_string_map(label, f);
+    }
+    if let Some(parent) = &mut edge.parent {
+        vertex_map_strings(parent, f);
+    }
+    for property in &mut edge.properties {
+        property_map_strings(property, f);
+    }
+}
+
+fn metrics_map_strings(metrics: &mut Metrics, f: &dyn Fn(&mut String)) {
+    metrics
+        .annotations
+        .values_mut()
+        .for_each(|val| val.map_strings_dyn(f));
+    for nested in &mut metrics.nested_metrics {
+        metrics_map_strings(nested, f);
+    }
+}
+
+fn property_map_strings(property: &mut Property, f: &dyn Fn(&mut String)) {
+    shared_string_map(&mut property.key, f);
+    property.value.map_strings_dyn(f);
+    match &mut property.parent {
+        EitherParent::Edge(edge) => edge_map_strings(edge, f),
+        EitherParent::VertexProperty(vertex_property) => {
+            vertex_property_map_strings(vertex_property, f);
+        }
+        EitherParent::None => {}
+    }
+}
+
+/// FNV-1a, unlike `DefaultHasher` its output does not change between Rust releases.
+/// Only fed through the canonical `write_*` methods, never through `Hash`.
+struct StableHasher(u64);
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        StableHasher(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_tag(&mut self, tag: u8) {
+        self.write(&[tag]);
+    }
+
+    fn write_int(&mut self, val: i64) {
+        self.write(&val.to_be_bytes());
+    }
+
+    fn write_len(&mut self, len: usize) {
+        self.write(&u64::try_from(len).unwrap_or(u64::MAX).to_be_bytes());
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        self.write_len(bytes.len());
+        self.write(bytes);
+    }
+
+    fn write_string(&mut self, val: &str) {
+        self.write_bytes(val.as_bytes());
+    }
+}
+
+fn unordered_hash(h: &mut StableHasher, hashes: impl Iterator<Item = u64>) {
+    let mut hashes: Vec<u64> = hashes.collect();
+    hashes.sort_unstable();
+    h.write_len(hashes.len());
+    for hash in hashes {
+        h.write(&hash.to_be_bytes());
+    }
+}
+
+/// `-0.0` equals `0.0` and NaNs have many bit patterns, both are mapped to a single one.
+fn normalized_f64(val: f64) -> f64 {
+    if val == 0.0 {
+        0.0
+    } else if val.is_nan() {
+        f64::NAN
+    } else {
+        val
+    }
+}
+
+fn normalized_f32(val: f32) -> f32 {
+    if val == 0.0 {
+        0.0
+    } else if val.is_nan() {
+        f32::NAN
+    } else {
+        val
+    }
+}
+
+fn sequence_stable_hash(values: &[GremlinValue], h: &mut StableHasher) {
+    h.write_len(values.len());
+    values.iter().for_each(|val| val.stable_hash_into(h));
+}
+
+fn string_entry_hash((key, value): (&String, &GremlinValue)) -> u64 {
+    let mut entry = StableHasher::default();
+    entry.write_string(key);
+    value.stable_hash_into(&mut entry);
+    entry.finish()
+}
+
+fn map_key_stable_hash(key: &MapKeys, h: &mut StableHasher) {
+    match key {
+        MapKeys::Int(val) => GremlinValue::Int(*val).stable_hash_into(h),
+        MapKeys::Long(val) => GremlinValue::Long(*val).stable_hash_into(h),
+        MapKeys::String(val) => {
+            h.write_tag(CoreType::String.into());
+            h.write_string(val);
+        }
+        MapKeys::Uuid(val) => GremlinValue::Uuid(*val).stable_hash_into(h),
+        MapKeys::T(val) => GremlinValue::T(*val).stable_hash_into(h),
+        MapKeys::Direction(val) => GremlinValue::Direction(*val).stable_hash_into(h),
+        MapKeys::Merge(val) => GremlinValue::Merge(*val).stable_hash_into(h),
+    }
+}
+
+fn element_id_stable_hash(id: &ElementId, h: &mut StableHasher) {
+    match id {
+        ElementId::Int(val) => GremlinValue::Int(*val).stable_hash_into(h),
+        ElementId::Long(val) => GremlinValue::Long(*val).stable_hash_into(h),
+        ElementId::String(val) => {
+            h.write_tag(CoreType::String.into());
+            h.write_string(val);
+        }
+        ElementId::Uuid(val) => GremlinValue::Uuid(*val).stable_hash_into(h),
+    }
+}
+
+fn optional_string_stable_hash(val: Option<&str>, h: &mut StableHasher) {
+    match val {
+        Some(val) => {
+            h.write_tag(1);
+            h.write_string(val);
+        }
+        None => h.write_tag(0),
+    }
+}
+
+fn tree_stable_hash(tree: &Tree, h: &mut StableHasher) {
+    unordered_hash(
+        h,
+        tree.iter().map(|(key, subtree)| {
+            let mut entry = StableHasher::default();
+            key.stable_hash_into(&mut entry);
+            tree_stable_hash(subtree, &mut entry);
+            entry.finish()
+        }),
+    );
+}
+
+fn vertex_stable_hash(vertex: &Vertex, h: &mut StableHasher) {
+    element_id_stable_hash(&vertex.id, h);
+    h.write_string(&vertex.label);
+    let properties = vertex.properties.as_deref().unwrap_or_default();
+    h.write_len(properties.len());
+    for vertex_property in properties {
+        vertex_property_stable_hash(vertex_property, h);
+    }
+}
+
+fn vertex_property_stable_hash(vertex_property: &VertexProperty, h: &mut StableHasher) {
+    element_id_stable_hash(&vertex_property.id, h);
+    h.write_string(&vertex_property.label);
+    vertex_property.value.stable_hash_into(h);
+    match &vertex_property.parent {
+        Some(parent) => {
+            h.write_tag(1);
+            vertex_stable_hash(parent, h);
+        }
+        None => h.write_tag(0),
+    }
+    properties_stable_hash(vertex_property.properties.as_deref().unwrap_or_default(), h);
+}
+
+fn edge_stable_hash(edge: &Edge, h: &mut StableHasher) {
+    element_id_stable_hash(&edge.id, h);
+    h.write_string(&edge.label);
+    element_id_stable_hash(&edge.in_v_id, h);
+    h.write_string(&edge.in_v_label);
+    element_id_stable_hash(&edge.out_v_id, h);
+    h.write_string(&edge.out_v_label);
+    match &edge.parent {
+        Some(parent) => {
+            h.write_tag(1);
+            vertex_stable_hash(parent, h);
+        }
+        None => h.write_tag(0),
+    }
+    properties_stable_hash(edge.properties.as_deref().unwrap_or_default(), h);
+}
+
+fn graph_edge_stable_hash(edge: &GraphEdge, h: &mut StableHasher) {
+    element_id_stable_hash(&edge.id, h);
+    h.write_string(&edge.label);
+    element_id_stable_hash(&edge.in_v_id, h);
+    optional_string_stable_hash(edge.in_v_label.as_deref(), h);
+    element_id_stable_hash(&edge.out_v_id, h);
+    optional_string_stable_hash(edge.out_v_label.as_deref(), h);
+    match &edge.parent {
+        Some(parent) => {
+            h.write_tag(1);
+            vertex_stable_hash(parent, h);
+        }
+        None => h.write_tag(0),
+    }
+    properties_stable_hash(&edge.properties, h);
+}
+
+fn properties_stable_hash(properties: &[Property], h: &mut StableHasher) {
+    h.write_len(properties.len());
+    for property in properties {
+        property_stable_hash(property, h);
+    }
+}
+
+fn property_stable_hash(property: &Property, h: &mut StableHasher) {
+    h.write_string(&property.key);
+    property.value.stable_hash_into(h);
+    match &property.parent {
+        EitherParent::Edge(edge) => {
+            h.write_tag(1);
+            edge_stable_hash(edge, h);
+        }
+        EitherParent::VertexProperty(vertex_property) => {
+            h.write_tag(2);
+            vertex_property_stable_hash(vertex_property, h);
+        }
+        EitherParent::None => h.write_tag(0),
+    }
+}
+
+fn metrics_stable_hash(metrics: &Metrics, h: &mut StableHasher) {
+    h.write_string(&metrics.id);
+    h.write_string(&metrics.name);
+    h.write_int(metrics.duration);
+    unordered_hash(
+        h,
+        metrics.counts.iter().map(|(key, count)| {
+            let mut entry = StableHasher::default();
+            entry.write_string(key);
+            entry.write_int(*count);
+            entry.finish()
+        }),
+    );
+    unordered_hash(h, metrics.annotations.iter().map(string_entry_hash));
+    h.write_len(metrics.nested_metrics.len());
+    for nested in &metrics.nested_metrics {
+        metrics_stable_hash(nested, h);
+    }
+}
+
+fn vertex_heap_size(vertex: &Vertex) -> usize {
+    element_id_heap_size(&vertex.id)
+        + shared_string_heap_size(&vertex.label)
+        + vertex.properties.as_ref().map_or(0, |properties| {
+            vec_heap_size(properties, vertex_property_heap_size)
+        })
+}
+
+fn vertex_property_heap_size(vertex_property: &VertexProperty) -> usize {
+    element_id_heap_size(&vertex_property.id)
+        + shared_string_heap_size(&vertex_property.label)
+        + std::mem::size_of::<GremlinValue>()
+        + vertex_property.value.heap_size()
+        + vertex_property.parent.as_ref().map_or(0, vertex_heap_size)
+        + vertex_property.properties.as_ref().map_or(0, |properties| {
+            vec_heap_size(properties, property_heap_size)
+        })
+}
+
+fn edge_heap_size(edge: &Edge) -> usize {
+    element_id_heap_size(&edge.id)
+        + shared_string_heap_size(&edge.label)
+        + element_id_heap_size(&edge.in_v_id)
+        + shared_string_heap_size(&edge.in_v_label)
+        + element_id_heap_size(&edge.out_v_id)
+        + shared_string_heap_size(&edge.out_v_label)
+        + edge.parent.as_ref().map_or(0, vertex_heap_size)
+        + edge.properties.as_ref().map_or(0, |properties| {
+            vec_heap_size(properties, property_heap_size)
+        })
+}
+
+fn graph_edge_heap_size(edge: &GraphEdge) -> usize {
+    element_id_heap_size(&edge.id)
+        + shared_string_heap_size(&edge.label)
+        + element_id_heap_size(&edge.in_v_id)
+        + edge.in_v_label.as_ref().map_or(0, shared_string_heap_size)
+        + element_id_heap_size(&edge.out_v_id)
+        + edge.out_v_label.as_ref().map_or(0, shared_string_heap_size)
+        + edge.parent.as_ref().map_or(0, vertex_heap_size)
+        + vec_heap_size(&edge.properties, property_heap_size)
+}
+
+fn property_heap_size(property: &Property) -> usize {
+    let parent = match &property.parent {
+        EitherParent::Edge(edge) => edge_heap_size(edge),
+        EitherParent::VertexProperty(vertex_property) => vertex_property_heap_size(vertex_property),
+        EitherParent::None => 0,
+    };
+    shared_string_heap_size(&property.key)
+        + std::mem::size_of::<GremlinValue>()
+        + property.value.heap_size()
+        + parent
+}
+
+fn integral_f64_to_i64(val: f64) -> Option<i64> {
+    // i64::MAX as f64 rounds up to 2^63, so the upper bound has to be exclusive
+    if val.fract() == 0.0 && val >= i64::MIN as f64 && val < i64::MAX as f64 {
+        Some(val as i64)
+    } else {
+        None
+    }
+}
+
+// the unmatched value is handed back as error so the caller can try another variant
+#[allow(clippy::result_large_err)]
+impl GremlinValue {
+    /// Returns the `Bytecode` if the value is the `Bytecode` variant,
+    /// otherwise the unchanged value is returned as error.
+    ///
+    /// ```
+    /// # use tinkerpop_io::GremlinValue;
+    /// # use tinkerpop_io::structure::bytecode::Bytecode;
+    /// let mut bytecode = Bytecode::new();
+    /// bytecode.push_new_step("V", vec![]);
+    ///
+    /// assert_eq!(Ok(bytecode.clone()), GremlinValue::Bytecode(bytecode).into_bytecode());
+    /// assert_eq!(Err(GremlinValue::Int(1)), GremlinValue::Int(1).into_bytecode());
+    /// ```
+    pub fn into_bytecode(self) -> Result<Bytecode, GremlinValue> {
+        match self {
+            GremlinValue::Bytecode(bytecode) => Ok(bytecode),
+            value => Err(value),
+        }
+    }
+
+    /// Returns the `Vertex` if the value is the `Vertex` variant,
+    /// otherwise the unchanged value is returned as error.
+    pub fn into_vertex(self) -> Result<Vertex, GremlinValue> {
+        match self {
+            GremlinValue::Vertex(vertex) => Ok(vertex),
+            value => Err(value),
+        }
+    }
+
+    /// Returns the `Edge` if the value is the `Edge` variant,
+    /// otherwise the unchanged value is returned as error.
+    pub fn into_edge(self) -> Result<Edge, GremlinValue> {
+        match self {
+            GremlinValue::Edge(edge) => Ok(edge),
+            value => Err(value),
+        }
+    }
+
+    /// Returns the `VertexProperty` if the value is the `VertexProperty` variant,
+    /// otherwise the unchanged value is returned as error.
+    pub fn into_vertex_property(self) -> Result<VertexProperty, GremlinValue> {
+        match self {
+            GremlinValue::VertexProperty(vertex_property) => Ok(vertex_property),
+            value => Err(value),
+        }
+    }
+
+    /// Returns the `Property` if the value is the `Property` variant,
+    /// otherwise the unchanged value is returned as error.
+    pub fn into_property(self) -> Result<Property, GremlinValue> {
+        match self {
+            GremlinValue::Property(property) => Ok(property),
+            value => Err(value),
+        }
+    }
+
+    /// Returns the `Path` if the value is the `Path` variant,
+    /// otherwise the unchanged value is returned as error.
+    pub fn into_path(self) -> Result<Path, GremlinValue> {
+        match self {
+            GremlinValue::Path(path) => Ok(path),
+            value => Err(value),
+        }
+    }
+
+    /// Returns the `Graph` if the value is the `Graph` variant,
+    /// otherwise the unchanged value is returned as error.
+    pub fn into_graph(self) -> Result<Graph, GremlinValue> {
+        match self {
+            GremlinValue::Graph(graph) => Ok(graph),
+            value => Err(value),
+        }
+    }
+
+    /// Returns the `Tree` if the value is the `Tree` variant,
+    /// otherwise the unchanged value is returned as error.
+    pub fn into_tree(self) -> Result<Tree, GremlinValue> {
+        match self {
+            GremlinValue::Tree(tree) => Ok(tree),
+            value => Err(value),
+        }
+    }
+
+    /// Returns the `Traverser` if the value is the `Traverser` variant,
+    /// otherwise the unchanged value is returned as error.
+    pub fn into_traverser(self) -> Result<Traverser, GremlinValue> {
+        match self {
+            GremlinValue::Traverser(traverser) => Ok(traverser),
+            value => Err(value),
+        }
+    }
+
+    /// Returns the `BulkSet` if the value is the `BulkSet` variant,
+    /// otherwise the unchanged value is returned as error.
+    pub fn into_bulk_set(self) -> Result<BulkSet, GremlinValue> {
+        match self {
+            GremlinValue::BulkSet(bulk_set) => Ok(bulk_set),
+            value => Err(value),
+        }
+    }
+
+    /// Returns the `Metrics` if the value is the `Metrics` variant,
+    /// otherwise the unchanged value is returned as error.
+    pub fn into_metrics(self) -> Result<Metrics, GremlinValue> {
+        match self {
+            GremlinValue::Metrics(metrics) => Ok(metrics),
+            value => Err(value),
+        }
+    }
+
+    /// Returns the `TraversalMetrics` if the value is the `TraversalMetrics` variant,
+    /// otherwise the unchanged value is returned as error.
+    pub fn into_traversal_metrics(self) -> Result<TraversalMetrics, GremlinValue> {
+        match self {
+            GremlinValue::TraversalMetrics(traversal_metrics) => Ok(traversal_metrics),
+            value => Err(value),
+        }
+    }
+
+    /// Returns the `Lambda` if the value is the `Lambda` variant,
+    /// otherwise the unchanged value is returned as error.
+    pub fn into_lambda(self) -> Result<Lambda, GremlinValue> {
+        match self {
+            GremlinValue::Lambda(lambda) => Ok(lambda),
+            value => Err(value),
+        }
+    }
+
+    /// Returns the `Binding` if the value is the `Binding` variant,
+    /// otherwise the unchanged value is returned as error.
+    pub fn into_binding(self) -> Result<Binding, GremlinValue> {
+        match self {
+            GremlinValue::Binding(binding) => Ok(binding),
+            value => Err(value),
+        }
+    }
+}
+
+impl Display for GremlinValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GremlinValue::Int(val) => write!(f, "{val}_i32"),
+            GremlinValue::Long(val) => write!(f, "{val}_i64"),
+            GremlinValue::String(val) => write!(f, "\"{val}\""),
+            GremlinValue::Class(val) => write!(f, "Class:{val}"),
+            GremlinValue::Double(val) => write!(f, "{val}_f64"),
+            GremlinValue::Float(val) => write!(f, "{val}_f32"),
+            GremlinValue::List(val) => {
+                write!(f, "List::[")?;
+                for i in val {
+                    write!(f, " {i},")?;
+                }
+                write!(f, "]")
+            }
+            GremlinValue::Set(val) => write!(f, "Set::{val}"),
+            GremlinValue::Map(val) => {
+                write!(f, "Map::[")?;
+                for (key, value) in val {
+                    writeln!(f, "{{{key}:{value}}},")?;
+                }
+                write!(f, "]")
+            }
+            GremlinValue::Uuid(val) => write!(f, "Uuid::{val}"),
+            GremlinValue::Edge(val) => write!(f, "Edge::{val}"),
+            GremlinValue::Path(val) => write!(f, "Path::{val}"),
+            GremlinValue::Property(val) => write!(f, "Property::{val}"),
+            GremlinValue::Graph(val) => write!(f, "Graph::{val}"),
+            GremlinValue::Vertex(val) => write!(f, "Vertex::{val}"),
+            GremlinValue::VertexProperty(val) => write!(f, "VertexProperty::{val}"),
+            GremlinValue::Barrier(val) => write!(f, "Barrier::{val}"),
+            GremlinValue::Binding(val) => write!(f, "Binding::{val}"),
+            GremlinValue::Bytecode(val) => write!(f, "Bytecode::{val}"),
+            GremlinValue::Cardinality(val) => write!(f, "Cardinality::{val}"),
+            GremlinValue::Column(val) => write!(f, "Column::{val}"),
+            GremlinValue::Direction(val) => write!(f, "Direction::{val}"),
+            GremlinValue::Operator(val) => write!(f, "Operator::{val}"),
+            GremlinValue::Order(val) => write!(f, "Order::{val}"),
+            GremlinValue::Pick(val) => write!(f, "Pick::{val}"),
+            GremlinValue::Pop(val) => write!(f, "Pop::{val}"),
+            GremlinValue::Lambda(val) => write!(f, "Lambda::{val}"),
+            GremlinValue::P(val) => write!(f, "P::{val}"),
+            GremlinValue::Scope(val) => write!(f, "Scope::{val}"),
+            GremlinValue::T(val) => write!(f, "T::{val}"),
+            GremlinValue::BigDecimal(val) => write!(f, "BigDecimal::{val}"),
+            GremlinValue::BigInteger(val) => write!(f, "BigInteger::{val}"),
+            GremlinValue::Traverser(val) => write!(f, "Traverser::{val}"),
+            GremlinValue::Byte(val) => write!(f, "{val}_u8"),
+            GremlinValue::ByteBuffer(val) => write!(f, "ByteBuffer::{val}_u8"),
+            GremlinValue::Short(val) => write!(f, "{val}_i16"),
+            GremlinValue::Boolean(val) => write!(f, "{val}"),
+            GremlinValue::TextP(val) => write!(f, "TextP::{val}"),
+            GremlinValue::TraversalStrategy(val) => write!(f, "TraversalStrategy::{val}"),
+            GremlinValue::BulkSet(val) => write!(f, "BulkSet::{val}"),
+            GremlinValue::Tree(val) => write!(f, "Tree::{val}"),
+            GremlinValue::Metrics(val) => write!(f, "{val}"),
+            GremlinValue::TraversalMetrics(val) => write!(f, "{val}"),
+            GremlinValue::Merge(val) => write!(f, "Merge::{val}"),
+            GremlinValue::UnspecifiedNullObject => write!(f, "UnspecifiedNullObject"),
+            GremlinValue::Date(val) => write!(f, "Date::{val}"),
+            GremlinValue::Timestamp(val) => write!(f, "Timestamp::{val}"),
             GremlinValue::Char(val) => write!(f, "{val}"),
             #[cfg(feature = "extended")]
             GremlinValue::Duration(val) => write!(f, "Duration::{val}"),
@@ -50,3 +50,67 @@ macro_rules! conversion {
         }
     };
 }
+
+/// Like [`conversion`], but `TryFrom<GremlinValue>` additionally accepts the wider integer
+/// variants `Byte`, `Short`, `Int` and `Long` if the value is in range of `$t`.
+/// The attributes are attached to the `TryFrom` impl.
+#[macro_export]
+macro_rules! narrowing_conversion {
+    ($(#[$meta:meta])* $t:ty,$variant:ident) => {
+        impl From<$t> for $crate::GremlinValue {
+            fn from(g: $t) -> Self {
+                $crate::GremlinValue::$variant(g)
+            }
+        }
+
+        $(#[$meta])*
+        impl TryFrom<$crate::GremlinValue> for $t {
+            type Error = $crate::error::DecodeError;
+
+            fn try_from(value: $crate::GremlinValue) -> Result<Self, Self::Error> {
+                let out_of_range = |val: &dyn std::fmt::Display| {
+                    $crate::error::DecodeError::ConvertError(format!(
+                        "{val} is out of range of {}",
+                        stringify!($t)
+                    ))
+                };
+                match value {
+                    $crate::GremlinValue::Byte(val) => {
+                        <$t>::try_from(val).map_err(|_| out_of_range(&val))
+                    }
+                    $crate::GremlinValue::Short(val) => {
+                        <$t>::try_from(val).map_err(|_| out_of_range(&val))
+                    }
+                    $crate::GremlinValue::Int(val) => {
+                        <$t>::try_from(val).map_err(|_| out_of_range(&val))
+                    }
+                    $crate::GremlinValue::Long(val) => {
+                        <$t>::try_from(val).map_err(|_| out_of_range(&val))
+                    }
+                    _ => Err($crate::error::DecodeError::ConvertError(format!(
+                        "cannot convert Value to {}",
+                        stringify!($t)
+                    ))),
+                }
+            }
+        }
+
+        impl $crate::macros::TryBorrowFrom for $t {
+            fn try_borrow_from(graph_binary: &$crate::GremlinValue) -> Option<&Self> {
+                match graph_binary {
+                    $crate::GremlinValue::$variant(val) => Some(val),
+                    _ => None,
+                }
+            }
+        }
+
+        impl $crate::macros::TryMutBorrowFrom for $t {
+            fn try_mut_borrow_from(graph_binary: &mut $crate::GremlinValue) -> Option<&mut Self> {
+                match graph_binary {
+                    $crate::GremlinValue::$variant(val) => Some(val),
+                    _ => None,
+                }
+            }
+        }
+    };
+}
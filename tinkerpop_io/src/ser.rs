@@ -75,6 +75,14 @@ impl serde::Serializer for GremlinValueSerializer {
         todo!()
     }
 
+    fn serialize_i128(self, v: i128) -> Result<Self::Ok, Self::Error> {
+        Ok(GremlinValue::from(v))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok, Self::Error> {
+        Ok(GremlinValue::from(v))
+    }
+
     fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
         Ok(GremlinValue::Float(v))
     }
@@ -84,9 +92,6 @@ impl serde::Serializer for GremlinValueSerializer {
     }
 
     fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
-        #[cfg(not(feature = "extended"))]
-        unimplemented!("serializing char needs the extended flag");
-        #[cfg(feature = "extended")]
         Ok(GremlinValue::Char(v))
     }
 
@@ -356,7 +361,52 @@ fn struct_to_gb2() {
 
     let test = TestStruct(1);
     let gb = to_graph_binary(&test).unwrap();
-    let expected = GremlinValue::Int(1.into());
+    let expected = GremlinValue::Int(1);
 
     assert_eq!(expected, gb);
 }
+
+#[cfg(feature = "graph_binary")]
+#[test]
+fn u128_to_gb() {
+    use crate::binary::{from_slice, to_writer};
+    use crate::de::from_gremlin;
+
+    let gb = to_graph_binary(&u128::MAX).unwrap();
+    assert_eq!(gb, GremlinValue::BigInteger(num::BigInt::from(u128::MAX)));
+
+    let mut buf = Vec::new();
+    to_writer(gb, &mut buf).unwrap();
+    let gb: GremlinValue = from_slice(&mut &buf[..]).unwrap();
+
+    assert_eq!(u128::try_from(gb.clone()).unwrap(), u128::MAX);
+    assert_eq!(from_gremlin::<u128>(gb).unwrap(), u128::MAX);
+    assert_eq!(
+        from_gremlin::<i128>(to_graph_binary(&i128::MIN).unwrap()).unwrap(),
+        i128::MIN
+    );
+}
+
+#[cfg(feature = "graph_binary")]
+#[test]
+fn struct_with_i16_to_gb() {
+    use crate::binary::to_writer;
+
+    #[derive(Debug, Serialize)]
+    struct TestStruct {
+        s: i16,
+    }
+
+    let gb = to_graph_binary(&TestStruct { s: -2 }).unwrap();
+    assert_eq!(
+        GremlinValue::Map(HashMap::from([("s".into(), GremlinValue::Short(-2))])),
+        gb
+    );
+
+    let mut buf = Vec::new();
+    to_writer(gb, &mut buf).unwrap();
+    let expected = [
+        0x0a, 0x0, 0x0, 0x0, 0x0, 0x1, 0x03, 0x0, 0x0, 0x0, 0x0, 0x1, b's', 0x26, 0x0, 0xff, 0xfe,
+    ];
+    assert_eq!(expected, buf[..]);
+}
@@ -40,7 +40,7 @@ const CORE_TYPE_BOOLEAN: u8 = 0x27;
 const CORE_TYPE_TEXT_P: u8 = 0x28;
 const CORE_TYPE_TRAVERSAL_STRATEGY: u8 = 0x29;
 const CORE_TYPE_BULK_SET: u8 = 0x2a;
-// const CORE_TYPE_TREE: u8 = 0x2b;
+const CORE_TYPE_TREE: u8 = 0x2b;
 const CORE_TYPE_METRICS: u8 = 0x2c;
 const CORE_TYPE_TRAVERSAL_METRICS: u8 = 0x2d;
 const CORE_TYPE_MERGE: u8 = 0x2e;
@@ -63,8 +63,9 @@ const EXTENDED_TYPE_YEAR_MONTH: u8 = 0x8c;
 const EXTENDED_TYPE_ZONED_DATETIME: u8 = 0x8d;
 const EXTENDED_TYPE_ZONED_OFFSET: u8 = 0x8f;
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-pub(crate) enum CoreType {
+/// The type codes of the GraphBinary format.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum CoreType {
     Int32,
     Long,
     String,
@@ -107,7 +108,7 @@ pub(crate) enum CoreType {
     TextP,
     TraversalStrategy,
     BulkSet,
-    // Tree,
+    Tree,
     Metrics,
     TraversalMetrics,
     Merge,
@@ -175,7 +176,7 @@ impl From<CoreType> for u8 {
             CoreType::ByteBuffer => CORE_TYPE_BYTE_BUFFER,
             CoreType::TextP => CORE_TYPE_TEXT_P,
             CoreType::TraversalStrategy => CORE_TYPE_TRAVERSAL_STRATEGY,
-            // CoreType::Tree => CORE_TYPE_TREE,
+            CoreType::Tree => CORE_TYPE_TREE,
             CoreType::Metrics => CORE_TYPE_METRICS,
             CoreType::TraversalMetrics => CORE_TYPE_TRAVERSAL_METRICS,
             CoreType::BulkSet => CORE_TYPE_BULK_SET,
@@ -251,7 +252,7 @@ impl TryFrom<u8> for CoreType {
             CORE_TYPE_TEXT_P => Ok(CoreType::TextP),
             CORE_TYPE_TRAVERSAL_STRATEGY => Ok(CoreType::TraversalStrategy),
             CORE_TYPE_BULK_SET => Ok(CoreType::BulkSet),
-            // CORE_TYPE_TREE => Ok(CoreType::Tree),
+            CORE_TYPE_TREE => Ok(CoreType::Tree),
             CORE_TYPE_METRICS => Ok(CoreType::Metrics),
             CORE_TYPE_TRAVERSAL_METRICS => Ok(CoreType::TraversalMetrics),
             CORE_TYPE_MERGE => Ok(CoreType::Merge),
@@ -277,3 +278,114 @@ impl TryFrom<u8> for CoreType {
         }
     }
 }
+
+macro_rules! graphson_identifiers {
+    ($(($core_type:ident, $identifier:literal)),*$(,)?) => {
+        /// Returns the GraphSON `@type` identifier of the type, None if it is written without
+        /// one. `String`, `Boolean`, `Custom` and `UnspecifiedNullObject` are written as plain
+        /// JSON.
+        #[cfg(feature = "graph_son")]
+        #[must_use]
+        pub fn graphson_identifier(core_type: CoreType) -> Option<&'static str> {
+            match core_type {
+                $(CoreType::$core_type => Some($identifier),)*
+                _ => None,
+            }
+        }
+
+        /// Returns the type of a GraphSON `@type` identifier, the inverse of
+        /// [`graphson_identifier`].
+        #[cfg(feature = "graph_son")]
+        #[must_use]
+        pub fn core_type_from_graphson(identifier: &str) -> Option<CoreType> {
+            match identifier {
+                $($identifier => Some(CoreType::$core_type),)*
+                _ => None,
+            }
+        }
+
+        #[cfg(all(feature = "graph_son", test))]
+        const GRAPHSON_IDENTIFIERS: &[(CoreType, &str)] = &[$((CoreType::$core_type, $identifier)),*];
+    };
+}
+
+// The single source of the GraphSON `@type` identifiers, both lookups are generated from it.
+graphson_identifiers!(
+    (Int32, "g:Int32"),
+    (Long, "g:Int64"),
+    (Date, "g:Date"),
+    (Timestamp, "g:Timestamp"),
+    (Class, "g:Class"),
+    (Double, "g:Double"),
+    (Float, "g:Float"),
+    (List, "g:List"),
+    (Set, "g:Set"),
+    (Map, "g:Map"),
+    (Uuid, "g:UUID"),
+    (Edge, "g:Edge"),
+    (Path, "g:Path"),
+    (Property, "g:Property"),
+    (Graph, "tinker:graph"),
+    (Vertex, "g:Vertex"),
+    (VertexProperty, "g:VertexProperty"),
+    (Barrier, "g:Barrier"),
+    (Binding, "g:Binding"),
+    (ByteCode, "g:Bytecode"),
+    (Cardinality, "g:Cardinality"),
+    (Column, "g:Column"),
+    (Direction, "g:Direction"),
+    (Operator, "g:Operator"),
+    (Order, "g:Order"),
+    (Pick, "g:Pick"),
+    (Pop, "g:Pop"),
+    (Lambda, "g:Lambda"),
+    (P, "g:P"),
+    (Scope, "g:Scope"),
+    (T, "g:T"),
+    (Traverser, "g:Traverser"),
+    (BigInteger, "gx:BigInteger"),
+    (BigDecimal, "gx:BigDecimal"),
+    (Byte, "gx:Byte"),
+    (ByteBuffer, "gx:ByteBuffer"),
+    (Short, "gx:Int16"),
+    (TextP, "g:TextP"),
+    (TraversalStrategy, "g:TraversalStrategy"),
+    (BulkSet, "g:BulkSet"),
+    (Tree, "g:Tree"),
+    (Metrics, "g:Metrics"),
+    (TraversalMetrics, "g:TraversalMetrics"),
+    (Merge, "g:Merge"),
+    (Char, "gx:Char"),
+    (Duration, "gx:Duration"),
+    (InetAddress, "gx:InetAddress"),
+    (Instant, "gx:Instant"),
+    (LocalDate, "gx:LocalDate"),
+    (LocalDateTime, "gx:LocalDateTime"),
+    (LocalTime, "gx:LocalTime"),
+    (MonthDay, "gx:MonthDay"),
+    (OffsetDateTime, "gx:OffsetDateTime"),
+    (OffsetTime, "gx:OffsetTime"),
+    (Period, "gx:Period"),
+    (Year, "gx:Year"),
+    (YearMonth, "gx:YearMonth"),
+    (ZonedDateTime, "gx:ZonedDateTime"),
+    (ZoneOffset, "gx:ZoneOffset"),
+);
+
+#[cfg(feature = "graph_son")]
+#[test]
+fn graphson_identifier_table() {
+    assert_eq!(Some("g:Vertex"), graphson_identifier(CoreType::Vertex));
+    assert_eq!(Some("tinker:graph"), graphson_identifier(CoreType::Graph));
+    assert_eq!(
+        Some(CoreType::Graph),
+        core_type_from_graphson("tinker:graph")
+    );
+    assert_eq!(None, core_type_from_graphson("g:Graph"));
+    assert_eq!(None, graphson_identifier(CoreType::String));
+
+    for &(core_type, identifier) in GRAPHSON_IDENTIFIERS {
+        assert_eq!(Some(core_type), core_type_from_graphson(identifier));
+        assert_eq!(Some(identifier), graphson_identifier(core_type));
+    }
+}
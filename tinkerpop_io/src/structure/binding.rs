@@ -3,6 +3,7 @@ use std::fmt::Display;
 use crate::{conversion, GremlinValue};
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub struct Binding {
     pub(crate) key: String,
     pub(crate) value: Box<GremlinValue>,
@@ -10,9 +11,9 @@ pub struct Binding {
 
 impl Binding {
     #[must_use]
-    pub fn new(key: &str, value: impl Into<GremlinValue>) -> Self {
+    pub fn new(key: impl Into<String>, value: impl Into<GremlinValue>) -> Self {
         Binding {
-            key: key.to_owned(),
+            key: key.into(),
             value: Box::new(value.into()),
         }
     }
@@ -46,3 +47,11 @@ impl Display for Binding {
 }
 
 conversion!(Binding, Binding);
+
+#[test]
+fn binding_new() {
+    let binding = Binding::new(String::from("x"), 1_i64);
+    assert_eq!("x", binding.key());
+    assert_eq!(&GremlinValue::Long(1), binding.value());
+    assert_eq!(Binding::from(("x", 1_i64)), binding);
+}
@@ -6,6 +6,7 @@ use std::{
 use crate::GremlinValue;
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub struct BulkSet(pub(crate) Vec<(GremlinValue, i64)>);
 
 impl BulkSet {
@@ -3,12 +3,14 @@ use std::fmt::Display;
 use crate::{conversion, GremlinValue};
 
 #[derive(Debug, PartialEq, Default, Clone)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub struct Bytecode {
     pub(crate) steps: Vec<Step>,
     pub(crate) sources: Vec<Source>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct Step {
     pub name: String,
     pub values: Vec<GremlinValue>,
@@ -25,6 +27,7 @@ impl Display for Step {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct Source {
     pub name: String,
     pub values: Vec<GremlinValue>,
@@ -1,17 +1,27 @@
-use std::fmt::Display;
+use std::{fmt::Display, sync::Arc};
 
 use crate::conversion;
 
-use super::{id::ElementId, property::Property, vertex::Vertex};
+use super::{
+    id::ElementId,
+    property::{EitherParent, Property},
+    vertex::Vertex,
+};
+use crate::GremlinValue;
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub struct Edge {
     pub id: ElementId,
-    pub label: String,
+    pub label: Arc<str>,
+    #[cfg_attr(feature = "serde-derive", serde(rename = "inV"))]
     pub in_v_id: ElementId,
-    pub in_v_label: String,
+    #[cfg_attr(feature = "serde-derive", serde(rename = "inVLabel"))]
+    pub in_v_label: Arc<str>,
+    #[cfg_attr(feature = "serde-derive", serde(rename = "outV"))]
     pub out_v_id: ElementId,
-    pub out_v_label: String,
+    #[cfg_attr(feature = "serde-derive", serde(rename = "outVLabel"))]
+    pub out_v_label: Arc<str>,
     pub parent: Option<Vertex>,
     pub properties: Option<Vec<Property>>,
 }
@@ -33,7 +43,7 @@ impl Edge {
 
     pub fn out_v<T: Into<ElementId>>(&mut self, id: T, out_label: &str) -> &mut Self {
         self.out_v_id = id.into();
-        self.out_v_label = out_label.to_string();
+        self.out_v_label = out_label.into();
         self
     }
 
@@ -44,7 +54,7 @@ impl Edge {
     }
     pub fn in_v<T: Into<ElementId>>(&mut self, id: T, in_label: &str) -> &mut Self {
         self.in_v_id = id.into();
-        self.in_v_label = in_label.to_string();
+        self.in_v_label = in_label.into();
         self
     }
     pub fn in_vertex(&mut self, v: Vertex) -> &mut Self {
@@ -52,6 +62,26 @@ impl Edge {
         self.in_v_label = v.label;
         self
     }
+
+    #[must_use]
+    pub fn property(&self, key: &str) -> Option<&Property> {
+        self.properties_iter()
+            .find(|property| &*property.key == key)
+    }
+
+    /// Replaces the value of the property `key` or inserts a new property if `key` is not present.
+    pub fn set_property(&mut self, key: &str, value: impl Into<GremlinValue>) -> &mut Self {
+        let properties = self.properties.get_or_insert_with(Vec::new);
+        match properties.iter_mut().find(|property| &*property.key == key) {
+            Some(property) => *property.value = value.into(),
+            None => properties.push(Property::new(key, value, EitherParent::None)),
+        }
+        self
+    }
+
+    pub fn properties_iter(&self) -> impl Iterator<Item = &Property> {
+        self.properties.iter().flatten()
+    }
 }
 
 impl Display for Edge {
@@ -75,3 +105,34 @@ impl Display for Edge {
 }
 
 conversion!(Edge, Edge);
+
+#[test]
+fn edge_set_property() {
+    let mut edge = Edge {
+        id: 7.into(),
+        label: "knows".into(),
+        in_v_id: 2.into(),
+        in_v_label: "person".into(),
+        out_v_id: 1.into(),
+        out_v_label: "person".into(),
+        parent: None,
+        properties: None,
+    };
+    assert!(edge.property("weight").is_none());
+
+    edge.set_property("weight", 0.5_f64);
+    assert_eq!(
+        Some(&GremlinValue::Double(0.5)),
+        edge.property("weight")
+            .map(|property| property.value.as_ref())
+    );
+
+    edge.set_property("weight", 1.0_f64)
+        .set_property("since", 2010);
+    assert_eq!(2, edge.properties_iter().count());
+    assert_eq!(
+        Some(&GremlinValue::Double(1.0)),
+        edge.property("weight")
+            .map(|property| property.value.as_ref())
+    );
+}
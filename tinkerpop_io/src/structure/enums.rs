@@ -1,9 +1,13 @@
 use std::fmt::Display;
 use std::marker::PhantomData;
 
-use crate::{error::DecodeError, GremlinValue};
+use crate::{
+    error::{DecodeError, OperatorError},
+    GremlinValue,
+};
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub enum Barrier {
     NormSack,
 }
@@ -28,6 +32,7 @@ impl Barrier {
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub enum Cardinality {
     List,
     Set,
@@ -58,6 +63,7 @@ impl Cardinality {
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub enum Column {
     Keys,
     Values,
@@ -85,6 +91,7 @@ impl TryFrom<&str> for Column {
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub enum Direction {
     Both,
     In,
@@ -112,9 +119,35 @@ impl Direction {
             Direction::Out => "OUT",
         }
     }
+
+    /// Returns the opposite `Direction`, `Both` stays `Both`.
+    #[must_use]
+    pub const fn opposite(self) -> Direction {
+        match self {
+            Direction::Both => Direction::Both,
+            Direction::In => Direction::Out,
+            Direction::Out => Direction::In,
+        }
+    }
+
+    #[must_use]
+    pub const fn is_out(&self) -> bool {
+        matches!(self, Direction::Out)
+    }
+
+    #[must_use]
+    pub const fn is_in(&self) -> bool {
+        matches!(self, Direction::In)
+    }
+
+    #[must_use]
+    pub const fn is_both(&self) -> bool {
+        matches!(self, Direction::Both)
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub enum Operator {
     AddAll,
     And,
@@ -147,6 +180,121 @@ impl Operator {
     }
 }
 
+impl Operator {
+    /// Applies the operator to `a` and `b` as the server would in `sack()` or `fold()`.
+    ///
+    /// `sum`, `minus`, `mult`, `div`, `min` and `max` keep the variant of operands with the same
+    /// numeric variant, mixed integral operands are widened to `Long` and mixed operands including
+    /// a floating point are widened to `Double`. `sumLong` always returns a `Long`, `and` and `or`
+    /// expect `Boolean` operands and `assign` returns `b`. `addAll` is not supported.
+    ///
+    /// ```
+    /// # use tinkerpop_io::GremlinValue;
+    /// # use tinkerpop_io::structure::enums::Operator;
+    /// assert_eq!(
+    ///     GremlinValue::Long(5),
+    ///     Operator::Sum.apply(&GremlinValue::Int(2), &GremlinValue::Long(3)).unwrap()
+    /// );
+    /// assert_eq!(
+    ///     GremlinValue::Boolean(false),
+    ///     Operator::And
+    ///         .apply(&GremlinValue::Boolean(true), &GremlinValue::Boolean(false))
+    ///         .unwrap()
+    /// );
+    /// ```
+    pub fn apply(&self, a: &GremlinValue, b: &GremlinValue) -> Result<GremlinValue, OperatorError> {
+        match self {
+            Operator::And | Operator::Or => match (a, b) {
+                (GremlinValue::Boolean(a), GremlinValue::Boolean(b)) => {
+                    Ok(GremlinValue::Boolean(if self == &Operator::And {
+                        *a && *b
+                    } else {
+                        *a || *b
+                    }))
+                }
+                _ => Err(OperatorError::NotBoolean(*self)),
+            },
+            Operator::Assign => Ok(b.clone()),
+            Operator::SumLong => match (a.as_i64(), b.as_i64()) {
+                (Some(a), Some(b)) => a
+                    .checked_add(b)
+                    .map(GremlinValue::Long)
+                    .ok_or(OperatorError::Overflow(*self)),
+                _ => Err(OperatorError::NotNumeric(*self)),
+            },
+            Operator::Sum
+            | Operator::Minus
+            | Operator::Mult
+            | Operator::Div
+            | Operator::Min
+            | Operator::Max => self.apply_numeric(a, b),
+            Operator::AddAll => Err(OperatorError::Unsupported(*self)),
+        }
+    }
+
+    fn apply_numeric(
+        &self,
+        a: &GremlinValue,
+        b: &GremlinValue,
+    ) -> Result<GremlinValue, OperatorError> {
+        match (a, b) {
+            (GremlinValue::Byte(a), GremlinValue::Byte(b)) => {
+                self.apply_integral(*a, *b).map(GremlinValue::Byte)
+            }
+            (GremlinValue::Short(a), GremlinValue::Short(b)) => {
+                self.apply_integral(*a, *b).map(GremlinValue::Short)
+            }
+            (GremlinValue::Int(a), GremlinValue::Int(b)) => {
+                self.apply_integral(*a, *b).map(GremlinValue::Int)
+            }
+            (GremlinValue::Long(a), GremlinValue::Long(b)) => {
+                self.apply_integral(*a, *b).map(GremlinValue::Long)
+            }
+            (GremlinValue::Float(a), GremlinValue::Float(b)) => Ok(GremlinValue::Float(
+                self.apply_float(f64::from(*a), f64::from(*b)) as f32,
+            )),
+            (GremlinValue::Float(_) | GremlinValue::Double(_), _)
+            | (_, GremlinValue::Float(_) | GremlinValue::Double(_)) => {
+                match (a.as_f64(), b.as_f64()) {
+                    (Some(a), Some(b)) => Ok(GremlinValue::Double(self.apply_float(a, b))),
+                    _ => Err(OperatorError::NotNumeric(*self)),
+                }
+            }
+            _ => match (a.as_i64(), b.as_i64()) {
+                (Some(a), Some(b)) => self.apply_integral(a, b).map(GremlinValue::Long),
+                _ => Err(OperatorError::NotNumeric(*self)),
+            },
+        }
+    }
+
+    fn apply_integral<N: num::PrimInt>(&self, a: N, b: N) -> Result<N, OperatorError> {
+        match self {
+            Operator::Div if b.is_zero() => return Err(OperatorError::DivisionByZero),
+            Operator::Min => return Ok(a.min(b)),
+            Operator::Max => return Ok(a.max(b)),
+            _ => {}
+        }
+        match self {
+            Operator::Sum => a.checked_add(&b),
+            Operator::Minus => a.checked_sub(&b),
+            Operator::Mult => a.checked_mul(&b),
+            _ => a.checked_div(&b),
+        }
+        .ok_or(OperatorError::Overflow(*self))
+    }
+
+    fn apply_float(&self, a: f64, b: f64) -> f64 {
+        match self {
+            Operator::Sum => a + b,
+            Operator::Minus => a - b,
+            Operator::Mult => a * b,
+            Operator::Div => a / b,
+            Operator::Min => a.min(b),
+            _ => a.max(b),
+        }
+    }
+}
+
 impl TryFrom<&str> for Operator {
     type Error = DecodeError;
 
@@ -169,6 +317,7 @@ impl TryFrom<&str> for Operator {
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub enum Order {
     Shuffle,
     Asc,
@@ -199,6 +348,7 @@ impl Order {
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub enum Pick {
     Any,
     None,
@@ -226,6 +376,7 @@ impl Pick {
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub enum Pop {
     All,
     First,
@@ -259,6 +410,7 @@ impl Pop {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub struct P<T> {
     pub(crate) predicate: String,
     pub(crate) value: Vec<GremlinValue>,
@@ -406,6 +558,7 @@ impl Display for P<GremlinValue> {
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub enum Scope {
     Local,
     Global,
@@ -433,6 +586,7 @@ impl TryFrom<&str> for Scope {
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub enum T {
     Id,
     Key,
@@ -466,6 +620,7 @@ impl TryFrom<&str> for T {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextP {
     pub(crate) predicate: String,
     pub(crate) value: Vec<GremlinValue>,
@@ -566,6 +721,7 @@ impl Display for TextP {
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub enum Merge {
     OnCreate,
     OnMatch,
@@ -609,6 +765,14 @@ macro_rules! enum_conversion {
                 }
             }
 
+            impl std::str::FromStr for $t {
+                type Err = $crate::error::DecodeError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    $t::try_from(s)
+                }
+            }
+
             impl TryFrom<GremlinValue> for $t {
                 type Error = $crate::error::DecodeError;
 
@@ -656,3 +820,57 @@ enum_conversion!(
     T,
     Merge
 );
+
+#[test]
+fn direction_opposite() {
+    assert_eq!(Direction::Out.opposite(), Direction::In);
+    assert_eq!(Direction::In.opposite(), Direction::Out);
+    assert_eq!(Direction::Both.opposite(), Direction::Both);
+    assert!(Direction::Out.is_out());
+    assert!(Direction::In.is_in());
+    assert!(Direction::Both.is_both());
+    assert!(!Direction::Both.is_out());
+}
+
+#[test]
+fn operator_apply() {
+    assert_eq!(
+        GremlinValue::Int(5),
+        Operator::Sum
+            .apply(&GremlinValue::Int(2), &GremlinValue::Int(3))
+            .unwrap()
+    );
+    assert_eq!(
+        GremlinValue::Int(6),
+        Operator::Mult
+            .apply(&GremlinValue::Int(2), &GremlinValue::Int(3))
+            .unwrap()
+    );
+    assert_eq!(
+        GremlinValue::Double(2.0),
+        Operator::Min
+            .apply(&GremlinValue::Double(2.0), &GremlinValue::Int(3))
+            .unwrap()
+    );
+    assert_eq!(
+        GremlinValue::Long(3),
+        Operator::Max
+            .apply(&GremlinValue::Long(2), &GremlinValue::Int(3))
+            .unwrap()
+    );
+    assert_eq!(
+        GremlinValue::Boolean(true),
+        Operator::Or
+            .apply(&GremlinValue::Boolean(false), &GremlinValue::Boolean(true))
+            .unwrap()
+    );
+    assert!(Operator::Sum
+        .apply(&GremlinValue::Int(i32::MAX), &GremlinValue::Int(1))
+        .is_err());
+    assert!(Operator::Div
+        .apply(&GremlinValue::Int(1), &GremlinValue::Int(0))
+        .is_err());
+    assert!(Operator::And
+        .apply(&GremlinValue::Int(1), &GremlinValue::Boolean(true))
+        .is_err());
+}
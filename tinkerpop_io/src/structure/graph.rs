@@ -1,10 +1,13 @@
+use std::collections::BTreeMap;
 use std::fmt::Display;
+use std::sync::Arc;
 
 use crate::conversion;
 
 use super::{edge::Edge, id::ElementId, property::Property, vertex::Vertex};
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub struct Graph {
     pub(crate) vertices: Vec<Vertex>,
     pub(crate) edges: Vec<GraphEdge>,
@@ -19,15 +22,58 @@ impl Graph {
         &mut self.vertices
     }
 
-    // pub fn edges(&self) -> &Vec<GraphEdge> {
-    //     &self.edges
-    // }
+    /// Compares two graphs independent of the order of their vertices and edges.
+    /// Vertices and edges are matched by id and have to equal their counterpart, elements
+    /// sharing an id are compared as a multiset.
+    #[must_use]
+    pub fn semantic_eq(&self, other: &Graph) -> bool {
+        same_elements(&self.vertices, &other.vertices, |vertex| &vertex.id)
+            && same_elements(&self.edges, &other.edges, |edge| &edge.id)
+    }
+
+    pub fn edges(&self) -> &Vec<GraphEdge> {
+        &self.edges
+    }
 
     // pub fn edges_mut(&mut self) -> &mut Vec<GraphEdge> {
     //     &mut self.edges
     // }
 }
 
+fn same_elements<T: PartialEq>(left: &[T], right: &[T], id: impl Fn(&T) -> &ElementId) -> bool {
+    let right = by_id(right, &id);
+    left.len() == right.values().map(Vec::len).sum::<usize>()
+        && by_id(left, &id).iter().all(|(id, left)| {
+            right
+                .get(id)
+                .is_some_and(|right| same_multiset(left, right))
+        })
+}
+
+/// Groups the elements by id, elements sharing an id are all kept.
+fn by_id<'a, T>(
+    elements: &'a [T],
+    id: &impl Fn(&T) -> &ElementId,
+) -> BTreeMap<&'a ElementId, Vec<&'a T>> {
+    let mut groups = BTreeMap::<_, Vec<_>>::new();
+    for element in elements {
+        groups.entry(id(element)).or_default().push(element);
+    }
+    groups
+}
+
+fn same_multiset<T: PartialEq>(left: &[&T], right: &[&T]) -> bool {
+    let mut unmatched = right.to_vec();
+    left.len() == right.len()
+        && left.iter().all(|element| {
+            unmatched
+                .iter()
+                .position(|candidate| candidate == element)
+                .map(|i| unmatched.swap_remove(i))
+                .is_some()
+        })
+}
+
 impl Display for Graph {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "vertexes:[")?;
@@ -44,13 +90,18 @@ impl Display for Graph {
 }
 
 #[derive(Debug, PartialEq, Clone)]
-pub(crate) struct GraphEdge {
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
+pub struct GraphEdge {
     pub id: ElementId,
-    pub label: String,
+    pub label: Arc<str>,
+    #[cfg_attr(feature = "serde-derive", serde(rename = "inV"))]
     pub in_v_id: ElementId,
-    pub in_v_label: Option<String>,
+    #[cfg_attr(feature = "serde-derive", serde(rename = "inVLabel"))]
+    pub in_v_label: Option<Arc<str>>,
+    #[cfg_attr(feature = "serde-derive", serde(rename = "outV"))]
     pub out_v_id: ElementId,
-    pub out_v_label: Option<String>,
+    #[cfg_attr(feature = "serde-derive", serde(rename = "outVLabel"))]
+    pub out_v_label: Option<Arc<str>>,
     pub parent: Option<Vertex>,
     pub properties: Vec<Property>,
 }
@@ -104,3 +155,96 @@ impl Display for GraphEdge {
 }
 
 conversion!(Graph, Graph);
+
+#[test]
+fn graph_semantic_eq() {
+    let marko = Vertex::new(1, "person", None);
+    let vadas = Vertex::new(2, "person", None);
+    let lop = Vertex::new(3, "software", None);
+    let knows = GraphEdge {
+        id: 7.into(),
+        label: "knows".into(),
+        in_v_id: 2.into(),
+        in_v_label: None,
+        out_v_id: 1.into(),
+        out_v_label: None,
+        parent: None,
+        properties: vec![],
+    };
+    let mut created = knows.clone();
+    created.id = 9.into();
+    created.label = "created".into();
+    created.in_v_id = 3.into();
+
+    let graph = Graph {
+        vertices: vec![marko.clone(), vadas.clone(), lop.clone()],
+        edges: vec![knows.clone(), created.clone()],
+    };
+    let shuffled = Graph {
+        vertices: vec![lop.clone(), marko.clone(), vadas],
+        edges: vec![created, knows.clone()],
+    };
+    let missing = Graph {
+        vertices: vec![lop, marko],
+        edges: vec![knows],
+    };
+
+    assert_ne!(graph, shuffled);
+    assert!(graph.semantic_eq(&shuffled));
+    assert!(!graph.semantic_eq(&missing));
+
+    let hash = |graph: &Graph| crate::GremlinValue::Graph(graph.clone()).stable_hash();
+    assert_eq!(hash(&graph), hash(&shuffled));
+    assert_ne!(hash(&graph), hash(&missing));
+}
+
+#[test]
+fn graph_semantic_eq_duplicate_ids() {
+    let marko = Vertex::new(1, "person", None);
+    let lop = Vertex::new(3, "software", None);
+    let mut renamed = lop.clone();
+    renamed.label = "renamed".into();
+    let graph = |vertices: Vec<Vertex>| Graph {
+        vertices,
+        edges: Vec::new(),
+    };
+
+    let duplicated = graph(vec![lop.clone(), lop.clone(), marko.clone()]);
+    assert!(duplicated.semantic_eq(&duplicated.clone()));
+    assert!(!duplicated.semantic_eq(&graph(vec![lop.clone(), marko.clone(), marko.clone()])));
+
+    let conflicting = graph(vec![lop.clone(), renamed.clone(), marko.clone()]);
+    assert!(conflicting.semantic_eq(&graph(vec![renamed, marko, lop])));
+    assert!(!conflicting.semantic_eq(&duplicated));
+}
+
+#[test]
+fn gremlin_value_graph_elements() {
+    use crate::GremlinValue;
+
+    let knows = GraphEdge {
+        id: 7.into(),
+        label: "knows".into(),
+        in_v_id: 2.into(),
+        in_v_label: None,
+        out_v_id: 1.into(),
+        out_v_label: None,
+        parent: None,
+        properties: vec![],
+    };
+    let graph = GremlinValue::Graph(Graph {
+        vertices: vec![
+            Vertex::new(1, "person", None),
+            Vertex::new(2, "person", None),
+        ],
+        edges: vec![knows.clone()],
+    });
+
+    assert_eq!(
+        vec![&ElementId::Int(1), &ElementId::Int(2)],
+        graph.vertices().map(Vertex::id).collect::<Vec<_>>()
+    );
+    assert_eq!(vec![&knows], graph.edges().collect::<Vec<_>>());
+    assert_eq!(0, GremlinValue::Int(1).vertices().count());
+    assert_eq!(0, GremlinValue::Int(1).edges().count());
+}
@@ -5,6 +5,7 @@ use uuid::Uuid;
 use crate::GremlinValue;
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub enum ElementId {
     String(String),
     Int(i32),
@@ -1,11 +1,23 @@
 use std::fmt::Display;
 
 use crate::conversion;
+use crate::error::ValidationError;
+
+/// Script languages known by the Gremlin Server for lambdas.
+pub const LAMBDA_LANGUAGES: [&str; 5] = [
+    "gremlin-groovy",
+    "gremlin-java",
+    "gremlin-jython",
+    "gremlin-python",
+    "gremlin-javascript",
+];
 
 #[derive(Debug, PartialEq, Clone, Hash, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub struct Lambda {
     pub language: String,
     pub script: String,
+    #[cfg_attr(feature = "serde-derive", serde(rename = "arguments"))]
     pub arguments_length: i32,
 }
 
@@ -18,6 +30,88 @@ impl Lambda {
             arguments_length: 1,
         }
     }
+
+    /// Builds a gremlin-groovy `Lambda`, inferring the arguments length from the closure.
+    /// Explicit parameters before the first `->` of the closure are counted, arrows of
+    /// closures nested in the body are ignored. `{ -> ...}` has zero arguments and
+    /// a closure without parameters has one argument if it uses `it`, otherwise zero.
+    #[must_use]
+    pub fn groovy(script: &str) -> Self {
+        Lambda {
+            language: "gremlin-groovy".to_string(),
+            script: script.to_string(),
+            arguments_length: groovy_arguments_length(script),
+        }
+    }
+
+    /// Builds a `Lambda`, failing if the language is not one of [`LAMBDA_LANGUAGES`].
+    pub fn with_language(
+        language: &str,
+        script: &str,
+        arguments_length: i32,
+    ) -> Result<Self, ValidationError> {
+        let lambda = Lambda {
+            language: language.to_string(),
+            script: script.to_string(),
+            arguments_length,
+        };
+        if lambda.is_known_language() {
+            Ok(lambda)
+        } else {
+            Err(ValidationError::LambdaLanguage(language.to_string()))
+        }
+    }
+
+    #[must_use]
+    pub fn is_known_language(&self) -> bool {
+        LAMBDA_LANGUAGES.contains(&self.language.as_str())
+    }
+}
+
+fn groovy_arguments_length(script: &str) -> i32 {
+    let body = script.trim();
+    let body = body
+        .strip_prefix('{')
+        .and_then(|b| b.strip_suffix('}'))
+        .unwrap_or(body);
+
+    if let Some(params) = groovy_parameter_list(body) {
+        let params = params.trim();
+        if params.is_empty() {
+            0
+        } else {
+            params.split(',').count() as i32
+        }
+    } else if body
+        .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+        .any(|token| token == "it")
+    {
+        1
+    } else {
+        0
+    }
+}
+
+/// Returns the text before the first `->` outside of nested brackets, if it is a
+/// parameter list. Arrows of nested closures in the body are ignored.
+fn groovy_parameter_list(body: &str) -> Option<&str> {
+    let mut depth = 0_usize;
+    let mut chars = body.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth = depth.saturating_sub(1),
+            '-' if depth == 0 && matches!(chars.peek(), Some((_, '>'))) => {
+                let params = &body[..i];
+                return params
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c.is_whitespace() || "_$,".contains(c))
+                    .then_some(params);
+            }
+            _ => {}
+        }
+    }
+    None
 }
 
 impl Display for Lambda {
@@ -31,3 +125,43 @@ impl Display for Lambda {
 }
 
 conversion!(Lambda, Lambda);
+
+#[test]
+fn groovy_one_arg() {
+    let lambda = Lambda::groovy("{ it.get().value('name') }");
+    assert_eq!(lambda.language, "gremlin-groovy");
+    assert_eq!(lambda.arguments_length, 1);
+
+    let lambda = Lambda::groovy("{ a, b -> a + b }");
+    assert_eq!(lambda.arguments_length, 2);
+}
+
+#[test]
+fn groovy_zero_arg() {
+    assert_eq!(Lambda::groovy("{ -> 42 }").arguments_length, 0);
+    assert_eq!(Lambda::groovy("{ items.size() }").arguments_length, 0);
+}
+
+#[test]
+fn groovy_arrow_in_body() {
+    let lambda = Lambda::groovy("{ it.get().collect { x -> x * 2 } }");
+    assert_eq!(lambda.arguments_length, 1);
+
+    let lambda = Lambda::groovy("{ a -> a.collect { x, y -> x } }");
+    assert_eq!(lambda.arguments_length, 1);
+
+    let lambda = Lambda::groovy("{ -> [1, 2].collect { x -> x } }");
+    assert_eq!(lambda.arguments_length, 0);
+
+    let lambda = Lambda::groovy("{ items.each { x -> println x } }");
+    assert_eq!(lambda.arguments_length, 0);
+}
+
+#[test]
+fn lambda_language() {
+    assert!(Lambda::with_language("gremlin-python", "lambda x: x", 1).is_ok());
+    assert!(matches!(
+        Lambda::with_language("groovy", "{ it }", 1),
+        Err(ValidationError::LambdaLanguage(_))
+    ));
+}
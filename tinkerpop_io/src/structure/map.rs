@@ -1,9 +1,18 @@
 use uuid::Uuid;
 
-use super::enums::{Direction, T};
-use crate::{error::DecodeError, GremlinValue};
+use super::enums::{Direction, Merge, T};
+use crate::{
+    error::DecodeError,
+    macros::{TryBorrowFrom, TryMutBorrowFrom},
+    GremlinValue,
+};
 use std::{collections::HashMap, fmt::Display, hash::Hash};
 
+/// Keys of a `GremlinValue::Map`.
+///
+/// With the `serde-derive` feature a key is serialized as a `"<variant>:<value>"` string,
+/// e.g. `"Int:5"` or `"T:id"`, so maps can be written to formats like JSON which only allow
+/// string keys.
 #[derive(Debug, Hash, PartialEq, Eq, Clone, PartialOrd, Ord)]
 pub enum MapKeys {
     Int(i32),
@@ -12,6 +21,7 @@ pub enum MapKeys {
     Uuid(Uuid),
     T(T),
     Direction(Direction),
+    Merge(Merge),
 }
 
 impl Display for MapKeys {
@@ -23,6 +33,50 @@ impl Display for MapKeys {
             MapKeys::Uuid(val) => write!(f, "{val}"),
             MapKeys::T(val) => write!(f, "{val}"),
             MapKeys::Direction(val) => write!(f, "{val}"),
+            MapKeys::Merge(val) => write!(f, "{val}"),
+        }
+    }
+}
+
+#[cfg(feature = "serde-derive")]
+impl serde::Serialize for MapKeys {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let variant = match self {
+            MapKeys::Int(_) => "Int",
+            MapKeys::String(_) => "String",
+            MapKeys::Long(_) => "Long",
+            MapKeys::Uuid(_) => "Uuid",
+            MapKeys::T(_) => "T",
+            MapKeys::Direction(_) => "Direction",
+            MapKeys::Merge(_) => "Merge",
+        };
+        serializer.collect_str(&format_args!("{variant}:{self}"))
+    }
+}
+
+#[cfg(feature = "serde-derive")]
+impl<'de> serde::Deserialize<'de> for MapKeys {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let key = std::borrow::Cow::<str>::deserialize(deserializer)?;
+        let (variant, value) = key
+            .split_once(':')
+            .ok_or_else(|| D::Error::custom(format!("map key `{key}` has no variant prefix")))?;
+        match variant {
+            "Int" => value.parse().map(MapKeys::Int).map_err(D::Error::custom),
+            "String" => Ok(MapKeys::String(value.to_string())),
+            "Long" => value.parse().map(MapKeys::Long).map_err(D::Error::custom),
+            "Uuid" => value.parse().map(MapKeys::Uuid).map_err(D::Error::custom),
+            "T" => value.parse().map(MapKeys::T).map_err(D::Error::custom),
+            "Direction" => value
+                .parse()
+                .map(MapKeys::Direction)
+                .map_err(D::Error::custom),
+            "Merge" => value.parse().map(MapKeys::Merge).map_err(D::Error::custom),
+            _ => Err(D::Error::custom(format!(
+                "unknown map key variant `{variant}`"
+            ))),
         }
     }
 }
@@ -36,6 +90,7 @@ impl From<MapKeys> for GremlinValue {
             MapKeys::Uuid(val) => GremlinValue::Uuid(val),
             MapKeys::T(val) => GremlinValue::T(val),
             MapKeys::Direction(val) => GremlinValue::Direction(val),
+            MapKeys::Merge(val) => GremlinValue::Merge(val),
         }
     }
 }
@@ -63,6 +118,7 @@ impl TryFrom<GremlinValue> for MapKeys {
             GremlinValue::Uuid(val) => Ok(MapKeys::Uuid(val)),
             GremlinValue::T(val) => Ok(MapKeys::T(val)),
             GremlinValue::Direction(val) => Ok(MapKeys::Direction(val)),
+            GremlinValue::Merge(val) => Ok(MapKeys::Merge(val)),
             rest => Err(DecodeError::ConvertError(format!(
                 "cannot convert from {:?} to MapKeys",
                 rest
@@ -86,6 +142,7 @@ impl TryFrom<MapKeys> for String {
             MapKeys::Uuid(u) => Ok(u.to_string()),
             MapKeys::T(t) => Ok(t.to_string()),
             MapKeys::Direction(d) => Ok(d.to_string()),
+            MapKeys::Merge(m) => Ok(m.to_string()),
         }
     }
 }
@@ -120,6 +177,12 @@ impl From<Uuid> for MapKeys {
     }
 }
 
+impl From<Merge> for MapKeys {
+    fn from(val: Merge) -> Self {
+        MapKeys::Merge(val)
+    }
+}
+
 impl<K: Into<MapKeys>, V: Into<GremlinValue>> From<HashMap<K, V>> for GremlinValue {
     fn from(m: HashMap<K, V>) -> Self {
         let map = m.into_iter().map(|(k, v)| (k.into(), v.into())).collect();
@@ -127,6 +190,24 @@ impl<K: Into<MapKeys>, V: Into<GremlinValue>> From<HashMap<K, V>> for GremlinVal
     }
 }
 
+impl TryBorrowFrom for HashMap<MapKeys, GremlinValue> {
+    fn try_borrow_from(graph_binary: &GremlinValue) -> Option<&Self> {
+        match graph_binary {
+            GremlinValue::Map(map) => Some(map),
+            _ => None,
+        }
+    }
+}
+
+impl TryMutBorrowFrom for HashMap<MapKeys, GremlinValue> {
+    fn try_mut_borrow_from(graph_binary: &mut GremlinValue) -> Option<&mut Self> {
+        match graph_binary {
+            GremlinValue::Map(map) => Some(map),
+            _ => None,
+        }
+    }
+}
+
 impl<K, V> TryFrom<GremlinValue> for HashMap<K, V>
 where
     K: TryFrom<MapKeys, Error = DecodeError> + Eq + Hash,
@@ -167,3 +248,30 @@ where
         }
     }
 }
+
+#[cfg(all(feature = "serde-derive", feature = "graph_son"))]
+#[test]
+fn map_plain_json_roundtrip() {
+    let map = GremlinValue::Map(HashMap::from([
+        (MapKeys::Int(5), GremlinValue::Int(1)),
+        (MapKeys::Long(5), GremlinValue::Int(2)),
+        (MapKeys::from("name:first"), GremlinValue::from("marko")),
+        (MapKeys::T(T::Id), GremlinValue::Long(1)),
+        (
+            MapKeys::Direction(Direction::Out),
+            GremlinValue::Boolean(true),
+        ),
+        (MapKeys::Merge(Merge::OnCreate), GremlinValue::Boolean(true)),
+        (MapKeys::Uuid(Uuid::nil()), GremlinValue::Boolean(true)),
+    ]));
+
+    let json = serde_json::to_string(&map).unwrap();
+    assert!(json.contains(r#""String:name:first""#));
+    assert!(json.contains(r#""Direction:OUT""#));
+
+    let res: GremlinValue = serde_json::from_str(&json).unwrap();
+    assert_eq!(res, map);
+
+    let res = serde_json::from_str::<MapKeys>(r#""Int:five""#);
+    assert!(res.is_err());
+}
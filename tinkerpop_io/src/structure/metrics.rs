@@ -4,12 +4,15 @@ use crate::conversion;
 use crate::GremlinValue;
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub struct Metrics {
     pub id: String,
     pub name: String,
+    #[cfg_attr(feature = "serde-derive", serde(rename = "dur"))]
     pub duration: i64,
     pub counts: HashMap<String, i64>,
     pub annotations: HashMap<String, GremlinValue>,
+    #[cfg_attr(feature = "serde-derive", serde(rename = "metrics"))]
     pub nested_metrics: Vec<Metrics>,
 }
 
@@ -20,7 +23,9 @@ impl Display for Metrics {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub struct TraversalMetrics {
+    #[cfg_attr(feature = "serde-derive", serde(rename = "dur"))]
     pub duration: i64,
     pub metrics: Vec<Metrics>,
 }
@@ -41,6 +46,46 @@ impl TraversalMetrics {
     pub fn metrics(&self) -> &Vec<Metrics> {
         &self.metrics
     }
+
+    /// Flattens the metrics into the rows of the profile table shown by `Display`,
+    /// nested metrics follow their parent with a depth increased by one.
+    #[must_use]
+    pub fn as_rows(&self) -> Vec<ProfileRow> {
+        let mut rows = Vec::new();
+        for metrics in &self.metrics {
+            push_rows(metrics, 0, &mut rows);
+        }
+        rows
+    }
+}
+
+/// Row of a profile table, see [`TraversalMetrics::as_rows`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct ProfileRow {
+    pub depth: usize,
+    pub name: String,
+    pub count: Option<i64>,
+    pub traversers: Option<i64>,
+    pub time_ms: f64,
+    pub percent_duration: Option<f64>,
+}
+
+fn push_rows(metrics: &Metrics, depth: usize, rows: &mut Vec<ProfileRow>) {
+    rows.push(ProfileRow {
+        depth,
+        name: metrics.name.clone(),
+        count: metrics.counts.get("elementCount").copied(),
+        traversers: metrics.counts.get("traverserCount").copied(),
+        time_ms: metrics.duration as f64 / 1000. / 1000., // from ns to ms
+        percent_duration: metrics
+            .annotations
+            .get("percentDur")
+            .and_then(|gb| gb.get_ref::<f64>())
+            .copied(),
+    });
+    for nested in &metrics.nested_metrics {
+        push_rows(nested, depth + 1, rows);
+    }
 }
 
 impl Display for TraversalMetrics {
@@ -201,3 +246,50 @@ fn test_build_string() {
     println!("{}", build_string(&metric, 0));
     println!("{}", build_string(&metric2, 0))
 }
+
+#[test]
+fn traversal_metrics_as_rows() {
+    let metrics = TraversalMetrics::new(
+        3_000_000,
+        vec![Metrics {
+            id: "7.0.0()".to_string(),
+            name: "TinkerGraphStep(vertex,[])".to_string(),
+            duration: 2_000_000,
+            counts: HashMap::from([
+                ("elementCount".to_string(), 6),
+                ("traverserCount".to_string(), 6),
+            ]),
+            annotations: HashMap::from([("percentDur".to_string(), 66.6_f64.into())]),
+            nested_metrics: vec![Metrics {
+                id: "7.0.1()".to_string(),
+                name: "ExpandableStepIterator".to_string(),
+                duration: 500_000,
+                counts: HashMap::from([("traverserCount".to_string(), 6)]),
+                annotations: HashMap::new(),
+                nested_metrics: Vec::new(),
+            }],
+        }],
+    );
+
+    assert_eq!(
+        vec![
+            ProfileRow {
+                depth: 0,
+                name: "TinkerGraphStep(vertex,[])".to_string(),
+                count: Some(6),
+                traversers: Some(6),
+                time_ms: 2.0,
+                percent_duration: Some(66.6),
+            },
+            ProfileRow {
+                depth: 1,
+                name: "ExpandableStepIterator".to_string(),
+                count: None,
+                traversers: Some(6),
+                time_ms: 0.5,
+                percent_duration: None,
+            },
+        ],
+        metrics.as_rows()
+    );
+}
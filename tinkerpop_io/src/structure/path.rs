@@ -6,6 +6,7 @@ use crate::GremlinValue;
 use super::set::Set;
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub struct Path {
     pub(crate) labels: Vec<Set<String>>,   // List<Set<String>>
     pub(crate) objects: Vec<GremlinValue>, // List<T>
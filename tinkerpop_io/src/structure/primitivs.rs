@@ -1,6 +1,7 @@
 use crate::macros::{TryBorrowFrom, TryMutBorrowFrom};
 
-use crate::{conversion, GremlinValue};
+use crate::{conversion, error::DecodeError, narrowing_conversion, GremlinValue};
+use num::BigInt;
 use uuid::Uuid;
 
 impl TryBorrowFrom for str {
@@ -21,6 +22,17 @@ impl TryMutBorrowFrom for str {
     }
 }
 
+/// ```
+/// # use tinkerpop_io::GremlinValue;
+///
+/// let expected = GremlinValue::String("marko".to_string());
+///
+/// assert_eq!(expected, GremlinValue::from("marko"));
+/// assert_eq!(expected, GremlinValue::from("marko".to_string()));
+/// assert_eq!(expected, GremlinValue::from(&"marko".to_string()));
+/// assert_eq!(GremlinValue::Char('m'), GremlinValue::from('m'));
+///
+/// ```
 impl From<&str> for GremlinValue {
     fn from(s: &str) -> Self {
         GremlinValue::String(s.to_owned())
@@ -28,11 +40,82 @@ impl From<&str> for GremlinValue {
 }
 
 conversion!(String, String);
-conversion!(u8, Byte);
-conversion!(i16, Short);
+narrowing_conversion!(
+    /// `u8` converts into `GremlinValue::Byte`, integer variants in range of `u8` convert back.
+    ///
+    /// ```
+    /// # use tinkerpop_io::GremlinValue;
+    ///
+    /// assert_eq!(GremlinValue::Byte(5), 5_u8.into());
+    /// assert_eq!(5_u8, u8::try_from(GremlinValue::Byte(5)).unwrap());
+    /// assert_eq!(5_u8, u8::try_from(GremlinValue::Int(5)).unwrap());
+    /// assert!(u8::try_from(GremlinValue::Int(256)).is_err());
+    /// assert!(u8::try_from(GremlinValue::Short(-1)).is_err());
+    /// ```
+    u8,
+    Byte
+);
+narrowing_conversion!(
+    /// `i16` converts into `GremlinValue::Short`, integer variants in range of `i16` convert back.
+    ///
+    /// ```
+    /// # use tinkerpop_io::GremlinValue;
+    ///
+    /// assert_eq!(GremlinValue::Short(5), 5_i16.into());
+    /// assert_eq!(GremlinValue::Int(5), 5_i32.into());
+    /// assert_eq!(-5_i16, i16::try_from(GremlinValue::Short(-5)).unwrap());
+    /// assert_eq!(5_i16, i16::try_from(GremlinValue::Long(5)).unwrap());
+    /// assert!(i16::try_from(GremlinValue::Int(i32::MAX)).is_err());
+    /// ```
+    i16,
+    Short
+);
 conversion!(i32, Int);
 conversion!(i64, Long);
 conversion!(f32, Float);
 conversion!(f64, Double);
 conversion!(bool, Boolean);
+conversion!(char, Char);
 conversion!(Uuid, Uuid);
+
+impl From<i128> for GremlinValue {
+    fn from(v: i128) -> Self {
+        GremlinValue::BigInteger(BigInt::from(v))
+    }
+}
+
+impl From<u128> for GremlinValue {
+    fn from(v: u128) -> Self {
+        GremlinValue::BigInteger(BigInt::from(v))
+    }
+}
+
+impl TryFrom<GremlinValue> for i128 {
+    type Error = DecodeError;
+
+    fn try_from(value: GremlinValue) -> Result<Self, Self::Error> {
+        match value {
+            GremlinValue::BigInteger(val) => {
+                i128::try_from(val).map_err(|err| DecodeError::ConvertError(err.to_string()))
+            }
+            _ => Err(DecodeError::ConvertError(
+                "cannot convert Value to i128".to_string(),
+            )),
+        }
+    }
+}
+
+impl TryFrom<GremlinValue> for u128 {
+    type Error = DecodeError;
+
+    fn try_from(value: GremlinValue) -> Result<Self, Self::Error> {
+        match value {
+            GremlinValue::BigInteger(val) => {
+                u128::try_from(val).map_err(|err| DecodeError::ConvertError(err.to_string()))
+            }
+            _ => Err(DecodeError::ConvertError(
+                "cannot convert Value to u128".to_string(),
+            )),
+        }
+    }
+}
@@ -1,12 +1,13 @@
-use std::fmt::Display;
+use std::{fmt::Display, sync::Arc};
 
 use super::{edge::Edge, vertex_property::VertexProperty};
 use crate::conversion;
 use crate::GremlinValue;
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub struct Property {
-    pub key: String,
+    pub key: Arc<str>,
     pub value: Box<GremlinValue>,
     pub parent: EitherParent,
 }
@@ -14,7 +15,7 @@ pub struct Property {
 impl Property {
     pub fn new(key: &str, value: impl Into<GremlinValue>, parent: EitherParent) -> Self {
         Property {
-            key: key.to_string(),
+            key: key.into(),
             value: Box::new(value.into()),
             parent,
         }
@@ -22,6 +23,7 @@ impl Property {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub enum EitherParent {
     Edge(Edge),
     VertexProperty(VertexProperty),
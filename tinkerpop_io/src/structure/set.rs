@@ -7,6 +7,7 @@ use std::fmt::Display;
 use crate::GremlinValue;
 
 #[derive(Debug, Clone, PartialEq, Hash)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub struct Set<T>(Vec<T>);
 
 impl<T> Set<T> {
@@ -1,20 +1,64 @@
 use std::{collections::HashMap, fmt::Display};
 
-use crate::{conversion, GremlinValue};
+use crate::{conversion, error::TraverserError, GremlinValue};
+
+use super::{bytecode::Bytecode, set::Set};
+
+pub const PARTITION_STRATEGY: &str =
+    "org.apache.tinkerpop.gremlin.process.traversal.strategy.decoration.PartitionStrategy";
+pub const SUBGRAPH_STRATEGY: &str =
+    "org.apache.tinkerpop.gremlin.process.traversal.strategy.decoration.SubgraphStrategy";
+pub const OPTIONS_STRATEGY: &str =
+    "org.apache.tinkerpop.gremlin.process.traversal.strategy.decoration.OptionsStrategy";
+pub const ELEMENT_ID_STRATEGY: &str =
+    "org.apache.tinkerpop.gremlin.process.traversal.strategy.decoration.ElementIdStrategy";
+pub const READ_ONLY_STRATEGY: &str =
+    "org.apache.tinkerpop.gremlin.process.traversal.strategy.verification.ReadOnlyStrategy";
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub struct Traverser {
     pub bulk: i64,
     pub value: Box<GremlinValue>,
 }
 
 impl Traverser {
+    /// Builds a `Traverser` with a bulk of 1.
     #[must_use]
-    pub fn new(bulk: i64, value: GremlinValue) -> Traverser {
+    pub fn new(value: GremlinValue) -> Traverser {
         Traverser {
+            bulk: 1,
+            value: Box::new(value),
+        }
+    }
+
+    pub fn with_bulk(bulk: i64, value: GremlinValue) -> Result<Traverser, TraverserError> {
+        if bulk < 0 {
+            return Err(TraverserError::NegativeBulk(bulk));
+        }
+        Ok(Traverser {
             bulk,
             value: Box::new(value),
+        })
+    }
+
+    /// Adds `n` to the bulk, rejecting the change if the bulk would become negative.
+    pub fn add_bulk(&mut self, n: i64) -> Result<(), TraverserError> {
+        let bulk = self.bulk.checked_add(n).ok_or(TraverserError::Overflow)?;
+        if bulk < 0 {
+            return Err(TraverserError::NegativeBulk(bulk));
+        }
+        self.bulk = bulk;
+        Ok(())
+    }
+
+    /// Merges two `Traverser` holding equal values by summing their bulks.
+    pub fn merge(mut self, other: Traverser) -> Result<Traverser, TraverserError> {
+        if self.value != other.value {
+            return Err(TraverserError::ValueMismatch);
         }
+        self.add_bulk(other.bulk)?;
+        Ok(self)
     }
     #[must_use]
     pub fn bulk(&self) -> &i64 {
@@ -93,11 +137,89 @@ impl IntoIterator for Traverser {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub struct TraversalStrategy {
     pub strategy_class: String,                       // class
     pub configuration: HashMap<String, GremlinValue>, // not sure if key is correct
 }
 
+impl TraversalStrategy {
+    #[must_use]
+    pub fn new(strategy_class: &str, configuration: HashMap<String, GremlinValue>) -> Self {
+        TraversalStrategy {
+            strategy_class: strategy_class.to_string(),
+            configuration,
+        }
+    }
+
+    /// `PartitionStrategy`, `write_partition` and `read_partitions` are only configured if present.
+    #[must_use]
+    pub fn partition(
+        partition_key: &str,
+        write_partition: Option<&str>,
+        read_partitions: Vec<&str>,
+        include_meta_properties: bool,
+    ) -> Self {
+        let mut configuration = HashMap::from([("partitionKey".to_string(), partition_key.into())]);
+        if let Some(write_partition) = write_partition {
+            configuration.insert("writePartition".to_string(), write_partition.into());
+        }
+        if !read_partitions.is_empty() {
+            let read_partitions = read_partitions.into_iter().map(Into::into).collect();
+            configuration.insert(
+                "readPartitions".to_string(),
+                GremlinValue::Set(Set::new(read_partitions)),
+            );
+        }
+        if include_meta_properties {
+            configuration.insert("includeMetaProperties".to_string(), true.into());
+        }
+        TraversalStrategy::new(PARTITION_STRATEGY, configuration)
+    }
+
+    /// `SubgraphStrategy` with optional filter traversals for vertices, edges and vertex properties.
+    #[must_use]
+    pub fn subgraph(
+        vertices: Option<Bytecode>,
+        edges: Option<Bytecode>,
+        vertex_properties: Option<Bytecode>,
+        check_adjacent_vertices: bool,
+    ) -> Self {
+        let mut configuration = HashMap::from([(
+            "checkAdjacentVertices".to_string(),
+            check_adjacent_vertices.into(),
+        )]);
+        for (key, filter) in [
+            ("vertices", vertices),
+            ("edges", edges),
+            ("vertexProperties", vertex_properties),
+        ] {
+            if let Some(filter) = filter {
+                configuration.insert(key.to_string(), filter.into());
+            }
+        }
+        TraversalStrategy::new(SUBGRAPH_STRATEGY, configuration)
+    }
+
+    #[must_use]
+    pub fn options(options: HashMap<String, GremlinValue>) -> Self {
+        TraversalStrategy::new(OPTIONS_STRATEGY, options)
+    }
+
+    #[must_use]
+    pub fn element_id(id_property_key: &str) -> Self {
+        TraversalStrategy::new(
+            ELEMENT_ID_STRATEGY,
+            HashMap::from([("idPropertyKey".to_string(), id_property_key.into())]),
+        )
+    }
+
+    #[must_use]
+    pub fn read_only() -> Self {
+        TraversalStrategy::new(READ_ONLY_STRATEGY, HashMap::new())
+    }
+}
+
 impl Display for TraversalStrategy {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "class:{},config:[", self.strategy_class)?;
@@ -123,3 +245,27 @@ fn test_iter() {
     assert_eq!(iter.next(), Some(&1.into()));
     assert_eq!(iter.next(), None)
 }
+
+#[test]
+fn merge_equal_traverser() {
+    let t1 = Traverser::new("marko".into());
+    let t2 = Traverser::with_bulk(2, "marko".into()).unwrap();
+
+    let merged = t1.merge(t2).unwrap();
+    assert_eq!(merged.bulk, 3);
+    assert_eq!(merged.value(), &"marko".into());
+
+    let t3 = Traverser::new("josh".into());
+    assert!(merged.merge(t3).is_err());
+}
+
+#[test]
+fn add_negative_bulk() {
+    let mut t = Traverser::with_bulk(2, 1.into()).unwrap();
+    assert!(t.add_bulk(-3).is_err());
+    assert_eq!(t.bulk, 2);
+
+    t.add_bulk(-2).unwrap();
+    assert_eq!(t.bulk, 0);
+    assert!(Traverser::with_bulk(-1, 1.into()).is_err());
+}
@@ -1 +1,83 @@
+use std::{fmt::Display, slice::Iter};
 
+use crate::conversion;
+use crate::GremlinValue;
+
+/// Tree as returned by the `tree()` step.
+///
+/// `GremlinValue` implements neither `Hash` nor `Ord`, therefore the branches are stored
+/// as a list of key and subtree pairs in insertion order instead of a map or set.
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tree(pub(crate) Vec<(GremlinValue, Tree)>);
+
+impl Tree {
+    #[must_use]
+    pub fn new() -> Tree {
+        Tree(Vec::new())
+    }
+
+    /// Returns the subtree of `key`, inserting an empty subtree if `key` is not present yet.
+    pub fn insert(&mut self, key: impl Into<GremlinValue>) -> &mut Tree {
+        let key = key.into();
+        let index = match self.0.iter().position(|(k, _)| k.eq(&key)) {
+            Some(index) => index,
+            None => {
+                self.0.push((key, Tree::new()));
+                self.0.len() - 1
+            }
+        };
+        &mut self.0[index].1
+    }
+
+    #[must_use]
+    pub fn get(&self, key: &GremlinValue) -> Option<&Tree> {
+        self.0.iter().find(|(k, _)| k.eq(key)).map(|(_, tree)| tree)
+    }
+
+    #[must_use]
+    pub fn branches(&self) -> &Vec<(GremlinValue, Tree)> {
+        &self.0
+    }
+
+    #[must_use]
+    pub fn is_leaf(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> Iter<'_, (GremlinValue, Tree)> {
+        self.0.iter()
+    }
+}
+
+impl IntoIterator for Tree {
+    type Item = (GremlinValue, Tree);
+
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Tree {
+    type Item = &'a (GremlinValue, Tree);
+
+    type IntoIter = Iter<'a, (GremlinValue, Tree)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Display for Tree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{")?;
+        for (key, tree) in &self.0 {
+            write!(f, "{key}:{tree},")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+conversion!(Tree, Tree);
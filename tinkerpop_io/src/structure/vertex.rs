@@ -1,13 +1,14 @@
-use std::fmt::Display;
+use std::{collections::HashMap, fmt::Display, sync::Arc};
 
-use crate::conversion;
+use crate::{conversion, error::DecodeError, GremlinValue};
 
-use super::{id::ElementId, vertex_property::VertexProperty};
+use super::{enums::T, id::ElementId, map::MapKeys, vertex_property::VertexProperty};
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vertex {
     pub id: ElementId,
-    pub label: String,
+    pub label: Arc<str>,
     pub properties: Option<Vec<VertexProperty>>,
 }
 
@@ -20,7 +21,7 @@ impl Vertex {
     ) -> Self {
         Vertex {
             id: id.into(),
-            label: label.to_owned(),
+            label: label.into(),
             properties,
         }
     }
@@ -30,9 +31,87 @@ impl Vertex {
     }
 
     #[must_use]
-    pub fn label(&self) -> &String {
+    pub fn label(&self) -> &str {
         &self.label
     }
+
+    /// Reconstructs a `Vertex` from a plain map with `id`, `label` and optional `properties` keys,
+    /// as returned by REST-style responses. The keys may also be given as `T::Id` and `T::Label`.
+    ///
+    /// `properties` has to map each property key to a list of maps with `id` and `value` keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DecodeError::ConvertError` if a key is missing or a value has an unexpected shape.
+    pub fn try_from_map(map: &HashMap<MapKeys, GremlinValue>) -> Result<Vertex, DecodeError> {
+        let id = element_id(map_entry(map, "id", Some(T::Id))?)?;
+        let label = map_entry(map, "label", Some(T::Label))?
+            .as_string()
+            .ok_or_else(|| DecodeError::ConvertError("Vertex label is not a String".to_string()))?
+            .to_owned();
+
+        let properties = match map.get(&MapKeys::from("properties")) {
+            Some(GremlinValue::Map(properties)) => {
+                let mut vertex_properties = Vec::new();
+                for (key, value) in properties {
+                    let GremlinValue::List(list) = value else {
+                        return Err(DecodeError::ConvertError(format!(
+                            "Vertex property {key} is not a List"
+                        )));
+                    };
+                    for entry in list {
+                        let GremlinValue::Map(entry) = entry else {
+                            return Err(DecodeError::ConvertError(format!(
+                                "Vertex property {key} contains a non Map entry"
+                            )));
+                        };
+                        vertex_properties.push(VertexProperty::new(
+                            element_id(map_entry(entry, "id", Some(T::Id))?)?,
+                            &key.to_string(),
+                            map_entry(entry, "value", Some(T::Value))?.clone(),
+                            None,
+                            None,
+                        ));
+                    }
+                }
+                Some(vertex_properties)
+            }
+            Some(GremlinValue::UnspecifiedNullObject) | None => None,
+            Some(_) => {
+                return Err(DecodeError::ConvertError(
+                    "Vertex properties is not a Map".to_string(),
+                ))
+            }
+        };
+
+        Ok(Vertex {
+            id,
+            label: label.into(),
+            properties,
+        })
+    }
+}
+
+fn map_entry<'a>(
+    map: &'a HashMap<MapKeys, GremlinValue>,
+    key: &str,
+    t: Option<T>,
+) -> Result<&'a GremlinValue, DecodeError> {
+    map.get(&MapKeys::from(key))
+        .or_else(|| t.and_then(|t| map.get(&MapKeys::T(t))))
+        .ok_or_else(|| DecodeError::ConvertError(format!("missing key {key}")))
+}
+
+fn element_id(value: &GremlinValue) -> Result<ElementId, DecodeError> {
+    match value {
+        GremlinValue::String(s) => Ok(ElementId::String(s.clone())),
+        GremlinValue::Int(i) => Ok(ElementId::Int(*i)),
+        GremlinValue::Long(l) => Ok(ElementId::Long(*l)),
+        GremlinValue::Uuid(u) => Ok(ElementId::Uuid(*u)),
+        rest => Err(DecodeError::ConvertError(format!(
+            "{rest:?} is not a valid element id"
+        ))),
+    }
 }
 
 impl Display for Vertex {
@@ -48,3 +127,58 @@ impl Display for Vertex {
 }
 
 conversion!(Vertex, Vertex);
+
+#[cfg(all(feature = "serde-derive", feature = "graph_son"))]
+#[test]
+fn vertex_plain_json() {
+    let vertex = Vertex::new(1_i64, "person", None);
+
+    let jval = serde_json::to_value(&vertex).unwrap();
+    let expected = serde_json::json!({"id": {"Long": 1}, "label": "person", "properties": null});
+    assert_eq!(jval, expected);
+
+    let res: Vertex = serde_json::from_value(jval).unwrap();
+    assert_eq!(res, vertex);
+}
+
+#[test]
+fn vertex_try_from_map() {
+    let name = HashMap::from([
+        (MapKeys::from("id"), GremlinValue::Long(2)),
+        (MapKeys::from("value"), GremlinValue::from("marko")),
+    ]);
+    let properties = HashMap::from([(
+        MapKeys::from("name"),
+        GremlinValue::List(vec![GremlinValue::Map(name)]),
+    )]);
+    let map = HashMap::from([
+        (MapKeys::T(T::Id), GremlinValue::Long(1)),
+        (MapKeys::from("label"), GremlinValue::from("person")),
+        (MapKeys::from("properties"), GremlinValue::Map(properties)),
+    ]);
+
+    let expected = Vertex::new(
+        1_i64,
+        "person",
+        Some(vec![VertexProperty::new(
+            2_i64, "name", "marko", None, None,
+        )]),
+    );
+    assert_eq!(Vertex::try_from_map(&map).unwrap(), expected);
+
+    let missing_label = HashMap::from([(MapKeys::from("id"), GremlinValue::Long(1))]);
+    assert!(Vertex::try_from_map(&missing_label).is_err());
+
+    let malformed = HashMap::from([
+        (MapKeys::from("id"), GremlinValue::Long(1)),
+        (MapKeys::from("label"), GremlinValue::from("person")),
+        (
+            MapKeys::from("properties"),
+            GremlinValue::Map(HashMap::from([(
+                MapKeys::from("name"),
+                GremlinValue::Int(1),
+            )])),
+        ),
+    ]);
+    assert!(Vertex::try_from_map(&malformed).is_err());
+}
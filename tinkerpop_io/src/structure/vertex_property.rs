@@ -1,13 +1,14 @@
-use std::fmt::Display;
+use std::{fmt::Display, sync::Arc};
 
 use crate::{conversion, GremlinValue};
 
 use super::{id::ElementId, property::Property, vertex::Vertex};
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde-derive", derive(serde::Serialize, serde::Deserialize))]
 pub struct VertexProperty {
     pub id: ElementId, // TODO needs refinment
-    pub label: String,
+    pub label: Arc<str>,
     pub value: Box<GremlinValue>,
     pub parent: Option<Vertex>,
     pub properties: Option<Vec<Property>>,
@@ -23,7 +24,7 @@ impl VertexProperty {
     ) -> Self {
         VertexProperty {
             id: id.into(),
-            label: label.to_string(),
+            label: label.into(),
             value: Box::new(value.into()),
             parent,
             properties,
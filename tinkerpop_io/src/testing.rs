@@ -0,0 +1,182 @@
+//! Helpers to compare GraphBinary payloads in tests independent of the order of map entries
+//! and set items, and to check that GraphSON encoding and decoding are symmetric.
+
+use crate::binary::from_slice_exact;
+#[cfg(feature = "graph_son")]
+use crate::graphson::{DecodeGraphSON, EncodeGraphSON};
+use crate::GremlinValue;
+
+/// Compares two values, ignoring the order of `Set` and `BulkSet` items.
+/// `Map` entries are compared by key and `List` items by position.
+#[must_use]
+pub fn deep_eq(a: &GremlinValue, b: &GremlinValue) -> bool {
+    diff(a, b, "$").is_none()
+}
+
+/// Decodes both payloads and compares them with [`deep_eq`].
+/// Returns false if either payload fails to decode.
+#[must_use]
+pub fn gb_eq(a: &[u8], b: &[u8]) -> bool {
+    match (
+        from_slice_exact::<GremlinValue>(a),
+        from_slice_exact::<GremlinValue>(b),
+    ) {
+        (Ok(a), Ok(b)) => deep_eq(&a, &b),
+        _ => false,
+    }
+}
+
+/// Asserts that both payloads decode to equal values according to [`deep_eq`].
+///
+/// # Panics
+///
+/// Panics with the path of the first difference if the values are not equal
+/// or if either payload fails to decode.
+pub fn assert_gb_eq(a: &[u8], b: &[u8]) {
+    let left = from_slice_exact::<GremlinValue>(a)
+        .unwrap_or_else(|err| panic!("left payload failed to decode: {err}"));
+    let right = from_slice_exact::<GremlinValue>(b)
+        .unwrap_or_else(|err| panic!("right payload failed to decode: {err}"));
+    if let Some(difference) = diff(&left, &right, "$") {
+        panic!("GraphBinary payloads differ at {difference}\n left: {left:?}\nright: {right:?}");
+    }
+}
+
+/// Asserts that `value` is equal to itself after encoding it to GraphSON 3.0, serializing the
+/// JSON to text, parsing it again and decoding it.
+///
+/// # Panics
+///
+/// Panics with the encoded JSON if decoding fails or the decoded value differs.
+#[cfg(feature = "graph_son")]
+pub fn assert_roundtrip_v3<T>(value: T)
+where
+    T: EncodeGraphSON + DecodeGraphSON + PartialEq + std::fmt::Debug,
+{
+    let json = reparse(&value.encode_v3());
+    let decoded = T::decode_v3(&json)
+        .unwrap_or_else(|err| panic!("GraphSON v3 failed to decode: {err}\njson: {json}"));
+    assert_eq!(
+        value, decoded,
+        "GraphSON v3 round trip differs\njson: {json}"
+    );
+}
+
+/// Asserts the round trip of [`assert_roundtrip_v3`] for GraphSON 2.0.
+///
+/// # Panics
+///
+/// Panics with the encoded JSON if decoding fails or the decoded value differs.
+#[cfg(feature = "graph_son")]
+pub fn assert_roundtrip_v2<T>(value: T)
+where
+    T: EncodeGraphSON + DecodeGraphSON + PartialEq + std::fmt::Debug,
+{
+    let json = reparse(&value.encode_v2());
+    let decoded = T::decode_v2(&json)
+        .unwrap_or_else(|err| panic!("GraphSON v2 failed to decode: {err}\njson: {json}"));
+    assert_eq!(
+        value, decoded,
+        "GraphSON v2 round trip differs\njson: {json}"
+    );
+}
+
+#[cfg(feature = "graph_son")]
+fn reparse(json: &serde_json::Value) -> serde_json::Value {
+    serde_json::from_str(&json.to_string())
+        .unwrap_or_else(|err| panic!("encoded GraphSON is not valid JSON: {err}\njson: {json}"))
+}
+
+fn diff(a: &GremlinValue, b: &GremlinValue, path: &str) -> Option<String> {
+    match (a, b) {
+        (GremlinValue::List(a), GremlinValue::List(b)) => {
+            if a.len() != b.len() {
+                return Some(format!("{path}: list length {} != {}", a.len(), b.len()));
+            }
+            a.iter()
+                .zip(b)
+                .enumerate()
+                .find_map(|(index, (a, b))| diff(a, b, &format!("{path}[{index}]")))
+        }
+        (GremlinValue::Map(a), GremlinValue::Map(b)) => {
+            if let Some(key) = b.keys().find(|key| !a.contains_key(key)) {
+                return Some(format!("{path}: key `{key}` missing on the left"));
+            }
+            a.iter().find_map(|(key, a)| match b.get(key) {
+                Some(b) => diff(a, b, &format!("{path}.{key}")),
+                None => Some(format!("{path}: key `{key}` missing on the right")),
+            })
+        }
+        (GremlinValue::Set(a), GremlinValue::Set(b)) => unordered_diff(a.set(), b.set(), path),
+        (GremlinValue::BulkSet(a), GremlinValue::BulkSet(b)) => {
+            unordered_diff(a.bulk_set(), b.bulk_set(), path)
+        }
+        (a, b) if a == b => None,
+        (a, b) => Some(format!("{path}: {a:?} != {b:?}")),
+    }
+}
+
+fn unordered_diff<T: PartialEq + std::fmt::Debug>(a: &[T], b: &[T], path: &str) -> Option<String> {
+    if a.len() != b.len() {
+        return Some(format!("{path}: set length {} != {}", a.len(), b.len()));
+    }
+    let mut remaining: Vec<&T> = b.iter().collect();
+    for item in a {
+        match remaining.iter().position(|other| *other == item) {
+            Some(index) => {
+                remaining.swap_remove(index);
+            }
+            None => return Some(format!("{path}: {item:?} missing on the right")),
+        }
+    }
+    None
+}
+
+#[test]
+fn map_key_order() {
+    let a = [
+        0x0a, 0x0, 0x0, 0x0, 0x0, 0x02, // map with two entries
+        0x03, 0x0, 0x0, 0x0, 0x0, 0x01, b'a', 0x01, 0x0, 0x0, 0x0, 0x0, 0x01, // "a": 1
+        0x03, 0x0, 0x0, 0x0, 0x0, 0x01, b'b', 0x01, 0x0, 0x0, 0x0, 0x0, 0x02, // "b": 2
+    ];
+    let b = [
+        0x0a, 0x0, 0x0, 0x0, 0x0, 0x02, // map with two entries
+        0x03, 0x0, 0x0, 0x0, 0x0, 0x01, b'b', 0x01, 0x0, 0x0, 0x0, 0x0, 0x02, // "b": 2
+        0x03, 0x0, 0x0, 0x0, 0x0, 0x01, b'a', 0x01, 0x0, 0x0, 0x0, 0x0, 0x01, // "a": 1
+    ];
+
+    assert_ne!(a, b);
+    assert!(gb_eq(&a, &b));
+    assert_gb_eq(&a, &b);
+
+    let mut c = b;
+    c[31] = 0x03;
+    assert!(!gb_eq(&a, &c));
+}
+
+#[cfg(feature = "graph_son")]
+#[test]
+fn graphson_roundtrip() {
+    use crate::structure::vertex::Vertex;
+    use crate::structure::vertex_property::VertexProperty;
+    use std::collections::HashMap;
+
+    let marko = Vertex::new(
+        1,
+        "person",
+        Some(vec![VertexProperty::new(
+            0_i64, "name", "marko", None, None,
+        )]),
+    );
+    let map = GremlinValue::Map(HashMap::from([
+        ("name".into(), "marko".into()),
+        ("age".into(), 29.into()),
+    ]));
+
+    assert_roundtrip_v3(1_i32);
+    assert_roundtrip_v2(1_i64);
+    assert_roundtrip_v3(GremlinValue::Vertex(marko.clone()));
+    assert_roundtrip_v2(marko);
+    assert_roundtrip_v3(map.clone());
+    assert_roundtrip_v3(GremlinValue::List(vec![1.into(), "a".into()]));
+}
@@ -0,0 +1,61 @@
+//! Converts payloads between GraphBinary and GraphSON in one call, going through `GremlinValue`.
+
+use serde_json::Value;
+
+use crate::binary::{from_slice_exact, Encode};
+use crate::error::TranscodeError;
+use crate::graphson::{DecodeGraphSON, EncodeGraphSON, GraphSonVersion};
+use crate::GremlinValue;
+
+/// Decodes the GraphBinary bytes and encodes the value as GraphSON of the given version.
+/// GraphSON 1.0 is untyped, the value is written with [`GremlinValue::to_plain_json`].
+pub fn transcode_gb_to_graphson(
+    bytes: &[u8],
+    version: GraphSonVersion,
+) -> Result<Value, TranscodeError> {
+    let value = from_slice_exact::<GremlinValue>(bytes)?;
+    Ok(match version {
+        GraphSonVersion::V1 => value.to_plain_json(),
+        GraphSonVersion::V2 => value.encode_v2(),
+        GraphSonVersion::V3 => value.encode_v3(),
+    })
+}
+
+/// Decodes the GraphSON of the given version and encodes the value as GraphBinary.
+/// GraphSON 1.0 is untyped, the value is read with [`GremlinValue::from_plain_json`].
+pub fn transcode_graphson_to_gb(
+    j_val: &Value,
+    version: GraphSonVersion,
+) -> Result<Vec<u8>, TranscodeError> {
+    let value = match version {
+        GraphSonVersion::V1 => GremlinValue::from_plain_json(j_val),
+        GraphSonVersion::V2 => GremlinValue::decode_v2(j_val)?,
+        GraphSonVersion::V3 => GremlinValue::decode_v3(j_val)?,
+    };
+    let mut buf = Vec::new();
+    value.encode(&mut buf)?;
+    Ok(buf)
+}
+
+#[test]
+fn vertex_transcode() {
+    use crate::structure::vertex::Vertex;
+
+    let vertex = GremlinValue::Vertex(Vertex::new(1_i32, "person", None));
+    let mut bytes = Vec::new();
+    vertex.encode(&mut bytes).unwrap();
+
+    let j_val = transcode_gb_to_graphson(&bytes, GraphSonVersion::V3).unwrap();
+    assert_eq!(vertex.encode_v3(), j_val);
+    assert_eq!(
+        bytes,
+        transcode_graphson_to_gb(&j_val, GraphSonVersion::V3).unwrap()
+    );
+
+    let j_val = transcode_gb_to_graphson(&bytes, GraphSonVersion::V2).unwrap();
+    assert_eq!(vertex.encode_v2(), j_val);
+    assert_eq!(
+        bytes,
+        transcode_graphson_to_gb(&j_val, GraphSonVersion::V2).unwrap()
+    );
+}